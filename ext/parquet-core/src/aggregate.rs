@@ -0,0 +1,220 @@
+//! Row counts, min, max and null counts answered from the footer
+//!
+//! A row group outside the range filters contributes nothing, and one whose
+//! statistics show that every row matches contributes its exact statistics
+//! without being read. Only the row groups left undecided, because they
+//! straddle a range bound, lack exact statistics or hold floats, whose
+//! statistics leave out NaN, are scanned, projected to the one column
+//! involved.
+
+use crate::{
+    arrow_conversion::arrow_to_parquet_value,
+    sorting::{
+        covered_row_groups, is_float, leaf_index, row_filter, select_row_groups, ResolvedRange,
+    },
+    statistics::has_ordered_values,
+    ParquetError, ParquetValue, Result,
+};
+use arrow::compute::{cast, concat, sort_to_indices, SortOptions};
+use arrow_array::{Array, ArrayRef};
+use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder,
+};
+use parquet::arrow::ProjectionMask;
+use parquet::file::reader::ChunkReader;
+
+/// Number of rows within the ranges
+pub(crate) fn count<R>(
+    reader: R,
+    metadata: &ArrowReaderMetadata,
+    ranges: &[ResolvedRange],
+) -> Result<u64>
+where
+    R: ChunkReader + 'static,
+{
+    let (covered, undecided) = split_row_groups(metadata, ranges)?;
+    let mut count = covered
+        .iter()
+        .map(|&i| metadata.metadata().row_group(i).num_rows() as u64)
+        .sum::<u64>();
+
+    // Any filtered column will do as the projection, the row filter does the work
+    if let Some(range) = ranges.first() {
+        for batch in scan(reader, metadata, undecided, range.leaf_idx(), ranges)? {
+            count += batch?.num_rows() as u64;
+        }
+    }
+    Ok(count)
+}
+
+/// Number of nulls in `column` among the rows within the ranges
+pub(crate) fn null_count<R>(
+    reader: R,
+    metadata: &ArrowReaderMetadata,
+    ranges: &[ResolvedRange],
+    column: &str,
+) -> Result<u64>
+where
+    R: ChunkReader + 'static,
+{
+    let leaf_idx = leaf_index(metadata.metadata().file_metadata().schema_descr(), column)?;
+    let (covered, mut undecided) = split_row_groups(metadata, ranges)?;
+
+    let mut nulls = 0;
+    for i in covered {
+        let statistics = metadata
+            .metadata()
+            .row_group(i)
+            .column(leaf_idx)
+            .statistics();
+        match statistics.and_then(|s| s.null_count_opt()) {
+            Some(count) => nulls += count,
+            None => undecided.push(i),
+        }
+    }
+    undecided.sort_unstable();
+
+    for batch in scan(reader, metadata, undecided, leaf_idx, ranges)? {
+        nulls += batch?.column(0).null_count() as u64;
+    }
+    Ok(nulls)
+}
+
+/// Smallest (or with `largest`, largest) non null value of `column` among
+/// the rows within the ranges
+pub(crate) fn extreme<R>(
+    reader: R,
+    metadata: &ArrowReaderMetadata,
+    ranges: &[ResolvedRange],
+    column: &str,
+    largest: bool,
+) -> Result<Option<ParquetValue>>
+where
+    R: ChunkReader + 'static,
+{
+    let parquet_metadata = metadata.metadata();
+    let schema_descr = parquet_metadata.file_metadata().schema_descr();
+    let leaf_idx = leaf_index(schema_descr, column)?;
+    let root_idx = schema_descr.get_column_root_idx(leaf_idx);
+    let parquet_field = &schema_descr.root_schema().get_fields()[root_idx];
+    let field = metadata.schema().field(root_idx);
+    if !has_ordered_values(field) {
+        return Err(ParquetError::unsupported(format!(
+            "Column {:?} has no order to take a min or max by",
+            column
+        )));
+    }
+
+    let converter = StatisticsConverter::try_new(column, metadata.schema(), schema_descr)?;
+    let row_groups = parquet_metadata.row_groups();
+    let bounds = if largest {
+        converter.row_group_maxes(row_groups.iter())?
+    } else {
+        converter.row_group_mins(row_groups.iter())?
+    };
+
+    let (mut covered, mut undecided) = split_row_groups(metadata, ranges)?;
+    if is_float(bounds.data_type()) {
+        // NaN, the largest float, never shows up in the statistics
+        undecided.append(&mut covered);
+    }
+    let mut candidates: Vec<ArrayRef> = Vec::new();
+    for i in covered {
+        let row_group = &row_groups[i];
+        let Some(statistics) = row_group.column(leaf_idx).statistics() else {
+            undecided.push(i);
+            continue;
+        };
+        if statistics.null_count_opt() == Some(row_group.num_rows() as u64) {
+            // Only nulls
+            continue;
+        }
+        let exact = if largest {
+            statistics.max_is_exact()
+        } else {
+            statistics.min_is_exact()
+        };
+        if exact && bounds.is_valid(i) {
+            candidates.push(bounds.slice(i, 1));
+        } else {
+            undecided.push(i);
+        }
+    }
+    undecided.sort_unstable();
+
+    for batch in scan(reader, metadata, undecided, leaf_idx, ranges)? {
+        let batch = batch?;
+        let mut values = batch.column(0).clone();
+        if values.data_type() != bounds.data_type() {
+            values = cast(&values, bounds.data_type())?;
+        }
+        if let Some(index) = extreme_index(&values, largest)? {
+            candidates.push(values.slice(index, 1));
+        }
+    }
+
+    if candidates.is_empty() {
+        return Ok(None);
+    }
+    let candidates = concat(&candidates.iter().map(|c| c.as_ref()).collect::<Vec<_>>())?;
+    match extreme_index(&candidates, largest)? {
+        Some(index) => {
+            arrow_to_parquet_value(field, parquet_field, candidates.as_ref(), index).map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
+/// Index of the smallest (or largest) non null value
+fn extreme_index(values: &ArrayRef, largest: bool) -> Result<Option<usize>> {
+    let options = SortOptions {
+        descending: largest,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices(values.as_ref(), Some(options), Some(1))?;
+    Ok(indices
+        .iter()
+        .flatten()
+        .map(|index| index as usize)
+        .find(|&index| values.is_valid(index)))
+}
+
+/// Row groups that may hold rows within the ranges, split into those the
+/// statistics show are entirely within them and the rest, in file order
+fn split_row_groups(
+    metadata: &ArrowReaderMetadata,
+    ranges: &[ResolvedRange],
+) -> Result<(Vec<usize>, Vec<usize>)> {
+    if ranges.is_empty() {
+        return Ok((
+            (0..metadata.metadata().num_row_groups()).collect(),
+            Vec::new(),
+        ));
+    }
+    let covered = covered_row_groups(ranges, metadata)?;
+    Ok(select_row_groups(ranges, metadata)?
+        .into_iter()
+        .partition(|&i| covered[i]))
+}
+
+/// Read one leaf column of the rows within the ranges in `row_groups`
+fn scan<R>(
+    reader: R,
+    metadata: &ArrowReaderMetadata,
+    row_groups: Vec<usize>,
+    leaf_idx: usize,
+    ranges: &[ResolvedRange],
+) -> Result<ParquetRecordBatchReader>
+where
+    R: ChunkReader + 'static,
+{
+    let schema_descr = metadata.metadata().file_metadata().schema_descr();
+    let mut builder = ParquetRecordBatchReaderBuilder::new_with_metadata(reader, metadata.clone())
+        .with_row_groups(row_groups)
+        .with_projection(ProjectionMask::leaves(schema_descr, [leaf_idx]));
+    if !ranges.is_empty() {
+        builder = builder.with_row_filter(row_filter(ranges, schema_descr));
+    }
+    Ok(builder.build()?)
+}
@@ -5,17 +5,27 @@
 //! the conversion logic that was previously duplicated between the reader
 //! and writer modules.
 
-use crate::{ParquetError, ParquetValue, Result};
+use crate::{
+    geometry::GEOMETRY_EXTENSION_NAME,
+    variant::{Variant, VARIANT_EXTENSION_NAME},
+    ParquetError, ParquetValue, Result,
+};
 use arrow_array::{builder::*, Array, ArrayRef, ListArray, MapArray, StructArray};
-use arrow_schema::extension::Uuid as ArrowUuid;
+use arrow_schema::extension::{Json as ArrowJson, Uuid as ArrowUuid};
 use arrow_schema::{DataType, Field};
 use bytes::Bytes;
 use indexmap::IndexMap;
 use ordered_float::OrderedFloat;
-use parquet::basic::LogicalType;
+use parquet::basic::{ConvertedType, LogicalType};
 use parquet::schema::types::Type;
 use std::sync::Arc;
 
+/// Arrow extension name used to mark dictionary encoded ENUM columns
+pub const ENUM_EXTENSION_NAME: &str = "parquet.enum";
+
+/// Arrow extension name used to mark FLBA(12) columns holding Parquet INTERVAL values
+pub const INTERVAL_EXTENSION_NAME: &str = "parquet.interval";
+
 /// Convert a single value from an Arrow array at the given index to a ParquetValue
 pub fn arrow_to_parquet_value(
     arrow_field: &Field,
@@ -83,18 +93,34 @@ pub fn arrow_to_parquet_value(
         // String and binary types
         DataType::Utf8 => {
             let array = downcast_array::<StringArray>(array)?;
-            Ok(ParquetValue::String(Arc::from(array.value(index))))
+            Ok(string_value(arrow_field, parquet_field, array.value(index)))
         }
         DataType::Binary => {
             let array = downcast_array::<BinaryArray>(array)?;
-            Ok(ParquetValue::Bytes(Bytes::copy_from_slice(
-                array.value(index),
-            )))
+            binary_value(arrow_field, parquet_field, array.value(index))
+        }
+        DataType::LargeUtf8 => {
+            let array = downcast_array::<LargeStringArray>(array)?;
+            Ok(string_value(arrow_field, parquet_field, array.value(index)))
+        }
+        DataType::Utf8View => {
+            let array = downcast_array::<StringViewArray>(array)?;
+            Ok(string_value(arrow_field, parquet_field, array.value(index)))
+        }
+        DataType::LargeBinary => {
+            let array = downcast_array::<LargeBinaryArray>(array)?;
+            binary_value(arrow_field, parquet_field, array.value(index))
+        }
+        DataType::BinaryView => {
+            let array = downcast_array::<BinaryViewArray>(array)?;
+            binary_value(arrow_field, parquet_field, array.value(index))
         }
         DataType::FixedSizeBinary(_) => {
             let array = downcast_array::<FixedSizeBinaryArray>(array)?;
             let value = array.value(index);
-            if let Some(LogicalType::Uuid) = parquet_field.get_basic_info().logical_type() {
+            if is_interval_field(arrow_field, parquet_field) {
+                decode_interval(value)
+            } else if let Some(LogicalType::Uuid) = parquet_field.get_basic_info().logical_type() {
                 let uuid = uuid::Uuid::from_slice(value)
                     .map_err(|e| ParquetError::Conversion(format!("Invalid UUID: {}", e)))?;
                 Ok(ParquetValue::Uuid(uuid))
@@ -196,52 +222,85 @@ pub fn arrow_to_parquet_value(
             Ok(ParquetValue::Decimal256(bigint, *scale))
         }
 
-        // Complex types
-        DataType::List(item_field) => {
-            let array = downcast_array::<ListArray>(array)?;
-            let list_values = array.value(index);
-
-            let mut values = Vec::with_capacity(list_values.len());
+        // Duration types
+        DataType::Duration(unit) => match unit {
+            arrow_schema::TimeUnit::Second => {
+                let array = downcast_array::<DurationSecondArray>(array)?;
+                Ok(ParquetValue::DurationSecond(array.value(index)))
+            }
+            arrow_schema::TimeUnit::Millisecond => {
+                let array = downcast_array::<DurationMillisecondArray>(array)?;
+                Ok(ParquetValue::DurationMillis(array.value(index)))
+            }
+            arrow_schema::TimeUnit::Microsecond => {
+                let array = downcast_array::<DurationMicrosecondArray>(array)?;
+                Ok(ParquetValue::DurationMicros(array.value(index)))
+            }
+            arrow_schema::TimeUnit::Nanosecond => {
+                let array = downcast_array::<DurationNanosecondArray>(array)?;
+                Ok(ParquetValue::DurationNanos(array.value(index)))
+            }
+        },
 
-            // Get the list's element type from parquet schema
-            let element_type = match parquet_field {
-                parquet::schema::types::Type::GroupType { fields, .. } => {
-                    // List has a repeated group containing the element
-                    // The structure is: LIST -> repeated group -> element
-                    if let Some(repeated_group) = fields.first() {
-                        match repeated_group.as_ref() {
-                            parquet::schema::types::Type::GroupType {
-                                fields: inner_fields,
-                                ..
-                            } => {
-                                // This is the repeated group, get the actual element
-                                inner_fields.first().ok_or_else(|| {
-                                    ParquetError::Conversion(
-                                        "List repeated group missing element field".to_string(),
-                                    )
-                                })?
-                            }
-                            _ => repeated_group, // If it's not a group, use it directly
-                        }
-                    } else {
-                        return Err(ParquetError::Conversion(
-                            "List type missing fields".to_string(),
-                        ));
-                    }
+        // Interval types - normalised to the Parquet INTERVAL layout
+        DataType::Interval(unit) => match unit {
+            arrow_schema::IntervalUnit::YearMonth => {
+                let array = downcast_array::<IntervalYearMonthArray>(array)?;
+                Ok(ParquetValue::Interval(array.value(index), 0, 0))
+            }
+            arrow_schema::IntervalUnit::DayTime => {
+                let array = downcast_array::<IntervalDayTimeArray>(array)?;
+                let value = array.value(index);
+                Ok(ParquetValue::Interval(0, value.days, value.milliseconds))
+            }
+            arrow_schema::IntervalUnit::MonthDayNano => {
+                let array = downcast_array::<IntervalMonthDayNanoArray>(array)?;
+                let value = array.value(index);
+                // Parquet intervals only carry millisecond precision, so refuse to
+                // drop anything finer
+                if value.nanoseconds % 1_000_000 != 0 {
+                    return Err(ParquetError::Conversion(format!(
+                        "Interval nanoseconds {} are not a whole number of milliseconds",
+                        value.nanoseconds
+                    )));
                 }
-                _ => parquet_field, // Fallback for cases where it's not a proper list structure
-            };
-
-            for i in 0..list_values.len() {
-                values.push(arrow_to_parquet_value(
-                    item_field,
-                    element_type,
-                    &list_values,
-                    i,
-                )?);
+                let millis = i32::try_from(value.nanoseconds / 1_000_000).map_err(|_| {
+                    ParquetError::Conversion(format!(
+                        "Interval nanoseconds {} out of range for milliseconds",
+                        value.nanoseconds
+                    ))
+                })?;
+                Ok(ParquetValue::Interval(value.months, value.days, millis))
             }
+        },
+
+        // Null type - every slot is null regardless of the validity buffer
+        DataType::Null => Ok(ParquetValue::Null),
+
+        // Dictionary encoded values - resolve the key and read straight from the
+        // dictionary values so the full array is never materialised
+        DataType::Dictionary(_, _) => {
+            use arrow_array::cast::AsArray;
 
-            Ok(ParquetValue::List(values))
+            let dictionary = array.as_any_dictionary_opt().ok_or_else(|| {
+                ParquetError::Conversion("Failed to cast to dictionary array".to_string())
+            })?;
+            let key = dictionary_key(dictionary.keys(), index)?;
+            arrow_to_parquet_value(arrow_field, parquet_field, dictionary.values(), key)
+        }
+
+        // Complex types
+        DataType::List(item_field) => {
+            let array = downcast_array::<ListArray>(array)?;
+            list_to_parquet_value(item_field, parquet_field, &array.value(index))
+        }
+        DataType::LargeList(item_field) => {
+            let array = downcast_array::<LargeListArray>(array)?;
+            list_to_parquet_value(item_field, parquet_field, &array.value(index))
+        }
+        DataType::FixedSizeList(item_field, _) => {
+            let array = downcast_array::<FixedSizeListArray>(array)?;
+            list_to_parquet_value(item_field, parquet_field, &array.value(index))
         }
         DataType::Map(_, _) => {
             let array = downcast_array::<MapArray>(array)?;
@@ -337,6 +396,45 @@ pub fn arrow_to_parquet_value(
                 }
             };
 
+            if is_variant_field(arrow_field, parquet_field) {
+                let mut variant = Variant {
+                    metadata: Bytes::new(),
+                    value: None,
+                    typed_value: None,
+                };
+                for (col_idx, field) in array.fields().iter().enumerate() {
+                    let nested_parquet_field = parquet_fields
+                        .iter()
+                        .find(|f| f.name() == field.name())
+                        .ok_or_else(|| {
+                            ParquetError::Conversion(format!(
+                                "No matching parquet field for variant field '{}'",
+                                field.name()
+                            ))
+                        })?;
+                    let value = arrow_to_parquet_value(
+                        field,
+                        nested_parquet_field,
+                        array.column(col_idx),
+                        index,
+                    )?;
+                    match (field.name().as_str(), value) {
+                        ("metadata", ParquetValue::Bytes(b)) => variant.metadata = b,
+                        ("value", ParquetValue::Bytes(b)) => variant.value = Some(b),
+                        (_, ParquetValue::Null) => {}
+                        ("typed_value", typed) => variant.typed_value = Some(Box::new(typed)),
+                        (name, other) => {
+                            return Err(ParquetError::Conversion(format!(
+                                "Expected binary variant {}, got {}",
+                                name,
+                                other.type_name()
+                            )))
+                        }
+                    }
+                }
+                return Ok(ParquetValue::Variant(Box::new(variant)));
+            }
+
             for (col_idx, arrow_field) in array.fields().iter().enumerate() {
                 let column = array.column(col_idx);
 
@@ -405,6 +503,9 @@ pub fn parquet_values_to_arrow_array(values: Vec<ParquetValue>, field: &Field) -
         DataType::Utf8 => build_string_array(values),
         DataType::Binary => build_binary_array(values),
         DataType::FixedSizeBinary(size) => build_fixed_binary_array(values, *size),
+        DataType::Dictionary(key_type, value_type) => {
+            build_dictionary_array(values, key_type, value_type)
+        }
 
         // Date and time
         DataType::Date32 => build_date32_array(values),
@@ -415,6 +516,9 @@ pub fn parquet_values_to_arrow_array(values: Vec<ParquetValue>, field: &Field) -
         // Timestamp
         DataType::Timestamp(unit, tz) => build_timestamp_array(values, unit, tz.as_deref()),
 
+        // Duration
+        DataType::Duration(unit) => build_duration_array(values, unit),
+
         // Decimal
         DataType::Decimal128(precision, scale) => {
             build_decimal128_array(values, *precision, *scale)
@@ -426,6 +530,9 @@ pub fn parquet_values_to_arrow_array(values: Vec<ParquetValue>, field: &Field) -
         // Complex types
         DataType::List(item_field) => build_list_array(values, item_field),
         DataType::Map(entries_field, sorted) => build_map_array(values, entries_field, *sorted),
+        DataType::Struct(fields) if field.extension_type_name() == Some(VARIANT_EXTENSION_NAME) => {
+            build_variant_array(values, fields)
+        }
         DataType::Struct(fields) => build_struct_array(values, fields),
 
         dt => Err(ParquetError::Conversion(format!(
@@ -435,6 +542,158 @@ pub fn parquet_values_to_arrow_array(values: Vec<ParquetValue>, field: &Field) -
     }
 }
 
+/// Wrap a string value, keeping track of columns annotated as JSON or ENUM
+fn string_value(arrow_field: &Field, parquet_field: &Type, value: &str) -> ParquetValue {
+    let is_json = matches!(
+        parquet_field.get_basic_info().logical_type(),
+        Some(LogicalType::Json)
+    ) || arrow_field.try_extension_type::<ArrowJson>().is_ok();
+
+    if is_json {
+        ParquetValue::Json(Arc::from(value))
+    } else if is_enum_field(arrow_field, parquet_field) {
+        ParquetValue::Enum(Arc::from(value))
+    } else {
+        ParquetValue::String(Arc::from(value))
+    }
+}
+
+/// Wrap a binary value, decoding it as text for ENUM annotated columns
+fn binary_value(arrow_field: &Field, parquet_field: &Type, value: &[u8]) -> Result<ParquetValue> {
+    if is_enum_field(arrow_field, parquet_field) {
+        let value = std::str::from_utf8(value)?;
+        Ok(ParquetValue::Enum(Arc::from(value)))
+    } else if arrow_field.extension_type_name() == Some(GEOMETRY_EXTENSION_NAME) {
+        Ok(ParquetValue::Geometry(Bytes::copy_from_slice(value)))
+    } else {
+        Ok(ParquetValue::Bytes(Bytes::copy_from_slice(value)))
+    }
+}
+
+/// Check whether a column holds ENUM values, either from the Parquet
+/// logical type or the Arrow field written by this library
+pub fn is_enum_field(arrow_field: &Field, parquet_field: &Type) -> bool {
+    matches!(
+        parquet_field.get_basic_info().logical_type(),
+        Some(LogicalType::Enum)
+    ) || arrow_field.extension_type_name() == Some(ENUM_EXTENSION_NAME)
+}
+
+/// Check whether a struct column is a VARIANT group, either from the Parquet
+/// logical type or the Arrow extension name
+fn is_variant_field(arrow_field: &Field, parquet_field: &Type) -> bool {
+    matches!(
+        parquet_field.get_basic_info().logical_type(),
+        Some(LogicalType::Variant { .. })
+    ) || arrow_field.extension_type_name() == Some(VARIANT_EXTENSION_NAME)
+}
+
+/// Check whether a column holds Parquet INTERVAL values
+fn is_interval_field(arrow_field: &Field, parquet_field: &Type) -> bool {
+    parquet_field.get_basic_info().converted_type() == ConvertedType::INTERVAL
+        || arrow_field.extension_type_name() == Some(INTERVAL_EXTENSION_NAME)
+}
+
+/// Decode the little-endian months, days and milliseconds of a Parquet INTERVAL
+fn decode_interval(value: &[u8]) -> Result<ParquetValue> {
+    if value.len() != 12 {
+        return Err(ParquetError::Conversion(format!(
+            "Interval expected 12 bytes, got {}",
+            value.len()
+        )));
+    }
+    let part = |i: usize| i32::from_le_bytes([value[i], value[i + 1], value[i + 2], value[i + 3]]);
+    Ok(ParquetValue::Interval(part(0), part(4), part(8)))
+}
+
+/// Encode an interval in the Parquet INTERVAL layout
+fn encode_interval(months: i32, days: i32, millis: i32) -> [u8; 12] {
+    let mut bytes = [0u8; 12];
+    bytes[0..4].copy_from_slice(&months.to_le_bytes());
+    bytes[4..8].copy_from_slice(&days.to_le_bytes());
+    bytes[8..12].copy_from_slice(&millis.to_le_bytes());
+    bytes
+}
+
+/// Convert the values of a single list slot, whatever the list layout
+fn list_to_parquet_value(
+    item_field: &Field,
+    parquet_field: &Type,
+    list_values: &ArrayRef,
+) -> Result<ParquetValue> {
+    let mut values = Vec::with_capacity(list_values.len());
+
+    // Get the list's element type from parquet schema
+    let element_type = match parquet_field {
+        parquet::schema::types::Type::GroupType { fields, .. } => {
+            // List has a repeated group containing the element
+            // The structure is: LIST -> repeated group -> element
+            if let Some(repeated_group) = fields.first() {
+                match repeated_group.as_ref() {
+                    parquet::schema::types::Type::GroupType {
+                        fields: inner_fields,
+                        ..
+                    } => {
+                        // This is the repeated group, get the actual element
+                        inner_fields.first().ok_or_else(|| {
+                            ParquetError::Conversion(
+                                "List repeated group missing element field".to_string(),
+                            )
+                        })?
+                    }
+                    _ => repeated_group, // If it's not a group, use it directly
+                }
+            } else {
+                return Err(ParquetError::Conversion(
+                    "List type missing fields".to_string(),
+                ));
+            }
+        }
+        _ => parquet_field, // Fallback for cases where it's not a proper list structure
+    };
+
+    for i in 0..list_values.len() {
+        values.push(arrow_to_parquet_value(
+            item_field,
+            element_type,
+            list_values,
+            i,
+        )?);
+    }
+
+    Ok(ParquetValue::List(values))
+}
+
+/// Read a dictionary key as an index into the dictionary values
+pub(crate) fn dictionary_key(keys: &dyn Array, index: usize) -> Result<usize> {
+    use arrow_array::*;
+
+    let key = match keys.data_type() {
+        DataType::Int8 => downcast_array::<Int8Array>(keys)?.value(index) as i64,
+        DataType::Int16 => downcast_array::<Int16Array>(keys)?.value(index) as i64,
+        DataType::Int32 => downcast_array::<Int32Array>(keys)?.value(index) as i64,
+        DataType::Int64 => downcast_array::<Int64Array>(keys)?.value(index),
+        DataType::UInt8 => downcast_array::<UInt8Array>(keys)?.value(index) as i64,
+        DataType::UInt16 => downcast_array::<UInt16Array>(keys)?.value(index) as i64,
+        DataType::UInt32 => downcast_array::<UInt32Array>(keys)?.value(index) as i64,
+        DataType::UInt64 => {
+            let key = downcast_array::<UInt64Array>(keys)?.value(index);
+            i64::try_from(key).map_err(|_| {
+                ParquetError::Conversion(format!("Dictionary key {} out of range", key))
+            })?
+        }
+        dt => {
+            return Err(ParquetError::Conversion(format!(
+                "Unsupported dictionary key type: {:?}",
+                dt
+            )))
+        }
+    };
+
+    usize::try_from(key)
+        .map_err(|_| ParquetError::Conversion(format!("Invalid dictionary key: {}", key)))
+}
+
 /// Helper function to downcast an array with better error messages
 fn downcast_array<T: 'static>(array: &dyn Array) -> Result<&T> {
     array.as_any().downcast_ref::<T>().ok_or_else(|| {
@@ -642,7 +901,7 @@ fn build_string_array(values: Vec<ParquetValue>) -> Result<ArrayRef> {
     let mut builder = StringBuilder::with_capacity(values.len(), 0);
     for value in values {
         match value {
-            ParquetValue::String(s) => builder.append_value(&s),
+            ParquetValue::String(s) | ParquetValue::Json(s) => builder.append_value(&s),
             ParquetValue::Null => builder.append_null(),
             _ => {
                 return Err(ParquetError::Conversion(format!(
@@ -655,12 +914,44 @@ fn build_string_array(values: Vec<ParquetValue>) -> Result<ArrayRef> {
     Ok(Arc::new(builder.finish()))
 }
 
+/// Build dictionary encoded string array
+fn build_dictionary_array(
+    values: Vec<ParquetValue>,
+    key_type: &DataType,
+    value_type: &DataType,
+) -> Result<ArrayRef> {
+    if key_type != &DataType::Int32 || value_type != &DataType::Utf8 {
+        return Err(ParquetError::Conversion(format!(
+            "Unsupported dictionary type: Dictionary({:?}, {:?})",
+            key_type, value_type
+        )));
+    }
+
+    let mut builder =
+        StringDictionaryBuilder::<arrow_array::types::Int32Type>::with_capacity(values.len(), 0, 0);
+    for value in values {
+        match value {
+            ParquetValue::Enum(s) | ParquetValue::String(s) => {
+                builder.append(&s)?;
+            }
+            ParquetValue::Null => builder.append_null(),
+            _ => {
+                return Err(ParquetError::Conversion(format!(
+                    "Expected Enum, got {:?}",
+                    value.type_name()
+                )))
+            }
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
 /// Build binary array
 fn build_binary_array(values: Vec<ParquetValue>) -> Result<ArrayRef> {
     let mut builder = BinaryBuilder::with_capacity(values.len(), 0);
     for value in values {
         match value {
-            ParquetValue::Bytes(b) => builder.append_value(&b),
+            ParquetValue::Bytes(b) | ParquetValue::Geometry(b) => builder.append_value(&b),
             ParquetValue::Null => builder.append_null(),
             _ => {
                 return Err(ParquetError::Conversion(format!(
@@ -688,6 +979,9 @@ fn build_fixed_binary_array(values: Vec<ParquetValue>, size: i32) -> Result<Arra
                 }
                 builder.append_value(&b)?;
             }
+            ParquetValue::Interval(months, days, millis) if size == 12 => {
+                builder.append_value(encode_interval(months, days, millis))?;
+            }
             ParquetValue::Null => builder.append_null(),
             _ => {
                 return Err(ParquetError::Conversion(format!(
@@ -800,32 +1094,9 @@ fn build_timestamp_array(
     unit: &arrow_schema::TimeUnit,
     timezone: Option<&str>,
 ) -> Result<ArrayRef> {
-    // First, check if all values have the same timezone (or use the field timezone)
-    let mut common_tz: Option<Option<Arc<str>>> = None;
-    for value in &values {
-        match value {
-            ParquetValue::TimestampSecond(_, tz)
-            | ParquetValue::TimestampMillis(_, tz)
-            | ParquetValue::TimestampMicros(_, tz)
-            | ParquetValue::TimestampNanos(_, tz) => {
-                match &common_tz {
-                    None => common_tz = Some(tz.clone()),
-                    Some(existing) => {
-                        // If we have mixed timezones, we'll use the field timezone
-                        if existing != tz {
-                            common_tz = Some(timezone.map(Arc::from));
-                            break;
-                        }
-                    }
-                }
-            }
-            ParquetValue::Null => {}
-            _ => {}
-        }
-    }
-
-    // Use the common timezone from values, or fall back to field timezone
-    let tz = common_tz.unwrap_or_else(|| timezone.map(Arc::from));
+    // Values are instants, so the array takes the field's timezone whatever
+    // zone they were tagged with; anything else wouldn't match the schema
+    let tz = timezone.map(Arc::from);
 
     match unit {
         arrow_schema::TimeUnit::Second => {
@@ -899,6 +1170,37 @@ fn build_timestamp_array(
     }
 }
 
+/// Build Duration array
+fn build_duration_array(
+    values: Vec<ParquetValue>,
+    unit: &arrow_schema::TimeUnit,
+) -> Result<ArrayRef> {
+    use arrow_schema::TimeUnit;
+
+    let durations = values
+        .into_iter()
+        .map(|value| match (unit, value) {
+            (_, ParquetValue::Null) => Ok(None),
+            (TimeUnit::Second, ParquetValue::DurationSecond(d))
+            | (TimeUnit::Millisecond, ParquetValue::DurationMillis(d))
+            | (TimeUnit::Microsecond, ParquetValue::DurationMicros(d))
+            | (TimeUnit::Nanosecond, ParquetValue::DurationNanos(d)) => Ok(Some(d)),
+            (_, value) => Err(ParquetError::Conversion(format!(
+                "Expected Duration({:?}), got {:?}",
+                unit,
+                value.type_name()
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(match unit {
+        TimeUnit::Second => Arc::new(arrow_array::DurationSecondArray::from(durations)),
+        TimeUnit::Millisecond => Arc::new(arrow_array::DurationMillisecondArray::from(durations)),
+        TimeUnit::Microsecond => Arc::new(arrow_array::DurationMicrosecondArray::from(durations)),
+        TimeUnit::Nanosecond => Arc::new(arrow_array::DurationNanosecondArray::from(durations)),
+    })
+}
+
 /// Build Decimal128 array
 fn build_decimal128_array(values: Vec<ParquetValue>, precision: u8, scale: i8) -> Result<ArrayRef> {
     let mut builder = Decimal128Builder::with_capacity(values.len())
@@ -1141,6 +1443,63 @@ fn build_struct_array(
     )))
 }
 
+/// Build a VARIANT group, encoding plain values as unshredded variants
+fn build_variant_array(
+    values: Vec<ParquetValue>,
+    fields: &arrow_schema::Fields,
+) -> Result<ArrayRef> {
+    let mut metadata_builder = BinaryBuilder::with_capacity(values.len(), 0);
+    let mut value_builder = BinaryBuilder::with_capacity(values.len(), 0);
+    let mut null_buffer_builder = arrow_buffer::BooleanBufferBuilder::new(values.len());
+
+    for value in values {
+        let variant = match value {
+            ParquetValue::Null => {
+                metadata_builder.append_null();
+                value_builder.append_null();
+                null_buffer_builder.append(false);
+                continue;
+            }
+            // Shredded variants are written back unshredded
+            ParquetValue::Variant(v) if v.value.is_some() && v.typed_value.is_none() => *v,
+            ParquetValue::Variant(v) => Variant::from_value(&v.decode()?)?,
+            other => Variant::from_value(&other)?,
+        };
+
+        metadata_builder.append_value(&variant.metadata);
+        match &variant.value {
+            Some(v) => value_builder.append_value(v),
+            None => value_builder.append_null(),
+        }
+        null_buffer_builder.append(true);
+    }
+
+    let mut metadata = Some(Arc::new(metadata_builder.finish()) as ArrayRef);
+    let mut value = Some(Arc::new(value_builder.finish()) as ArrayRef);
+    let columns = fields
+        .iter()
+        .map(|field| {
+            match field.name().as_str() {
+                "metadata" => metadata.take(),
+                "value" => value.take(),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                ParquetError::Conversion(format!(
+                    "Unexpected field '{}' in variant group",
+                    field.name()
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        columns,
+        Some(null_buffer_builder.finish().into()),
+    )?))
+}
+
 /// Append a single ParquetValue to an ArrayBuilder
 /// This is used for incremental building in complex scenarios
 pub fn append_parquet_value_to_builder(
@@ -0,0 +1,587 @@
+//! Streaming conversion between Parquet and other Arrow based formats
+//!
+//! Record batches go straight from one format's Arrow reader into the other
+//! format's writer, so no value is ever turned into a [`ParquetValue`].
+//!
+//! [`ParquetValue`]: crate::ParquetValue
+
+use crate::{reader::Reader, writer::WriterBuilder, ParquetError, Result, Schema, SchemaNode};
+use arrow::csv::reader::Format as CsvFormat;
+use arrow::csv::{ReaderBuilder as CsvReaderBuilder, WriterBuilder as CsvWriterBuilder};
+use arrow::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow::ipc::reader::{FileReader as IpcFileReader, StreamReader as IpcStreamReader};
+use arrow::ipc::writer::{FileWriter as IpcFileWriter, StreamWriter as IpcStreamWriter};
+use arrow::json::reader::infer_json_schema_from_seekable;
+use arrow::json::{LineDelimitedWriter, ReaderBuilder as JsonReaderBuilder};
+use arrow::record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{Array, ArrayRef, PrimitiveArray, StructArray};
+use arrow_schema::{DataType, Field, SchemaRef, TimeUnit};
+use jiff::civil::Date;
+use jiff::fmt::strtime;
+use jiff::tz::TimeZone;
+use parquet::file::reader::ChunkReader;
+use regex::Regex;
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+use std::sync::Arc;
+
+/// Rows read to infer a CSV schema when none is given
+const CSV_INFER_ROWS: usize = 1000;
+
+/// Leading bytes of an Arrow IPC file; streams start with a message instead
+const IPC_FILE_MAGIC: &[u8; 6] = b"ARROW1";
+
+/// Write the rows of a Parquet file as newline delimited JSON
+///
+/// Returns the number of rows written.
+pub fn parquet_to_ndjson<R, W>(
+    reader: Reader<R>,
+    output: W,
+    columns: Option<&[String]>,
+) -> Result<usize>
+where
+    R: ChunkReader + Clone + 'static,
+    W: Write,
+{
+    let mut writer = LineDelimitedWriter::new(output);
+    let mut rows = 0;
+    for batch in reader.read_batches(columns, None)? {
+        let batch = batch?;
+        rows += batch.num_rows();
+        writer.write(&batch)?;
+    }
+    writer.finish()?;
+    writer.into_inner().flush()?;
+    Ok(rows)
+}
+
+/// Write newline delimited JSON as a Parquet file
+///
+/// Without a `schema` one is inferred from every record first, which is why
+/// the input must be seekable. Returns the number of rows written.
+pub fn ndjson_to_parquet<R, W>(
+    mut input: R,
+    output: W,
+    schema: Option<Schema>,
+    builder: WriterBuilder,
+) -> Result<usize>
+where
+    R: BufRead + Seek,
+    W: Write + Send,
+{
+    let mut writer = match schema {
+        Some(schema) => builder.build(output, schema)?,
+        None => {
+            let (inferred, _) = infer_json_schema_from_seekable(&mut input, None)?;
+            builder.build_with_arrow_schema(output, Arc::new(inferred))?
+        }
+    };
+
+    let reader = JsonReaderBuilder::new(writer.arrow_schema().clone()).build(input)?;
+    let mut rows = 0;
+    for batch in reader {
+        let batch = batch?;
+        rows += batch.num_rows();
+        writer.write_batch(&batch)?;
+    }
+    writer.close()?;
+    Ok(rows)
+}
+
+/// Options for reading and writing CSV
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    header: bool,
+    delimiter: u8,
+    null_values: Vec<String>,
+    date_format: Option<String>,
+    timestamp_format: Option<String>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            header: true,
+            delimiter: b',',
+            null_values: vec![String::new()],
+            date_format: None,
+            timestamp_format: None,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Create options for comma separated files with a header row
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the first row names the columns
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Set the field delimiter
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the strings read as null; the first one is written for nulls
+    pub fn with_null_values(mut self, null_values: Vec<String>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Set the `strftime` style format of date columns
+    pub fn with_date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = Some(format.into());
+        self
+    }
+
+    /// Set the `strftime` style format of timestamp columns
+    pub fn with_timestamp_format(mut self, format: impl Into<String>) -> Self {
+        self.timestamp_format = Some(format.into());
+        self
+    }
+
+    fn format(&self) -> Result<CsvFormat> {
+        let mut format = CsvFormat::default()
+            .with_header(self.header)
+            .with_delimiter(self.delimiter);
+        if !self.null_values.is_empty() {
+            let pattern = self
+                .null_values
+                .iter()
+                .map(|v| regex::escape(v))
+                .collect::<Vec<_>>()
+                .join("|");
+            let null_regex = Regex::new(&format!("^(?:{})$", pattern))
+                .map_err(|e| ParquetError::invalid_argument(e.to_string()))?;
+            format = format.with_null_regex(null_regex);
+        }
+        Ok(format)
+    }
+}
+
+/// Write the rows of a Parquet file as CSV
+///
+/// Nested columns cannot be written as CSV. Returns the number of rows written.
+pub fn parquet_to_csv<R, W>(
+    reader: Reader<R>,
+    output: W,
+    columns: Option<&[String]>,
+    options: &CsvOptions,
+) -> Result<usize>
+where
+    R: ChunkReader + Clone + 'static,
+    W: Write,
+{
+    let mut builder = CsvWriterBuilder::new()
+        .with_header(options.header)
+        .with_delimiter(options.delimiter)
+        .with_null(options.null_values.first().cloned().unwrap_or_default());
+    if let Some(format) = &options.date_format {
+        builder = builder.with_date_format(format.clone());
+    }
+    if let Some(format) = &options.timestamp_format {
+        builder = builder
+            .with_timestamp_format(format.clone())
+            .with_timestamp_tz_format(format.clone());
+    }
+
+    let mut writer = builder.build(output);
+    let mut rows = 0;
+    for batch in reader.read_batches(columns, None)? {
+        let batch = batch?;
+        rows += batch.num_rows();
+        writer.write(&batch)?;
+    }
+    writer.into_inner().flush()?;
+    Ok(rows)
+}
+
+/// Write CSV as a Parquet file
+///
+/// Without a `schema` one is inferred from the first rows. With one, date
+/// and timestamp columns are parsed with the field's `format`, falling back
+/// to the options' date or timestamp format, and to ISO 8601 otherwise.
+/// Returns the number of rows written.
+pub fn csv_to_parquet<R, W>(
+    mut input: R,
+    output: W,
+    schema: Option<Schema>,
+    options: &CsvOptions,
+    builder: WriterBuilder,
+) -> Result<usize>
+where
+    R: Read + Seek,
+    W: Write + Send,
+{
+    let format = options.format()?;
+
+    let (mut writer, field_formats) = match schema {
+        Some(schema) => {
+            let field_formats = match &schema.root {
+                SchemaNode::Struct { fields, .. } => fields
+                    .iter()
+                    .map(|field| match field {
+                        SchemaNode::Primitive { format, .. } => format.clone(),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            (builder.build(output, schema)?, field_formats)
+        }
+        None => {
+            let (inferred, _) = format.infer_schema(&mut input, Some(CSV_INFER_ROWS))?;
+            input.seek(SeekFrom::Start(0))?;
+            (
+                builder.build_with_arrow_schema(output, Arc::new(inferred))?,
+                Vec::new(),
+            )
+        }
+    };
+
+    // Dates and timestamps with a format are read as text and parsed here
+    let target_schema = writer.arrow_schema().clone();
+    let parse_formats = target_schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            let field_format = field_formats.get(idx).cloned().flatten();
+            match field.data_type() {
+                DataType::Date32 | DataType::Date64 => {
+                    field_format.or_else(|| options.date_format.clone())
+                }
+                DataType::Timestamp(_, _) => {
+                    field_format.or_else(|| options.timestamp_format.clone())
+                }
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    let read_schema = arrow_schema::Schema::new(
+        target_schema
+            .fields()
+            .iter()
+            .zip(&parse_formats)
+            .map(|(field, parse_format)| match parse_format {
+                Some(_) => Arc::new(Field::new(field.name(), DataType::Utf8, true)),
+                None => field.clone(),
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    let reader = CsvReaderBuilder::new(Arc::new(read_schema))
+        .with_format(format)
+        .build(input)?;
+    let mut rows = 0;
+    for batch in reader {
+        let batch = batch?;
+        rows += batch.num_rows();
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(target_schema.fields())
+            .zip(&parse_formats)
+            .map(|((column, field), parse_format)| match parse_format {
+                Some(parse_format) => parse_temporal(column, field.data_type(), parse_format),
+                None => Ok(column.clone()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        writer.write_batch(&RecordBatch::try_new(target_schema.clone(), columns)?)?;
+    }
+    writer.close()?;
+    Ok(rows)
+}
+
+/// Parse a text column into dates or timestamps with a `strftime` format
+fn parse_temporal(column: &ArrayRef, data_type: &DataType, format: &str) -> Result<ArrayRef> {
+    let strings = column.as_string::<i32>();
+    let parse_error = |value: &str, e: jiff::Error| {
+        ParquetError::conversion(format!(
+            "Failed to parse {:?} with format {:?}: {}",
+            value, format, e
+        ))
+    };
+
+    let epoch = jiff::civil::date(1970, 1, 1);
+    let days = |value: &str| -> Result<i64> {
+        let date = Date::strptime(format, value).map_err(|e| parse_error(value, e))?;
+        Ok(epoch
+            .until(date)
+            .map_err(|e| parse_error(value, e))?
+            .get_days() as i64)
+    };
+    let timestamp = |value: &str| -> Result<jiff::Timestamp> {
+        let parsed = strtime::parse(format, value).map_err(|e| parse_error(value, e))?;
+        // Values without an offset are taken to be UTC
+        parsed.to_timestamp().or_else(|_| {
+            parsed
+                .to_datetime()
+                .and_then(|dt| dt.to_zoned(TimeZone::UTC))
+                .map(|zoned| zoned.timestamp())
+                .map_err(|e| parse_error(value, e))
+        })
+    };
+
+    fn collect<T: ArrowPrimitiveType>(
+        strings: &arrow_array::StringArray,
+        mut parse: impl FnMut(&str) -> Result<T::Native>,
+    ) -> Result<PrimitiveArray<T>> {
+        strings
+            .iter()
+            .map(|value| value.map(&mut parse).transpose())
+            .collect()
+    }
+
+    let array: ArrayRef = match data_type {
+        DataType::Date32 => Arc::new(collect::<Date32Type>(strings, |v| Ok(days(v)? as i32))?),
+        DataType::Date64 => Arc::new(collect::<Date64Type>(strings, |v| {
+            Ok(days(v)? * 86_400_000)
+        })?),
+        DataType::Timestamp(unit, tz) => match unit {
+            TimeUnit::Second => Arc::new(
+                collect::<TimestampSecondType>(strings, |v| Ok(timestamp(v)?.as_second()))?
+                    .with_timezone_opt(tz.clone()),
+            ),
+            TimeUnit::Millisecond => Arc::new(
+                collect::<TimestampMillisecondType>(strings, |v| {
+                    Ok(timestamp(v)?.as_millisecond())
+                })?
+                .with_timezone_opt(tz.clone()),
+            ),
+            TimeUnit::Microsecond => Arc::new(
+                collect::<TimestampMicrosecondType>(strings, |v| {
+                    Ok(timestamp(v)?.as_microsecond())
+                })?
+                .with_timezone_opt(tz.clone()),
+            ),
+            TimeUnit::Nanosecond => Arc::new(
+                collect::<TimestampNanosecondType>(strings, |v| {
+                    i64::try_from(timestamp(v)?.as_nanosecond())
+                        .map_err(|_| ParquetError::conversion(format!("{:?} is out of range", v)))
+                })?
+                .with_timezone_opt(tz.clone()),
+            ),
+        },
+        other => {
+            return Err(ParquetError::unsupported(format!(
+                "Cannot parse {} with a format",
+                other
+            )))
+        }
+    };
+    Ok(array)
+}
+
+/// Layout of Arrow IPC data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IpcFormat {
+    /// Random access file format, also known as Feather V2
+    #[default]
+    File,
+    /// Streaming format, read front to back
+    Stream,
+}
+
+/// Write the rows of a Parquet file as Arrow IPC
+///
+/// Returns the number of rows written.
+pub fn parquet_to_arrow_ipc<R, W>(
+    reader: Reader<R>,
+    output: W,
+    columns: Option<&[String]>,
+    format: IpcFormat,
+) -> Result<usize>
+where
+    R: ChunkReader + Clone + 'static,
+    W: Write,
+{
+    let batches = reader.read_batches(columns, None)?;
+    let schema = batches.schema();
+    let mut rows = 0;
+
+    match format {
+        IpcFormat::File => {
+            let mut writer = IpcFileWriter::try_new(output, &schema)?;
+            for batch in batches {
+                let batch = batch?;
+                rows += batch.num_rows();
+                writer.write(&batch)?;
+            }
+            writer.finish()?;
+            writer.into_inner()?.flush()?;
+        }
+        IpcFormat::Stream => {
+            let mut writer = IpcStreamWriter::try_new(output, &schema)?;
+            for batch in batches {
+                let batch = batch?;
+                rows += batch.num_rows();
+                writer.write(&batch)?;
+            }
+            writer.finish()?;
+            writer.into_inner()?.flush()?;
+        }
+    }
+    Ok(rows)
+}
+
+/// Write Arrow IPC data as a Parquet file
+///
+/// The IPC file and stream formats are told apart by their leading bytes.
+/// The Parquet schema follows the IPC schema unless `schema` is given, in
+/// which case each column is cast to it. Returns the number of rows written.
+pub fn arrow_ipc_to_parquet<R, W>(
+    mut input: R,
+    output: W,
+    schema: Option<Schema>,
+    builder: WriterBuilder,
+) -> Result<usize>
+where
+    R: Read + Seek,
+    W: Write + Send,
+{
+    let mut magic = [0u8; IPC_FILE_MAGIC.len()];
+    let is_file = input.read_exact(&mut magic).is_ok() && &magic == IPC_FILE_MAGIC;
+    input.seek(SeekFrom::Start(0))?;
+
+    if is_file {
+        let batches = IpcFileReader::try_new(input, None)?;
+        write_record_batches(batches, output, schema, builder)
+    } else {
+        let batches = IpcStreamReader::try_new(input, None)?;
+        write_record_batches(batches, output, schema, builder)
+    }
+}
+
+/// Write the record batches of an Arrow C stream as a Parquet file
+///
+/// This is how Arrow data held by another library in the same process is
+/// written without copying it through IPC. The stream is moved out of
+/// `stream`, leaving it released, and is released in turn once read; the
+/// memory `stream` points to stays with the caller. The schema is handled
+/// as in [`arrow_ipc_to_parquet`]. Returns the number of rows written.
+///
+/// # Safety
+///
+/// `stream` must point to a valid `ArrowArrayStream` that nothing else
+/// uses while this runs.
+pub unsafe fn arrow_c_stream_to_parquet<W: Write + Send>(
+    stream: *mut FFI_ArrowArrayStream,
+    output: W,
+    schema: Option<Schema>,
+    builder: WriterBuilder,
+) -> Result<usize> {
+    let batches = ArrowArrayStreamReader::from_raw(stream)?;
+    write_record_batches(batches, output, schema, builder)
+}
+
+/// Write an Arrow C array holding a struct of columns as a Parquet file
+///
+/// This is the single record batch counterpart of
+/// [`arrow_c_stream_to_parquet`]; both structs are moved out and released
+/// the same way. Returns the number of rows written.
+///
+/// # Safety
+///
+/// `array` and `array_schema` must point to a valid `ArrowArray` and
+/// `ArrowSchema` that nothing else uses while this runs.
+pub unsafe fn arrow_c_array_to_parquet<W: Write + Send>(
+    array: *mut FFI_ArrowArray,
+    array_schema: *mut FFI_ArrowSchema,
+    output: W,
+    schema: Option<Schema>,
+    builder: WriterBuilder,
+) -> Result<usize> {
+    let array_schema = FFI_ArrowSchema::from_raw(array_schema);
+    let data = from_ffi(FFI_ArrowArray::from_raw(array), &array_schema)?;
+    if !matches!(data.data_type(), DataType::Struct(_)) {
+        return Err(ParquetError::schema(format!(
+            "Expected an Arrow struct array of columns, got {}",
+            data.data_type()
+        )));
+    }
+    data.validate_full()?;
+    // A null row can't be a record batch row, and converting one panics
+    if data.null_count() > 0 {
+        return Err(ParquetError::schema(format!(
+            "Expected an Arrow struct array of columns without null rows, got {} null rows",
+            data.null_count()
+        )));
+    }
+    let (fields, columns, _) = StructArray::from(data).into_parts();
+    let batch = RecordBatch::try_new(Arc::new(arrow_schema::Schema::new(fields)), columns)?;
+    let batch_schema = batch.schema();
+    let batches = RecordBatchIterator::new([Ok(batch)], batch_schema);
+    write_record_batches(batches, output, schema, builder)
+}
+
+/// Write record batches through a [`Writer`](crate::Writer), with the
+/// schema of the batches unless one is given
+pub(crate) fn write_record_batches<W: Write + Send>(
+    batches: impl RecordBatchReader,
+    output: W,
+    schema: Option<Schema>,
+    builder: WriterBuilder,
+) -> Result<usize> {
+    let mut writer = match schema {
+        Some(schema) => builder.build(output, schema)?,
+        None => builder.build_with_arrow_schema(output, batches.schema())?,
+    };
+    let target = writer.arrow_schema().clone();
+
+    let mut rows = 0;
+    for batch in batches {
+        let batch = cast_batch(batch?, &target)?;
+        rows += batch.num_rows();
+        writer.write_batch(&batch)?;
+    }
+    writer.close()?;
+    Ok(rows)
+}
+
+/// Cast the columns of a batch to the types of the fields with their names
+fn cast_batch(batch: RecordBatch, schema: &SchemaRef) -> Result<RecordBatch> {
+    let batch_schema = batch.schema();
+    if let Some(extra) = batch_schema
+        .fields()
+        .iter()
+        .find(|field| schema.field_with_name(field.name()).is_err())
+    {
+        return Err(ParquetError::schema(format!(
+            "Arrow data has column {} which the schema doesn't have",
+            extra.name()
+        )));
+    }
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let column = batch.column_by_name(field.name()).ok_or_else(|| {
+                ParquetError::schema(format!("Arrow data is missing column {}", field.name()))
+            })?;
+            if column.data_type() == field.data_type() {
+                return Ok(column.clone());
+            }
+            arrow::compute::cast(column, field.data_type()).map_err(|e| {
+                ParquetError::conversion(format!(
+                    "Cannot cast column {} from {} to {}: {}",
+                    field.name(),
+                    column.data_type(),
+                    field.data_type(),
+                    e
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(RecordBatch::try_new(schema.clone(), columns)?)
+}
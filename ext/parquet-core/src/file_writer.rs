@@ -0,0 +1,252 @@
+//! Arrow record batch writer that keeps Parquet-only annotations
+//!
+//! `ArrowWriter` derives the Parquet schema from the Arrow schema alone, so
+//! columns this library tags with its own extension names lose their Parquet
+//! logical type. [`FileWriter`] converts the Arrow schema the same way, then
+//! annotates those columns before encoding batches through the Arrow column
+//! writers, so other Parquet readers see the same types we do.
+
+use crate::{
+    arrow_conversion::{ENUM_EXTENSION_NAME, INTERVAL_EXTENSION_NAME},
+    variant::VARIANT_EXTENSION_NAME,
+    ParquetError, Result,
+};
+use arrow::record_batch::RecordBatch;
+use arrow_schema::{DataType, Field, SchemaRef};
+use parquet::arrow::arrow_writer::{compute_leaves, get_column_writers, ArrowColumnWriter};
+use parquet::arrow::{add_encoded_arrow_schema_to_metadata, ArrowSchemaConverter};
+use parquet::basic::{ConvertedType, LogicalType};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::format::KeyValue;
+use parquet::schema::types::{SchemaDescriptor, Type, TypePtr};
+use std::sync::Arc;
+
+/// Writes record batches to a Parquet file with an annotated schema
+///
+/// Mirrors the parts of `ArrowWriter` the core writer uses: batches are
+/// buffered into a row group until `max_row_group_size` rows or an explicit
+/// [`FileWriter::flush`].
+pub struct FileWriter<W: std::io::Write + Send> {
+    writer: SerializedFileWriter<W>,
+    arrow_schema: SchemaRef,
+    parquet_schema: SchemaDescriptor,
+    props: Arc<WriterProperties>,
+    in_progress: Option<Vec<ArrowColumnWriter>>,
+    in_progress_rows: usize,
+}
+
+impl<W: std::io::Write + Send> FileWriter<W> {
+    /// Create a writer for batches of `arrow_schema`
+    pub fn try_new(
+        writer: W,
+        arrow_schema: SchemaRef,
+        mut props: WriterProperties,
+    ) -> Result<Self> {
+        let parquet_schema = annotated_schema(&arrow_schema, props.coerce_types())?;
+        add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
+        let props = Arc::new(props);
+        let writer =
+            SerializedFileWriter::new(writer, parquet_schema.root_schema_ptr(), props.clone())?;
+
+        Ok(Self {
+            writer,
+            arrow_schema,
+            parquet_schema,
+            props,
+            in_progress: None,
+            in_progress_rows: 0,
+        })
+    }
+
+    /// Encode a batch into the current row group, starting new row groups
+    /// as `max_row_group_size` is reached
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let max_rows = self.props.max_row_group_size();
+        if self.in_progress_rows + batch.num_rows() > max_rows {
+            let to_write = max_rows - self.in_progress_rows;
+            self.write(&batch.slice(0, to_write))?;
+            return self.write(&batch.slice(to_write, batch.num_rows() - to_write));
+        }
+
+        let writers = match &mut self.in_progress {
+            Some(writers) => writers,
+            None => self.in_progress.insert(get_column_writers(
+                &self.parquet_schema,
+                &self.props,
+                &self.arrow_schema,
+            )?),
+        };
+
+        let mut leaf_writers = writers.iter_mut();
+        for (field, column) in self.arrow_schema.fields().iter().zip(batch.columns()) {
+            for leaf in compute_leaves(field, column)? {
+                let writer = leaf_writers.next().ok_or_else(|| {
+                    ParquetError::Internal(format!(
+                        "No column writer left for a leaf of {}",
+                        field.name()
+                    ))
+                })?;
+                writer.write(&leaf)?;
+            }
+        }
+        self.in_progress_rows += batch.num_rows();
+
+        if self.in_progress_rows >= max_rows {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Estimated encoded size of the row group in progress
+    pub fn in_progress_size(&self) -> usize {
+        self.in_progress.as_ref().map_or(0, |writers| {
+            writers.iter().map(|w| w.get_estimated_total_bytes()).sum()
+        })
+    }
+
+    /// Memory held by the row group in progress
+    pub fn memory_size(&self) -> usize {
+        self.in_progress
+            .as_ref()
+            .map_or(0, |writers| writers.iter().map(|w| w.memory_size()).sum())
+    }
+
+    /// Write the row group in progress, if any
+    pub fn flush(&mut self) -> Result<()> {
+        let Some(writers) = self.in_progress.take() else {
+            return Ok(());
+        };
+        self.in_progress_rows = 0;
+
+        let mut row_group = self.writer.next_row_group()?;
+        for writer in writers {
+            writer.close()?.append_to_row_group(&mut row_group)?;
+        }
+        row_group.close()?;
+        Ok(())
+    }
+
+    /// Add a key/value pair to the file footer metadata
+    pub fn append_key_value_metadata(&mut self, kv: KeyValue) {
+        self.writer.append_key_value_metadata(kv);
+    }
+
+    /// Write the last row group and the file footer
+    pub fn close(mut self) -> Result<()> {
+        self.flush()?;
+        self.writer.close()?;
+        Ok(())
+    }
+}
+
+/// Convert an Arrow schema to the Parquet schema written for it
+///
+/// This is the `ArrowWriter` conversion, coercing types to the Parquet spec
+/// when `coerce_types` is set like `WriterProperties::coerce_types`, plus the
+/// annotations of fields tagged with our extension names.
+pub fn annotated_schema(
+    arrow_schema: &arrow_schema::Schema,
+    coerce_types: bool,
+) -> Result<SchemaDescriptor> {
+    let converted = ArrowSchemaConverter::new()
+        .with_coerce_types(coerce_types)
+        .convert(arrow_schema)?;
+    let root = converted.root_schema();
+
+    let fields = arrow_schema
+        .fields()
+        .iter()
+        .zip(root.get_fields())
+        .map(|(field, parquet_type)| annotate(field, parquet_type))
+        .collect::<Result<Vec<_>>>()?;
+
+    let root = Type::group_type_builder(root.name())
+        .with_fields(fields)
+        .build()?;
+    Ok(SchemaDescriptor::new(Arc::new(root)))
+}
+
+/// Annotate the Parquet type converted from `field` and its children
+fn annotate(field: &Field, parquet_type: &TypePtr) -> Result<TypePtr> {
+    match parquet_type.as_ref() {
+        Type::PrimitiveType {
+            basic_info,
+            physical_type,
+            type_length,
+            scale,
+            precision,
+        } => {
+            let (logical_type, converted_type) = match field.extension_type_name() {
+                Some(ENUM_EXTENSION_NAME) => (Some(LogicalType::Enum), ConvertedType::ENUM),
+                // INTERVAL only exists as a converted type
+                Some(INTERVAL_EXTENSION_NAME) => (None, ConvertedType::INTERVAL),
+                _ => return Ok(parquet_type.clone()),
+            };
+
+            let annotated = Type::primitive_type_builder(basic_info.name(), *physical_type)
+                .with_repetition(basic_info.repetition())
+                .with_logical_type(logical_type)
+                .with_converted_type(converted_type)
+                .with_length(*type_length)
+                .with_precision(*precision)
+                .with_scale(*scale)
+                .with_id(basic_info.has_id().then(|| basic_info.id()))
+                .build()?;
+            Ok(Arc::new(annotated))
+        }
+        Type::GroupType { fields, .. } => {
+            let children = match field.data_type() {
+                DataType::Struct(children) => children
+                    .iter()
+                    .zip(fields)
+                    .map(|(child, parquet_child)| annotate(child, parquet_child))
+                    .collect::<Result<Vec<_>>>()?,
+                // LIST groups hold a repeated group around the element
+                DataType::List(item)
+                | DataType::LargeList(item)
+                | DataType::FixedSizeList(item, _) => {
+                    let repeated = &fields[0];
+                    let element = annotate(item, &repeated.get_fields()[0])?;
+                    vec![with_fields(repeated, vec![element])?]
+                }
+                // MAP groups hold the repeated key/value group as the entries struct
+                DataType::Map(entries, _) => vec![annotate(entries, &fields[0])?],
+                _ => return Ok(parquet_type.clone()),
+            };
+            let logical_type = match field.extension_type_name() {
+                Some(VARIANT_EXTENSION_NAME) => Some(LogicalType::Variant {
+                    specification_version: None,
+                }),
+                _ => parquet_type.get_basic_info().logical_type(),
+            };
+            annotated_group(parquet_type, children, logical_type)
+        }
+    }
+}
+
+/// Rebuild a group with new children, keeping its own annotations
+fn with_fields(group: &TypePtr, fields: Vec<TypePtr>) -> Result<TypePtr> {
+    annotated_group(group, fields, group.get_basic_info().logical_type())
+}
+
+/// Rebuild a group with new children and logical type
+fn annotated_group(
+    group: &TypePtr,
+    fields: Vec<TypePtr>,
+    logical_type: Option<LogicalType>,
+) -> Result<TypePtr> {
+    let info = group.get_basic_info();
+    let rebuilt = Type::group_type_builder(info.name())
+        .with_repetition(info.repetition())
+        .with_logical_type(logical_type)
+        .with_converted_type(info.converted_type())
+        .with_id(info.has_id().then(|| info.id()))
+        .with_fields(fields)
+        .build()?;
+    Ok(Arc::new(rebuilt))
+}
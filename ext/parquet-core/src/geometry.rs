@@ -0,0 +1,876 @@
+//! Geometry values for GeoParquet columns
+//!
+//! Geometries are stored as ISO WKB in binary columns. This module converts
+//! between WKB, WKT and the [`Geometry`] tree, and collects the per-column
+//! statistics (geometry types and bounding box) that GeoParquet expects in
+//! the file level `geo` metadata.
+
+use crate::{ParquetError, ParquetValue, PrimitiveType, Result, Schema, SchemaNode};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// Arrow extension name for WKB encoded geometry columns
+pub const GEOMETRY_EXTENSION_NAME: &str = "geoarrow.wkb";
+
+/// Key of the GeoParquet file metadata entry
+pub const GEO_METADATA_KEY: &str = "geo";
+
+const GEOPARQUET_VERSION: &str = "1.1.0";
+
+/// A coordinate: x, y and optionally z (and m)
+pub type Coord = Vec<f64>;
+
+/// A simple features geometry
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Option<Coord>), // None for POINT EMPTY
+    LineString(Vec<Coord>),
+    Polygon(Vec<Vec<Coord>>),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Vec<Vec<Coord>>>),
+    GeometryCollection(Vec<Geometry>),
+}
+
+impl Geometry {
+    /// Name of the geometry type as used by WKT and GeoJSON
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Geometry::Point(_) => "Point",
+            Geometry::LineString(_) => "LineString",
+            Geometry::Polygon(_) => "Polygon",
+            Geometry::MultiPoint(_) => "MultiPoint",
+            Geometry::MultiLineString(_) => "MultiLineString",
+            Geometry::MultiPolygon(_) => "MultiPolygon",
+            Geometry::GeometryCollection(_) => "GeometryCollection",
+        }
+    }
+
+    /// Number of dimensions of the coordinates, 2 for empty geometries
+    pub fn dimensions(&self) -> usize {
+        let mut dims = None;
+        self.visit_coords(&mut |c| {
+            dims.get_or_insert(c.len());
+        });
+        dims.unwrap_or(2)
+    }
+
+    /// 2D bounding box as `[xmin, ymin, xmax, ymax]`, or `None` if empty
+    pub fn bbox(&self) -> Option<[f64; 4]> {
+        let mut bbox: Option<[f64; 4]> = None;
+        self.visit_coords(&mut |c| {
+            let (x, y) = (c[0], c[1]);
+            let b = bbox.get_or_insert([x, y, x, y]);
+            b[0] = b[0].min(x);
+            b[1] = b[1].min(y);
+            b[2] = b[2].max(x);
+            b[3] = b[3].max(y);
+        });
+        bbox
+    }
+
+    fn visit_coords(&self, f: &mut impl FnMut(&Coord)) {
+        match self {
+            Geometry::Point(Some(c)) => f(c),
+            Geometry::Point(None) => {}
+            Geometry::LineString(coords) | Geometry::MultiPoint(coords) => {
+                coords.iter().for_each(f)
+            }
+            Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+                rings.iter().flatten().for_each(f)
+            }
+            Geometry::MultiPolygon(polygons) => polygons.iter().flatten().flatten().for_each(f),
+            Geometry::GeometryCollection(geometries) => {
+                for geometry in geometries {
+                    geometry.visit_coords(f);
+                }
+            }
+        }
+    }
+
+    /// Parse WKB, accepting both ISO and extended (PostGIS) type codes
+    pub fn from_wkb(bytes: &[u8]) -> Result<Self> {
+        let mut reader = WkbReader { bytes, pos: 0 };
+        reader.geometry()
+    }
+
+    /// Encode as little-endian ISO WKB
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_wkb(self, self.dimensions(), &mut out);
+        out
+    }
+
+    /// Parse well-known text such as `POINT (1 2)`
+    pub fn from_wkt(text: &str) -> Result<Self> {
+        let mut parser = WktParser {
+            tokens: tokenize_wkt(text)?,
+            pos: 0,
+        };
+        let geometry = parser.geometry()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(geometry_error(format!(
+                "Unexpected trailing WKT input: {:?}",
+                parser.tokens[parser.pos]
+            )));
+        }
+        Ok(geometry)
+    }
+
+    /// Format as well-known text
+    pub fn to_wkt(&self) -> String {
+        let mut out = String::new();
+        write_wkt(self, self.dimensions(), &mut out);
+        out
+    }
+}
+
+// =============================================================================
+// WKB
+// =============================================================================
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl WkbReader<'_> {
+    /// Read the byte order and type code, returning the byte order, the ISO
+    /// type code without EWKB flags, and whether there are Z and M values
+    fn header(&mut self) -> Result<(bool, u32, bool, bool)> {
+        let little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            other => return Err(geometry_error(format!("Invalid WKB byte order {}", other))),
+        };
+        let code = self.u32(little_endian)?;
+
+        let mut has_z = code & EWKB_Z_FLAG != 0;
+        let mut has_m = code & EWKB_M_FLAG != 0;
+        if code & EWKB_SRID_FLAG != 0 {
+            self.take::<4>()?;
+        }
+        let code = code & 0x0FFF_FFFF;
+        match code / 1000 {
+            0 => {}
+            1 => has_z = true,
+            2 => has_m = true,
+            3 => (has_z, has_m) = (true, true),
+            _ => {
+                return Err(geometry_error(format!(
+                    "Invalid WKB geometry type {}",
+                    code
+                )))
+            }
+        }
+        Ok((little_endian, code, has_z, has_m))
+    }
+
+    fn geometry(&mut self) -> Result<Geometry> {
+        let (little_endian, code, has_z, has_m) = self.header()?;
+        let dims = 2 + usize::from(has_z) + usize::from(has_m);
+
+        match code % 1000 {
+            WKB_POINT => {
+                let coord = self.coord(dims, little_endian)?;
+                Ok(Geometry::Point(
+                    (!coord.iter().all(|v| v.is_nan())).then_some(coord),
+                ))
+            }
+            WKB_LINESTRING => Ok(Geometry::LineString(self.coords(dims, little_endian)?)),
+            WKB_POLYGON => Ok(Geometry::Polygon(self.rings(dims, little_endian)?)),
+            WKB_MULTIPOINT => {
+                let points = self.geometries(little_endian)?;
+                points
+                    .into_iter()
+                    .map(|g| match g {
+                        Geometry::Point(Some(c)) => Ok(c),
+                        other => Err(unexpected_member("MultiPoint", &other)),
+                    })
+                    .collect::<Result<_>>()
+                    .map(Geometry::MultiPoint)
+            }
+            WKB_MULTILINESTRING => self
+                .geometries(little_endian)?
+                .into_iter()
+                .map(|g| match g {
+                    Geometry::LineString(c) => Ok(c),
+                    other => Err(unexpected_member("MultiLineString", &other)),
+                })
+                .collect::<Result<_>>()
+                .map(Geometry::MultiLineString),
+            WKB_MULTIPOLYGON => self
+                .geometries(little_endian)?
+                .into_iter()
+                .map(|g| match g {
+                    Geometry::Polygon(r) => Ok(r),
+                    other => Err(unexpected_member("MultiPolygon", &other)),
+                })
+                .collect::<Result<_>>()
+                .map(Geometry::MultiPolygon),
+            WKB_GEOMETRYCOLLECTION => Ok(Geometry::GeometryCollection(
+                self.geometries(little_endian)?,
+            )),
+            other => Err(geometry_error(format!(
+                "Unsupported WKB geometry type {}",
+                other
+            ))),
+        }
+    }
+
+    fn geometries(&mut self, little_endian: bool) -> Result<Vec<Geometry>> {
+        let count = self.u32(little_endian)? as usize;
+        (0..count).map(|_| self.geometry()).collect()
+    }
+
+    fn rings(&mut self, dims: usize, little_endian: bool) -> Result<Vec<Vec<Coord>>> {
+        let count = self.u32(little_endian)? as usize;
+        (0..count)
+            .map(|_| self.coords(dims, little_endian))
+            .collect()
+    }
+
+    fn coords(&mut self, dims: usize, little_endian: bool) -> Result<Vec<Coord>> {
+        let count = self.u32(little_endian)? as usize;
+        (0..count)
+            .map(|_| self.coord(dims, little_endian))
+            .collect()
+    }
+
+    fn coord(&mut self, dims: usize, little_endian: bool) -> Result<Coord> {
+        (0..dims)
+            .map(|_| {
+                let bytes = self.take::<8>()?;
+                Ok(if little_endian {
+                    f64::from_le_bytes(bytes)
+                } else {
+                    f64::from_be_bytes(bytes)
+                })
+            })
+            .collect()
+    }
+
+    fn u32(&mut self, little_endian: bool) -> Result<u32> {
+        let bytes = self.take::<4>()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| geometry_error("WKB data is truncated"))?;
+        self.pos += N;
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(bytes);
+        Ok(buf)
+    }
+}
+
+fn write_wkb(geometry: &Geometry, dims: usize, out: &mut Vec<u8>) {
+    let base = match geometry {
+        Geometry::Point(_) => WKB_POINT,
+        Geometry::LineString(_) => WKB_LINESTRING,
+        Geometry::Polygon(_) => WKB_POLYGON,
+        Geometry::MultiPoint(_) => WKB_MULTIPOINT,
+        Geometry::MultiLineString(_) => WKB_MULTILINESTRING,
+        Geometry::MultiPolygon(_) => WKB_MULTIPOLYGON,
+        Geometry::GeometryCollection(_) => WKB_GEOMETRYCOLLECTION,
+    };
+    let dimension_offset = match dims {
+        3 => 1000,
+        4 => 3000,
+        _ => 0,
+    };
+    out.push(1);
+    out.extend_from_slice(&(base + dimension_offset).to_le_bytes());
+
+    let write_coords = |coords: &[Coord], out: &mut Vec<u8>| {
+        out.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+        for coord in coords {
+            write_coord(coord, dims, out);
+        }
+    };
+
+    match geometry {
+        Geometry::Point(Some(coord)) => write_coord(coord, dims, out),
+        Geometry::Point(None) => {
+            for _ in 0..dims {
+                out.extend_from_slice(&f64::NAN.to_le_bytes());
+            }
+        }
+        Geometry::LineString(coords) => write_coords(coords, out),
+        Geometry::Polygon(rings) => {
+            out.extend_from_slice(&(rings.len() as u32).to_le_bytes());
+            for ring in rings {
+                write_coords(ring, out);
+            }
+        }
+        Geometry::MultiPoint(points) => {
+            out.extend_from_slice(&(points.len() as u32).to_le_bytes());
+            for point in points {
+                write_wkb(&Geometry::Point(Some(point.clone())), dims, out);
+            }
+        }
+        Geometry::MultiLineString(lines) => {
+            out.extend_from_slice(&(lines.len() as u32).to_le_bytes());
+            for line in lines {
+                write_wkb(&Geometry::LineString(line.clone()), dims, out);
+            }
+        }
+        Geometry::MultiPolygon(polygons) => {
+            out.extend_from_slice(&(polygons.len() as u32).to_le_bytes());
+            for polygon in polygons {
+                write_wkb(&Geometry::Polygon(polygon.clone()), dims, out);
+            }
+        }
+        Geometry::GeometryCollection(geometries) => {
+            out.extend_from_slice(&(geometries.len() as u32).to_le_bytes());
+            for geometry in geometries {
+                write_wkb(geometry, geometry.dimensions(), out);
+            }
+        }
+    }
+}
+
+fn write_coord(coord: &Coord, dims: usize, out: &mut Vec<u8>) {
+    for i in 0..dims {
+        let value = coord.get(i).copied().unwrap_or(f64::NAN);
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+// =============================================================================
+// WKT
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum WktToken {
+    Word(String),
+    Number(f64),
+    Open,
+    Close,
+    Comma,
+}
+
+fn tokenize_wkt(text: &str) -> Result<Vec<WktToken>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(WktToken::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(WktToken::Close);
+            }
+            ',' => {
+                chars.next();
+                tokens.push(WktToken::Comma);
+            }
+            c if c.is_ascii_alphabetic() => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    if !c.is_ascii_alphabetic() {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                tokens.push(WktToken::Word(text[start..end].to_ascii_uppercase()));
+            }
+            c if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let number = text[start..end].parse::<f64>().map_err(|_| {
+                    geometry_error(format!("Invalid WKT number {:?}", &text[start..end]))
+                })?;
+                tokens.push(WktToken::Number(number));
+            }
+            other => {
+                return Err(geometry_error(format!(
+                    "Unexpected character {:?} in WKT",
+                    other
+                )))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct WktParser {
+    tokens: Vec<WktToken>,
+    pos: usize,
+}
+
+impl WktParser {
+    fn geometry(&mut self) -> Result<Geometry> {
+        let kind = match self.next()? {
+            WktToken::Word(word) => word,
+            other => {
+                return Err(geometry_error(format!(
+                    "Expected WKT geometry type, got {:?}",
+                    other
+                )))
+            }
+        };
+
+        // Dimension markers are implied by the coordinates themselves
+        if let Some(WktToken::Word(word)) = self.peek() {
+            if matches!(word.as_str(), "Z" | "M" | "ZM") {
+                self.pos += 1;
+            }
+        }
+
+        if let Some(WktToken::Word(word)) = self.peek() {
+            if word == "EMPTY" {
+                self.pos += 1;
+                return match kind.as_str() {
+                    "POINT" => Ok(Geometry::Point(None)),
+                    "LINESTRING" => Ok(Geometry::LineString(Vec::new())),
+                    "POLYGON" => Ok(Geometry::Polygon(Vec::new())),
+                    "MULTIPOINT" => Ok(Geometry::MultiPoint(Vec::new())),
+                    "MULTILINESTRING" => Ok(Geometry::MultiLineString(Vec::new())),
+                    "MULTIPOLYGON" => Ok(Geometry::MultiPolygon(Vec::new())),
+                    "GEOMETRYCOLLECTION" => Ok(Geometry::GeometryCollection(Vec::new())),
+                    other => Err(geometry_error(format!("Unknown WKT geometry {}", other))),
+                };
+            }
+        }
+
+        match kind.as_str() {
+            "POINT" => {
+                self.expect(WktToken::Open)?;
+                let coord = self.coord()?;
+                self.expect(WktToken::Close)?;
+                Ok(Geometry::Point(Some(coord)))
+            }
+            "LINESTRING" => Ok(Geometry::LineString(self.coords()?)),
+            "POLYGON" => Ok(Geometry::Polygon(self.list(|p| p.coords())?)),
+            "MULTIPOINT" => Ok(Geometry::MultiPoint(self.list(|p| {
+                // Both MULTIPOINT ((1 2), (3 4)) and MULTIPOINT (1 2, 3 4) are common
+                if p.peek() == Some(&WktToken::Open) {
+                    p.expect(WktToken::Open)?;
+                    let coord = p.coord()?;
+                    p.expect(WktToken::Close)?;
+                    Ok(coord)
+                } else {
+                    p.coord()
+                }
+            })?)),
+            "MULTILINESTRING" => Ok(Geometry::MultiLineString(self.list(|p| p.coords())?)),
+            "MULTIPOLYGON" => Ok(Geometry::MultiPolygon(
+                self.list(|p| p.list(|p| p.coords()))?,
+            )),
+            "GEOMETRYCOLLECTION" => Ok(Geometry::GeometryCollection(self.list(|p| p.geometry())?)),
+            other => Err(geometry_error(format!("Unknown WKT geometry {}", other))),
+        }
+    }
+
+    /// Parse `( item, item, ... )`
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        self.expect(WktToken::Open)?;
+        let mut items = vec![item(self)?];
+        while self.peek() == Some(&WktToken::Comma) {
+            self.pos += 1;
+            items.push(item(self)?);
+        }
+        self.expect(WktToken::Close)?;
+        Ok(items)
+    }
+
+    fn coords(&mut self) -> Result<Vec<Coord>> {
+        self.list(|p| p.coord())
+    }
+
+    fn coord(&mut self) -> Result<Coord> {
+        let mut coord = Vec::with_capacity(3);
+        while let Some(WktToken::Number(n)) = self.peek() {
+            coord.push(*n);
+            self.pos += 1;
+        }
+        if !(2..=4).contains(&coord.len()) {
+            return Err(geometry_error(format!(
+                "WKT coordinates need 2 to 4 values, got {}",
+                coord.len()
+            )));
+        }
+        Ok(coord)
+    }
+
+    fn expect(&mut self, expected: WktToken) -> Result<()> {
+        let token = self.next()?;
+        if token != expected {
+            return Err(geometry_error(format!(
+                "Expected {:?} in WKT, got {:?}",
+                expected, token
+            )));
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<&WktToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<WktToken> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| geometry_error("Unexpected end of WKT"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+}
+
+fn write_wkt(geometry: &Geometry, dims: usize, out: &mut String) {
+    out.push_str(&geometry.type_name().to_ascii_uppercase());
+    match dims {
+        3 => out.push_str(" Z"),
+        4 => out.push_str(" ZM"),
+        _ => {}
+    }
+
+    let is_empty = match geometry {
+        Geometry::Point(coord) => coord.is_none(),
+        Geometry::LineString(c) | Geometry::MultiPoint(c) => c.is_empty(),
+        Geometry::Polygon(r) | Geometry::MultiLineString(r) => r.is_empty(),
+        Geometry::MultiPolygon(p) => p.is_empty(),
+        Geometry::GeometryCollection(g) => g.is_empty(),
+    };
+    if is_empty {
+        out.push_str(" EMPTY");
+        return;
+    }
+
+    out.push(' ');
+    match geometry {
+        Geometry::Point(Some(coord)) => {
+            out.push('(');
+            write_wkt_coord(coord, out);
+            out.push(')');
+        }
+        Geometry::Point(None) => {}
+        Geometry::LineString(coords) => write_wkt_coords(coords, out),
+        Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+            write_wkt_list(rings, out, |ring, out| write_wkt_coords(ring, out))
+        }
+        Geometry::MultiPoint(points) => write_wkt_list(points, out, |point, out| {
+            out.push('(');
+            write_wkt_coord(point, out);
+            out.push(')');
+        }),
+        Geometry::MultiPolygon(polygons) => write_wkt_list(polygons, out, |polygon, out| {
+            write_wkt_list(polygon, out, |ring, out| write_wkt_coords(ring, out))
+        }),
+        Geometry::GeometryCollection(geometries) => {
+            write_wkt_list(geometries, out, |geometry, out| {
+                write_wkt(geometry, geometry.dimensions(), out)
+            })
+        }
+    }
+}
+
+fn write_wkt_list<T>(items: &[T], out: &mut String, mut item: impl FnMut(&T, &mut String)) {
+    out.push('(');
+    for (i, value) in items.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        item(value, out);
+    }
+    out.push(')');
+}
+
+fn write_wkt_coords(coords: &[Coord], out: &mut String) {
+    write_wkt_list(coords, out, |coord, out| write_wkt_coord(coord, out));
+}
+
+fn write_wkt_coord(coord: &Coord, out: &mut String) {
+    for (i, value) in coord.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        out.push_str(&value.to_string());
+    }
+}
+
+// =============================================================================
+// GeoParquet metadata
+// =============================================================================
+
+/// Statistics gathered for the `geo` metadata of a file being written
+#[derive(Debug, Clone)]
+pub struct GeoMetadata {
+    primary_column: String,
+    columns: Vec<GeoColumn>,
+}
+
+#[derive(Debug, Clone)]
+struct GeoColumn {
+    name: String,
+    index: usize,
+    crs: Option<Arc<str>>,
+    spherical: bool,
+    geometry_types: BTreeSet<String>,
+    bbox: Option<[f64; 4]>,
+}
+
+impl GeoMetadata {
+    /// Collect the top level geometry columns of a schema, if there are any
+    pub fn from_schema(schema: &Schema) -> Option<Self> {
+        let SchemaNode::Struct { fields, .. } = &schema.root else {
+            return None;
+        };
+
+        let columns = fields
+            .iter()
+            .enumerate()
+            .filter_map(|(index, field)| match field {
+                SchemaNode::Primitive {
+                    name,
+                    primitive_type: PrimitiveType::Geometry(crs),
+                    ..
+                } => Some((index, name, crs, false)),
+                SchemaNode::Primitive {
+                    name,
+                    primitive_type: PrimitiveType::Geography(crs),
+                    ..
+                } => Some((index, name, crs, true)),
+                _ => None,
+            })
+            .map(|(index, name, crs, spherical)| GeoColumn {
+                name: name.clone(),
+                index,
+                crs: crs.clone(),
+                spherical,
+                geometry_types: BTreeSet::new(),
+                bbox: None,
+            })
+            .collect::<Vec<_>>();
+
+        Some(Self {
+            primary_column: columns.first()?.name.clone(),
+            columns,
+        })
+    }
+
+    /// Record a value written to the top level column at `index`
+    pub fn observe(&mut self, index: usize, value: &ParquetValue) -> Result<()> {
+        let Some(column) = self.columns.iter_mut().find(|c| c.index == index) else {
+            return Ok(());
+        };
+        let ParquetValue::Geometry(wkb) = value else {
+            return Ok(());
+        };
+
+        let geometry = Geometry::from_wkb(wkb)?;
+        // The tree only keeps the number of ordinates, so XYM and XYZ are
+        // told apart by the WKB header. GeoParquet types only have a " Z"
+        // suffix, so XYM is listed as XY and XYZM as XYZ
+        let (_, _, has_z, _) = WkbReader { bytes: wkb, pos: 0 }.header()?;
+        let type_name = if has_z {
+            format!("{} Z", geometry.type_name())
+        } else {
+            geometry.type_name().to_string()
+        };
+        column.geometry_types.insert(type_name);
+
+        if let Some([xmin, ymin, xmax, ymax]) = geometry.bbox() {
+            let bbox = column.bbox.get_or_insert([xmin, ymin, xmax, ymax]);
+            bbox[0] = bbox[0].min(xmin);
+            bbox[1] = bbox[1].min(ymin);
+            bbox[2] = bbox[2].max(xmax);
+            bbox[3] = bbox[3].max(ymax);
+        }
+        Ok(())
+    }
+
+    /// Render the GeoParquet metadata document
+    pub fn to_json(&self) -> Result<String> {
+        let mut columns = serde_json::Map::new();
+        for column in &self.columns {
+            let mut entry = serde_json::Map::new();
+            entry.insert("encoding".into(), "WKB".into());
+            entry.insert(
+                "geometry_types".into(),
+                column.geometry_types.iter().cloned().collect(),
+            );
+            if let Some(bbox) = column.bbox {
+                entry.insert("bbox".into(), bbox.to_vec().into());
+            }
+            if let Some(crs) = &column.crs {
+                let crs: serde_json::Value = serde_json::from_str(crs)
+                    .map_err(|e| geometry_error(format!("Invalid PROJJSON CRS: {}", e)))?;
+                entry.insert("crs".into(), crs);
+            }
+            if column.spherical {
+                entry.insert("edges".into(), "spherical".into());
+            }
+            columns.insert(column.name.clone(), entry.into());
+        }
+
+        let geo = serde_json::json!({
+            "version": GEOPARQUET_VERSION,
+            "primary_column": self.primary_column,
+            "columns": columns,
+        });
+        Ok(geo.to_string())
+    }
+}
+
+/// Names of the columns listed in a GeoParquet `geo` metadata document
+pub fn geo_metadata_columns(geo: &str) -> Vec<String> {
+    serde_json::from_str::<serde_json::Value>(geo)
+        .ok()
+        .and_then(|v| {
+            v.get("columns")
+                .and_then(|c| c.as_object())
+                .map(|c| c.keys().cloned().collect())
+        })
+        .unwrap_or_default()
+}
+
+fn unexpected_member(collection: &str, member: &Geometry) -> ParquetError {
+    geometry_error(format!(
+        "{} cannot contain a {}",
+        collection,
+        member.type_name()
+    ))
+}
+
+fn geometry_error(message: impl Into<String>) -> ParquetError {
+    ParquetError::Conversion(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wkt_roundtrip() {
+        let cases = [
+            "POINT (1 2)",
+            "POINT Z (1 2 3)",
+            "POINT EMPTY",
+            "LINESTRING (0 0, 1 1.5, 2 -2)",
+            "POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "MULTIPOINT ((1 2), (3 4))",
+            "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+            "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (0 0, 1 1))",
+        ];
+
+        for wkt in cases {
+            let geometry = Geometry::from_wkt(wkt).unwrap();
+            assert_eq!(geometry.to_wkt(), wkt);
+            assert_eq!(Geometry::from_wkb(&geometry.to_wkb()).unwrap(), geometry);
+        }
+    }
+
+    #[test]
+    fn test_wkt_accepts_lowercase_and_bare_multipoint() {
+        let geometry = Geometry::from_wkt("multipoint (1 2, 3 4)").unwrap();
+        assert_eq!(
+            geometry,
+            Geometry::MultiPoint(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn test_big_endian_ewkb() {
+        // SRID=4326;POINT(1 2) as big-endian extended WKB
+        let mut wkb = vec![0u8];
+        wkb.extend_from_slice(&(WKB_POINT | EWKB_SRID_FLAG).to_be_bytes());
+        wkb.extend_from_slice(&4326u32.to_be_bytes());
+        wkb.extend_from_slice(&1.0f64.to_be_bytes());
+        wkb.extend_from_slice(&2.0f64.to_be_bytes());
+
+        assert_eq!(
+            Geometry::from_wkb(&wkb).unwrap(),
+            Geometry::Point(Some(vec![1.0, 2.0]))
+        );
+    }
+
+    #[test]
+    fn test_geo_metadata_dimension_suffixes() {
+        let schema = crate::SchemaBuilder::new()
+            .with_root(SchemaNode::Struct {
+                name: "root".to_string(),
+                nullable: false,
+                fields: vec![SchemaNode::Primitive {
+                    name: "geom".to_string(),
+                    primitive_type: PrimitiveType::Geometry(None),
+                    nullable: true,
+                    format: None,
+                }],
+            })
+            .build()
+            .unwrap();
+        let mut metadata = GeoMetadata::from_schema(&schema).unwrap();
+
+        // ISO codes for POINT, POINT Z, POINT M and POINT ZM
+        for (code, ordinates) in [(1u32, 2), (1001, 3), (2001, 3), (3001, 4)] {
+            let mut wkb = vec![1u8];
+            wkb.extend_from_slice(&code.to_le_bytes());
+            for i in 0..ordinates {
+                wkb.extend_from_slice(&(i as f64).to_le_bytes());
+            }
+            metadata
+                .observe(0, &ParquetValue::Geometry(wkb.into()))
+                .unwrap();
+        }
+
+        let types = metadata.columns[0]
+            .geometry_types
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        // M has no GeoParquet suffix, so only XY and XYZ types are listed
+        assert_eq!(types, ["Point", "Point Z"]);
+    }
+
+    #[test]
+    fn test_bbox() {
+        let geometry = Geometry::from_wkt("LINESTRING (3 -1, -2 4, 0 0)").unwrap();
+        assert_eq!(geometry.bbox(), Some([-2.0, -1.0, 3.0, 4.0]));
+        assert_eq!(Geometry::Point(None).bbox(), None);
+    }
+
+    #[test]
+    fn test_invalid_wkt() {
+        assert!(Geometry::from_wkt("POINT (1)").is_err());
+        assert!(Geometry::from_wkt("CIRCLE (1 2)").is_err());
+        assert!(Geometry::from_wkt("POINT (1 2) extra").is_err());
+    }
+}
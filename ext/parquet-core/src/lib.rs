@@ -9,12 +9,19 @@
 //! - **Reader**: High-performance Parquet file reader
 //!   - Row-wise iteration through [`reader::Reader`]
 //!   - Column-wise batch reading for analytics workloads
+//!   - Decoded footer statistics through [`statistics::FileStatistics`]
+//!   - Range filters pruning row groups by statistics and declared sort
+//!     order through [`sorting::RangeFilter`]
+//!   - Seeded random row samples through [`sampling::Sample`]
+//!   - Counts, min, max and null counts from footer statistics where they
+//!     decide a row group, through [`aggregate`]
 //!   - Uses `parquet::file::reader::ChunkReader` for flexible input sources
 //!   
 //! - **Writer**: Efficient Parquet file writer
 //!   - Supports both row and columnar data input
 //!   - Configurable compression and encoding options
 //!   - Dynamic batch sizing based on memory usage
+//!   - Declared and optionally verified sort order through [`sorting::SortColumn`]
 //!   - Uses `std::io::Write + Send` for output flexibility
 //!   
 //! - **Schema**: Type-safe schema representation
@@ -26,6 +33,18 @@
 //!   - All Parquet primitive types
 //!   - Decimal support (128 and 256 bit)
 //!   - Temporal types (dates, times, timestamps)
+//!   - Semi-structured VARIANT values through [`variant::Variant`]
+//!   - GeoParquet geometries through [`geometry::Geometry`]
+//!
+//! - **Format Conversion**: Streaming Parquet to and from NDJSON, CSV and
+//!   Arrow IPC through [`convert`], batch by batch without building values
+//!
+//! - **Verification**: Decoding every page and checking CRCs and row counts
+//!   through [`verify`], reporting every problem found
+//!
+//! - **Recovery**: Rebuilding the footer of a file whose writer never closed
+//!   it through [`salvage`], and skipping row groups that fail to decode
+//!   with [`reader::Reader::with_skip_corrupt_row_groups`]
 //!
 //! - **Arrow Conversion**: Bidirectional conversion between Arrow and Parquet
 //!   - Zero-copy where possible
@@ -42,19 +61,36 @@
 //! This crate is designed to be used through language-specific adapters.
 //! See `parquet-ruby-adapter` for Ruby integration.
 
+pub mod aggregate;
 pub mod arrow_conversion;
+pub mod convert;
 pub mod error;
+pub mod file_writer;
+pub mod geometry;
 pub mod reader;
+pub mod salvage;
+pub mod sampling;
 pub mod schema;
+pub mod sorting;
+pub mod statistics;
 pub mod traits;
 pub mod value;
+pub mod variant;
+pub mod verify;
 pub mod writer;
 
 #[cfg(test)]
 pub mod test_utils;
 
 pub use error::{ErrorContext, ParquetError, Result};
-pub use reader::Reader;
+pub use geometry::Geometry;
+pub use reader::{Reader, SkippedRowGroup};
+pub use salvage::{SalvageReport, SalvageSchema, Salvaged};
+pub use sampling::Sample;
 pub use schema::{PrimitiveType, Repetition, Schema, SchemaBuilder, SchemaNode};
+pub use sorting::{RangeFilter, SortColumn};
+pub use statistics::{ColumnStatistics, FileStatistics, PageStatistics, RowGroupStatistics};
 pub use value::ParquetValue;
+pub use variant::Variant;
+pub use verify::{VerifyProblem, VerifyReport};
 pub use writer::{Writer, WriterBuilder};
@@ -1,16 +1,40 @@
 //! Core Parquet reading functionality
 
-use crate::{arrow_conversion::arrow_to_parquet_value, ParquetError, ParquetValue, Result};
+use crate::{
+    aggregate,
+    arrow_conversion::{
+        arrow_to_parquet_value, dictionary_key, is_enum_field, INTERVAL_EXTENSION_NAME,
+    },
+    geometry::{geo_metadata_columns, GEOMETRY_EXTENSION_NAME, GEO_METADATA_KEY},
+    sampling::{sample_row_groups, Sample},
+    sorting::{resolve_ranges, row_filter, select_row_groups, RangeFilter, ResolvedRange},
+    statistics::{read_statistics, FileStatistics},
+    verify::{verify, VerifyReport},
+    ParquetError, ParquetValue, Result,
+};
 use arrow::record_batch::RecordBatch;
-use arrow_array::Array;
-use parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{DataType, Field, FieldRef};
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
+    ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+};
+use parquet::arrow::ProjectionMask;
+use parquet::basic::ConvertedType;
 use parquet::file::metadata::{FileMetaData, ParquetMetaData};
+use parquet::schema::types::{SchemaDescriptor, Type, TypePtr};
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 /// Core Parquet reader that works with any source implementing Read + Seek
 #[derive(Clone)]
 pub struct Reader<R> {
     inner: R,
+    skip_corrupt_row_groups: bool,
+    range_filters: Vec<RangeFilter>,
+    dictionary_columns: Vec<String>,
+    sample: Option<(Sample, Option<u64>)>,
 }
 
 impl<R> Reader<R>
@@ -19,7 +43,87 @@ where
 {
     /// Create a new reader
     pub fn new(reader: R) -> Self {
-        Self { inner: reader }
+        Self {
+            inner: reader,
+            skip_corrupt_row_groups: false,
+            range_filters: Vec::new(),
+            dictionary_columns: Vec::new(),
+            sample: None,
+        }
+    }
+
+    /// Leave out row groups that fail to decode instead of failing the read
+    ///
+    /// Row and column reads then decode one row group at a time and only
+    /// hand out its values once the whole row group decoded, so a skipped
+    /// row group contributes no rows at all. Skipped row groups are
+    /// collected by the iterators' `take_skipped_row_groups`.
+    pub fn with_skip_corrupt_row_groups(mut self, skip: bool) -> Self {
+        self.skip_corrupt_row_groups = skip;
+        self
+    }
+
+    /// Only read rows within a range of a top level column
+    ///
+    /// Row groups whose statistics lie outside the range are not read at
+    /// all, see [`crate::sorting`]. Filters added by repeated calls must all
+    /// match. They apply to record batch, row and column reads alike.
+    pub fn with_range_filter(mut self, filter: RangeFilter) -> Self {
+        self.range_filters.push(filter);
+        self
+    }
+
+    /// Only read a random sample of rows, see [`crate::sampling`]
+    ///
+    /// The sample is drawn from the row groups left by any range filter,
+    /// before the filter is applied to their rows. With a `seed` the same
+    /// rows are picked on every read.
+    pub fn with_sample(mut self, sample: Sample, seed: Option<u64>) -> Self {
+        self.sample = Some((sample, seed));
+        self
+    }
+
+    /// Read top level string columns as Arrow dictionaries
+    ///
+    /// Pages of dictionary encoded columns then keep their dictionary, so
+    /// every distinct value is decoded once per dictionary and rows share
+    /// the same [`ParquetValue::String`] allocation. Naming a column that is
+    /// not a top level string column fails the read.
+    pub fn with_dictionary_columns(mut self, columns: Vec<String>) -> Self {
+        self.dictionary_columns = columns;
+        self
+    }
+
+    /// Top level string columns that are dictionary encoded in every row group
+    pub fn dictionary_encoded_columns(&self) -> Result<Vec<String>> {
+        let arrow_metadata = ArrowReaderMetadata::load(&self.inner, ArrowReaderOptions::new())?;
+        let metadata = arrow_metadata.metadata();
+        let schema_descr = metadata.file_metadata().schema_descr();
+        if metadata.num_row_groups() == 0 {
+            return Ok(Vec::new());
+        }
+
+        Ok(arrow_metadata
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| is_string_type(field.data_type()))
+            .filter_map(|(root_idx, field)| {
+                let leaf_idx = (0..schema_descr.num_columns())
+                    .find(|&leaf_idx| schema_descr.get_column_root_idx(leaf_idx) == root_idx)?;
+                metadata
+                    .row_groups()
+                    .iter()
+                    .all(|row_group| {
+                        row_group
+                            .column(leaf_idx)
+                            .dictionary_page_offset()
+                            .is_some()
+                    })
+                    .then(|| field.name().clone())
+            })
+            .collect())
     }
 
     /// Get the Parquet file metadata
@@ -28,21 +132,97 @@ where
         Ok(builder.metadata().file_metadata().clone())
     }
 
+    /// Get per column statistics from the footer, decoded to logical values
+    ///
+    /// Only the given top level columns are included when `columns` is set.
+    pub fn statistics(&self, columns: Option<&[String]>) -> Result<FileStatistics> {
+        let metadata = ArrowReaderMetadata::load(&self.inner, ArrowReaderOptions::new())?;
+        read_statistics(metadata.metadata(), metadata.schema(), columns)
+    }
+
+    /// Count the rows within the range filters
+    ///
+    /// Row groups the footer statistics decide are not read, see
+    /// [`crate::aggregate`]; neither is anything when there are no filters.
+    pub fn count(&self) -> Result<u64> {
+        let (metadata, ranges) = self.aggregate_metadata()?;
+        aggregate::count(self.inner.clone(), &metadata, &ranges)
+    }
+
+    /// Smallest non null value of a top level column within the range filters
+    pub fn min(&self, column: &str) -> Result<Option<ParquetValue>> {
+        let (metadata, ranges) = self.aggregate_metadata()?;
+        aggregate::extreme(self.inner.clone(), &metadata, &ranges, column, false)
+    }
+
+    /// Largest non null value of a top level column within the range filters
+    pub fn max(&self, column: &str) -> Result<Option<ParquetValue>> {
+        let (metadata, ranges) = self.aggregate_metadata()?;
+        aggregate::extreme(self.inner.clone(), &metadata, &ranges, column, true)
+    }
+
+    /// Number of nulls in a top level column within the range filters
+    pub fn null_count(&self, column: &str) -> Result<u64> {
+        let (metadata, ranges) = self.aggregate_metadata()?;
+        aggregate::null_count(self.inner.clone(), &metadata, &ranges, column)
+    }
+
+    fn aggregate_metadata(&self) -> Result<(ArrowReaderMetadata, Vec<ResolvedRange>)> {
+        let metadata = ArrowReaderMetadata::load(&self.inner, ArrowReaderOptions::new())?;
+        let ranges = resolve_ranges(&self.range_filters, &metadata)?;
+        Ok((metadata, ranges))
+    }
+
+    /// Decode every page of the file, collecting any problems found
+    ///
+    /// See [`verify`] for what is checked.
+    pub fn verify(&self, checksums: bool) -> Result<VerifyReport> {
+        verify(&self.inner, checksums)
+    }
+
+    /// Read Arrow record batches, projected to `columns` when given
+    ///
+    /// Batches carry the same ENUM and geometry hints as row and column
+    /// reads, but no value is converted to a [`ParquetValue`]. Format
+    /// conversions stream these straight into another Arrow writer.
+    pub fn read_batches(
+        self,
+        columns: Option<&[String]>,
+        batch_size: Option<usize>,
+    ) -> Result<ParquetRecordBatchReader> {
+        let arrow_metadata = reader_metadata(&self.inner, &self.dictionary_columns)?;
+        let plan = self.plan(&arrow_metadata)?;
+        let mut builder = plan.restrict(
+            ParquetRecordBatchReaderBuilder::new_with_metadata(self.inner, arrow_metadata.clone()),
+            &arrow_metadata,
+        );
+
+        if let Some(columns) = columns {
+            let column_indices = builder
+                .schema()
+                .fields()
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| columns.contains(field.name()))
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
+            let mask = ProjectionMask::roots(builder.parquet_schema(), column_indices);
+            builder = builder.with_projection(mask);
+        }
+
+        if let Some(size) = batch_size {
+            builder = builder.with_batch_size(size);
+        }
+
+        Ok(builder.build()?)
+    }
+
     /// Read rows from the Parquet file
     ///
     /// Returns an iterator over rows where each row is a vector of ParquetValues
     pub fn read_rows(self) -> Result<RowIterator<R>> {
-        let builder = ParquetRecordBatchReaderBuilder::try_new(self.inner)?;
-        let metadata = builder.metadata().clone();
-        let reader = builder.build()?;
-
-        Ok(RowIterator {
-            batch_reader: reader,
-            metadata,
-            current_batch: None,
-            current_row: 0,
-            _phantom: std::marker::PhantomData,
-        })
+        let (batches, metadata, _) = self.batches(None, None)?;
+        Ok(RowIterator::new(batches, metadata))
     }
 
     /// Read rows with column projection
@@ -50,32 +230,8 @@ where
     /// Only the specified columns will be read, which can significantly
     /// improve performance for wide tables.
     pub fn read_rows_with_projection(self, columns: &[String]) -> Result<RowIterator<R>> {
-        let mut builder = ParquetRecordBatchReaderBuilder::try_new(self.inner)?;
-        let arrow_schema = builder.schema();
-
-        // Create projection mask based on column names
-        let mut column_indices = Vec::new();
-        for (idx, field) in arrow_schema.fields().iter().enumerate() {
-            if columns.contains(&field.name().to_string()) {
-                column_indices.push(idx);
-            }
-        }
-
-        // Allow empty column projections to match v1 behavior
-        // This will result in rows with no fields
-
-        let mask = parquet::arrow::ProjectionMask::roots(builder.parquet_schema(), column_indices);
-        builder = builder.with_projection(mask);
-        let metadata = builder.metadata().clone();
-        let reader = builder.build()?;
-
-        Ok(RowIterator {
-            batch_reader: reader,
-            metadata,
-            current_batch: None,
-            current_row: 0,
-            _phantom: std::marker::PhantomData,
-        })
+        let (batches, metadata, _) = self.batches(Some(columns), None)?;
+        Ok(RowIterator::new(batches, metadata))
     }
 
     /// Read columns from the Parquet file
@@ -83,9 +239,51 @@ where
     /// Returns an iterator over column batches where each batch contains
     /// arrays of values for each column.
     pub fn read_columns(self, batch_size: Option<usize>) -> Result<ColumnIterator<R>> {
-        let mut builder = ParquetRecordBatchReaderBuilder::try_new(self.inner)?;
+        let (batches, metadata, schema) = self.batches(None, batch_size)?;
+        Ok(ColumnIterator::new(batches, metadata, schema))
+    }
 
-        let is_empty = builder.metadata().file_metadata().num_rows() == 0;
+    /// Read columns with projection
+    pub fn read_columns_with_projection(
+        self,
+        columns: &[String],
+        batch_size: Option<usize>,
+    ) -> Result<ColumnIterator<R>> {
+        let (batches, metadata, schema) = self.batches(Some(columns), batch_size)?;
+        Ok(ColumnIterator::new(batches, metadata, schema))
+    }
+
+    /// Record batches for row and column reads, with the file metadata and
+    /// Arrow schema
+    fn batches(
+        self,
+        columns: Option<&[String]>,
+        batch_size: Option<usize>,
+    ) -> Result<(Batches, Arc<ParquetMetaData>, Arc<arrow_schema::Schema>)> {
+        let arrow_metadata = reader_metadata(&self.inner, &self.dictionary_columns)?;
+        let plan = self.plan(&arrow_metadata)?;
+        let mut builder = ParquetRecordBatchReaderBuilder::new_with_metadata(
+            self.inner.clone(),
+            arrow_metadata.clone(),
+        );
+
+        let mask = match columns {
+            Some(columns) => {
+                // Allow empty column projections to match v1 behavior
+                // This will result in rows with no fields
+                let column_indices = builder
+                    .schema()
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, field)| columns.contains(field.name()))
+                    .map(|(idx, _)| idx)
+                    .collect::<Vec<_>>();
+                ProjectionMask::roots(builder.parquet_schema(), column_indices)
+            }
+            None => ProjectionMask::all(),
+        };
+        builder = builder.with_projection(mask.clone());
 
         if let Some(size) = batch_size {
             builder = builder.with_batch_size(size);
@@ -93,71 +291,578 @@ where
 
         let schema = builder.schema().clone();
         let metadata = builder.metadata().clone();
-        let reader = builder.build()?;
 
-        Ok(ColumnIterator {
-            batch_reader: reader,
-            metadata,
-            schema,
-            returned_empty_batch: false,
-            is_empty_file: is_empty,
-            _phantom: std::marker::PhantomData,
+        let batches = if self.skip_corrupt_row_groups {
+            Batches::RowGroups(RowGroupBatches::new(
+                self.inner,
+                arrow_metadata,
+                plan,
+                mask,
+                batch_size,
+            ))
+        } else {
+            Batches::Stream(plan.restrict(builder, &arrow_metadata).build()?)
+        };
+
+        Ok((batches, metadata, schema))
+    }
+
+    /// Which row groups and rows the range filters and sample leave to read
+    fn plan(&self, arrow_metadata: &ArrowReaderMetadata) -> Result<ReadPlan> {
+        let ranges = resolve_ranges(&self.range_filters, arrow_metadata)?;
+        let row_groups = if ranges.is_empty() {
+            (0..arrow_metadata.metadata().num_row_groups()).collect()
+        } else {
+            select_row_groups(&ranges, arrow_metadata)?
+        };
+
+        let selections = match self.sample {
+            Some((sample, seed)) => {
+                let row_counts = row_groups
+                    .iter()
+                    .map(|&index| arrow_metadata.metadata().row_group(index).num_rows() as usize)
+                    .collect::<Vec<_>>();
+                Some(sample_row_groups(sample, seed, &row_counts)?)
+            }
+            None => None,
+        };
+
+        Ok(ReadPlan {
+            row_groups,
+            ranges,
+            selections,
         })
     }
+}
 
-    /// Read columns with projection
-    pub fn read_columns_with_projection(
+/// The row groups to read, with the ranges their rows must lie in and the
+/// sampled rows of each
+struct ReadPlan {
+    row_groups: Vec<usize>,
+    ranges: Vec<ResolvedRange>,
+    /// One selection per entry of `row_groups`
+    selections: Option<Vec<RowSelection>>,
+}
+
+impl ReadPlan {
+    /// Restrict a builder to the planned row groups and rows
+    fn restrict<R>(
         self,
-        columns: &[String],
-        batch_size: Option<usize>,
-    ) -> Result<ColumnIterator<R>> {
-        let mut builder = ParquetRecordBatchReaderBuilder::try_new(self.inner)?;
-        let arrow_schema = builder.schema();
+        mut builder: ParquetRecordBatchReaderBuilder<R>,
+        arrow_metadata: &ArrowReaderMetadata,
+    ) -> ParquetRecordBatchReaderBuilder<R>
+    where
+        R: parquet::file::reader::ChunkReader + 'static,
+    {
+        if self.ranges.is_empty() && self.selections.is_none() {
+            return builder;
+        }
+        builder = builder.with_row_groups(self.row_groups);
+        if let Some(selections) = self.selections {
+            let selectors = selections
+                .into_iter()
+                .flat_map(Vec::<RowSelector>::from)
+                .collect::<Vec<_>>();
+            builder = builder.with_row_selection(RowSelection::from(selectors));
+        }
+        if !self.ranges.is_empty() {
+            let schema_descr = arrow_metadata.metadata().file_metadata().schema_descr();
+            builder = builder.with_row_filter(row_filter(&self.ranges, schema_descr));
+        }
+        builder
+    }
+}
+
+/// Load the footer, reading ENUM columns as dictionaries
+///
+/// Files written by this library already carry dictionary types in their
+/// embedded Arrow schema; files from other writers only have the Parquet
+/// ENUM annotation, so we supply the hint ourselves. Either way the
+/// dictionary page is kept and each distinct value is decoded once.
+fn reader_metadata<R>(reader: &R, dictionary_columns: &[String]) -> Result<ArrowReaderMetadata>
+where
+    R: parquet::file::reader::ChunkReader + 'static,
+{
+    let arrow_metadata = ArrowReaderMetadata::load(reader, ArrowReaderOptions::new())?;
+    with_dictionary_hints(with_read_hints(arrow_metadata)?, dictionary_columns)
+}
+
+fn is_string_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    )
+}
 
-        let is_empty = builder.metadata().file_metadata().num_rows() == 0;
+/// Hint the columns of [`Reader::with_dictionary_columns`] as dictionaries
+fn with_dictionary_hints(
+    arrow_metadata: ArrowReaderMetadata,
+    columns: &[String],
+) -> Result<ArrowReaderMetadata> {
+    if columns.is_empty() {
+        return Ok(arrow_metadata);
+    }
+
+    let schema = arrow_metadata.schema().clone();
+    for column in columns {
+        let field = schema
+            .field_with_name(column)
+            .map_err(|_| ParquetError::invalid_argument(format!("Unknown column {:?}", column)))?;
+        let is_dictionary =
+            matches!(field.data_type(), DataType::Dictionary(_, value) if is_string_type(value));
+        if !is_string_type(field.data_type()) && !is_dictionary {
+            return Err(ParquetError::invalid_argument(format!(
+                "Column {:?} is not a string column and cannot be read as a dictionary",
+                column
+            )));
+        }
+    }
+
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            if columns.contains(field.name()) && is_string_type(field.data_type()) {
+                Arc::new(field.as_ref().clone().with_data_type(DataType::Dictionary(
+                    Box::new(DataType::Int32),
+                    Box::new(DataType::Utf8),
+                )))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let hint = arrow_schema::Schema::new_with_metadata(fields, schema.metadata().clone());
+    Ok(ArrowReaderMetadata::try_new(
+        arrow_metadata.metadata().clone(),
+        ArrowReaderOptions::new().with_schema(Arc::new(hint)),
+    )?)
+}
+
+/// Add the INTERVAL, ENUM and geometry hints to already loaded metadata
+pub(crate) fn with_read_hints(arrow_metadata: ArrowReaderMetadata) -> Result<ArrowReaderMetadata> {
+    let arrow_metadata = with_interval_hints(arrow_metadata)?;
+    let schema = arrow_metadata.schema().clone();
+    let parquet_fields = arrow_metadata
+        .metadata()
+        .file_metadata()
+        .schema_descr()
+        .root_schema()
+        .get_fields()
+        .to_vec();
+
+    // GeoParquet files written elsewhere only describe their geometry
+    // columns in the `geo` footer entry
+    let geometry_columns = arrow_metadata
+        .metadata()
+        .file_metadata()
+        .key_value_metadata()
+        .and_then(|kv| kv.iter().find(|kv| kv.key == GEO_METADATA_KEY))
+        .and_then(|kv| kv.value.as_deref())
+        .map(geo_metadata_columns)
+        .unwrap_or_default();
+
+    let mut hinted = false;
+    let fields = schema
+        .fields()
+        .iter()
+        .zip(parquet_fields.iter())
+        .map(|(field, parquet_field)| {
+            let is_binary = matches!(
+                field.data_type(),
+                DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+            );
+            if is_binary
+                && field.extension_type_name().is_none()
+                && geometry_columns.iter().any(|c| c == field.name())
+            {
+                hinted = true;
+                let mut metadata = field.metadata().clone();
+                metadata.insert(
+                    arrow_schema::extension::EXTENSION_TYPE_NAME_KEY.to_string(),
+                    GEOMETRY_EXTENSION_NAME.to_string(),
+                );
+                return Arc::new(field.as_ref().clone().with_metadata(metadata));
+            }
+
+            let is_string = matches!(
+                field.data_type(),
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+            );
+            if is_string && is_enum_field(field, parquet_field) {
+                hinted = true;
+                Arc::new(field.as_ref().clone().with_data_type(DataType::Dictionary(
+                    Box::new(DataType::Int32),
+                    Box::new(DataType::Utf8),
+                )))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if hinted {
+        let hint = arrow_schema::Schema::new_with_metadata(fields, schema.metadata().clone());
+        Ok(ArrowReaderMetadata::try_new(
+            arrow_metadata.metadata().clone(),
+            ArrowReaderOptions::new().with_schema(Arc::new(hint)),
+        )?)
+    } else {
+        Ok(arrow_metadata)
+    }
+}
+
+/// Read Parquet INTERVAL columns as their raw 12 bytes
+///
+/// The Arrow reader decodes INTERVAL as a day/time interval and drops the
+/// months, so the annotation is left out of the schema it reads with and
+/// the columns are tagged with our extension name to be decoded by us.
+fn with_interval_hints(arrow_metadata: ArrowReaderMetadata) -> Result<ArrowReaderMetadata> {
+    let metadata = arrow_metadata.metadata();
+    let file_metadata = metadata.file_metadata();
+    let root = file_metadata.schema_descr().root_schema_ptr();
+    let Some(stripped) = without_intervals(&root)? else {
+        return Ok(arrow_metadata);
+    };
+
+    let schema = arrow_metadata.schema();
+    let fields = schema
+        .fields()
+        .iter()
+        .zip(root.get_fields())
+        .map(|(field, parquet_field)| interval_hint(field, parquet_field))
+        .collect::<Vec<_>>();
+    let hint = arrow_schema::Schema::new_with_metadata(fields, schema.metadata().clone());
+
+    let file_metadata = FileMetaData::new(
+        file_metadata.version(),
+        file_metadata.num_rows(),
+        file_metadata.created_by().map(str::to_string),
+        file_metadata.key_value_metadata().cloned(),
+        Arc::new(SchemaDescriptor::new(stripped)),
+        file_metadata.column_orders().cloned(),
+    );
+    let metadata = ParquetMetaData::new(file_metadata, metadata.row_groups().to_vec());
+    Ok(ArrowReaderMetadata::try_new(
+        Arc::new(metadata),
+        ArrowReaderOptions::new().with_schema(Arc::new(hint)),
+    )?)
+}
+
+/// Drop the INTERVAL annotation from a schema, or `None` if it has none
+fn without_intervals(parquet_type: &TypePtr) -> Result<Option<TypePtr>> {
+    let info = parquet_type.get_basic_info();
+    match parquet_type.as_ref() {
+        Type::PrimitiveType {
+            physical_type,
+            type_length,
+            ..
+        } => {
+            if info.converted_type() != ConvertedType::INTERVAL {
+                return Ok(None);
+            }
+            let stripped = Type::primitive_type_builder(info.name(), *physical_type)
+                .with_repetition(info.repetition())
+                .with_length(*type_length)
+                .with_id(info.has_id().then(|| info.id()))
+                .build()?;
+            Ok(Some(Arc::new(stripped)))
+        }
+        Type::GroupType { fields, .. } => {
+            let stripped = fields
+                .iter()
+                .map(without_intervals)
+                .collect::<Result<Vec<_>>>()?;
+            if stripped.iter().all(Option::is_none) {
+                return Ok(None);
+            }
 
-        // Create projection mask
-        let mut column_indices = Vec::new();
-        for (idx, field) in arrow_schema.fields().iter().enumerate() {
-            if columns.contains(&field.name().to_string()) {
-                column_indices.push(idx);
+            let fields = stripped
+                .into_iter()
+                .zip(fields)
+                .map(|(stripped, field)| stripped.unwrap_or_else(|| field.clone()))
+                .collect();
+            let mut group = Type::group_type_builder(info.name())
+                .with_logical_type(info.logical_type())
+                .with_converted_type(info.converted_type())
+                .with_id(info.has_id().then(|| info.id()))
+                .with_fields(fields);
+            if info.has_repetition() {
+                group = group.with_repetition(info.repetition());
             }
+            Ok(Some(Arc::new(group.build()?)))
         }
+    }
+}
+
+/// Read the INTERVAL leaves under `field` as tagged fixed size binary
+fn interval_hint(field: &FieldRef, parquet_field: &TypePtr) -> FieldRef {
+    let data_type = match (field.data_type(), parquet_field.as_ref()) {
+        (DataType::Struct(children), Type::GroupType { fields, .. }) => DataType::Struct(
+            children
+                .iter()
+                .zip(fields)
+                .map(|(child, parquet_child)| interval_hint(child, parquet_child))
+                .collect(),
+        ),
+        (DataType::List(item), _) => {
+            DataType::List(interval_hint(item, list_element(parquet_field)))
+        }
+        (DataType::LargeList(item), _) => {
+            DataType::LargeList(interval_hint(item, list_element(parquet_field)))
+        }
+        (DataType::Map(entries, sorted), Type::GroupType { fields, .. }) if !fields.is_empty() => {
+            DataType::Map(interval_hint(entries, &fields[0]), *sorted)
+        }
+        (_, Type::PrimitiveType { .. })
+            if parquet_field.get_basic_info().converted_type() == ConvertedType::INTERVAL =>
+        {
+            let mut metadata = field.metadata().clone();
+            metadata.insert(
+                arrow_schema::extension::EXTENSION_TYPE_NAME_KEY.to_string(),
+                INTERVAL_EXTENSION_NAME.to_string(),
+            );
+            return Arc::new(
+                field
+                    .as_ref()
+                    .clone()
+                    .with_data_type(DataType::FixedSizeBinary(12))
+                    .with_metadata(metadata),
+            );
+        }
+        _ => return field.clone(),
+    };
+    Arc::new(field.as_ref().clone().with_data_type(data_type))
+}
 
-        // Allow empty column projections to match v1 behavior
-        // This will result in rows with no fields
+/// The element of a LIST group, or the repeated field itself for the
+/// two-level list layouts of older writers
+fn list_element(parquet_field: &TypePtr) -> &TypePtr {
+    let Type::GroupType { fields, .. } = parquet_field.as_ref() else {
+        return parquet_field;
+    };
+    match fields.first().map(|repeated| repeated.as_ref()) {
+        Some(Type::GroupType {
+            fields: element, ..
+        }) if element.len() == 1 => &element[0],
+        _ => fields.first().unwrap_or(parquet_field),
+    }
+}
 
-        let mask = parquet::arrow::ProjectionMask::roots(builder.parquet_schema(), column_indices);
-        builder = builder.with_projection(mask);
+/// Decoded dictionary values, kept per column while consecutive batches
+/// share the same dictionary
+#[derive(Default)]
+struct DictionaryCache {
+    entries: Vec<Option<(ArrayRef, Vec<ParquetValue>)>>,
+}
 
-        if let Some(size) = batch_size {
-            builder = builder.with_batch_size(size);
+impl DictionaryCache {
+    /// Convert a single value, decoding dictionary columns through the cache
+    fn value(
+        &mut self,
+        column_idx: usize,
+        field: &Field,
+        parquet_field: &Type,
+        column: &ArrayRef,
+        row: usize,
+    ) -> Result<ParquetValue> {
+        let Some(dictionary) = column.as_any_dictionary_opt() else {
+            return arrow_to_parquet_value(field, parquet_field, column, row);
+        };
+
+        if column.is_null(row) {
+            return Ok(ParquetValue::Null);
         }
 
-        let schema = builder.schema().clone();
-        let metadata = builder.metadata().clone();
-        let reader = builder.build()?;
+        let values = dictionary.values();
 
-        Ok(ColumnIterator {
-            batch_reader: reader,
-            metadata,
-            schema,
-            returned_empty_batch: false,
-            is_empty_file: is_empty,
-            _phantom: std::marker::PhantomData,
-        })
+        // Large dictionaries relative to the batch are cheaper to decode lazily
+        if values.len() > column.len() {
+            return arrow_to_parquet_value(field, parquet_field, column, row);
+        }
+
+        if self.entries.len() <= column_idx {
+            self.entries.resize_with(column_idx + 1, || None);
+        }
+
+        let cached = matches!(
+            &self.entries[column_idx],
+            Some((array, _)) if Arc::ptr_eq(array, values)
+        );
+        if !cached {
+            let decoded = (0..values.len())
+                .map(|i| arrow_to_parquet_value(field, parquet_field, values, i))
+                .collect::<Result<Vec<_>>>()?;
+            self.entries[column_idx] = Some((values.clone(), decoded));
+        }
+
+        let key = dictionary_key(dictionary.keys(), row)?;
+        match &self.entries[column_idx] {
+            Some((_, decoded)) => decoded.get(key).cloned().ok_or_else(|| {
+                ParquetError::Conversion(format!("Dictionary key {} out of bounds", key))
+            }),
+            None => arrow_to_parquet_value(field, parquet_field, column, row),
+        }
+    }
+}
+
+/// A row group left out of a read with
+/// [`Reader::with_skip_corrupt_row_groups`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRowGroup {
+    pub index: usize,
+    pub num_rows: i64,
+    /// Why the row group could not be decoded
+    pub message: String,
+}
+
+/// Where row and column iterators take their record batches from
+enum Batches {
+    Stream(ParquetRecordBatchReader),
+    RowGroups(RowGroupBatches),
+}
+
+impl Batches {
+    fn next_batch(&mut self) -> Option<Result<RecordBatch>> {
+        match self {
+            Batches::Stream(reader) => reader.next().map(|batch| batch.map_err(Into::into)),
+            Batches::RowGroups(row_groups) => row_groups.next_batch(),
+        }
+    }
+
+    fn take_skipped(&mut self) -> Vec<SkippedRowGroup> {
+        match self {
+            Batches::Stream(_) => Vec::new(),
+            Batches::RowGroups(row_groups) => std::mem::take(&mut row_groups.skipped),
+        }
+    }
+}
+
+/// Record batches decoded one row group at a time, skipping row groups
+/// that fail to decode
+struct RowGroupBatches {
+    decode: Box<dyn FnMut(usize, Option<RowSelection>) -> Result<Vec<RecordBatch>> + Send>,
+    /// Index, row count and sampled rows of the row groups still to decode
+    row_groups: VecDeque<(usize, i64, Option<RowSelection>)>,
+    buffered: VecDeque<RecordBatch>,
+    skipped: Vec<SkippedRowGroup>,
+}
+
+impl RowGroupBatches {
+    fn new<R>(
+        reader: R,
+        metadata: ArrowReaderMetadata,
+        plan: ReadPlan,
+        mask: ProjectionMask,
+        batch_size: Option<usize>,
+    ) -> Self
+    where
+        R: parquet::file::reader::ChunkReader + Clone + 'static,
+    {
+        let ReadPlan {
+            row_groups,
+            ranges,
+            selections,
+        } = plan;
+        let mut selections = selections.map(Vec::into_iter);
+        let row_groups = row_groups
+            .into_iter()
+            .map(|index| {
+                let num_rows = metadata.metadata().row_group(index).num_rows();
+                let selection = selections.as_mut().and_then(Iterator::next);
+                (index, num_rows, selection)
+            })
+            .collect();
+
+        let decode =
+            move |row_group: usize, selection: Option<RowSelection>| -> Result<Vec<RecordBatch>> {
+                let mut builder = ParquetRecordBatchReaderBuilder::new_with_metadata(
+                    reader.clone(),
+                    metadata.clone(),
+                )
+                .with_row_groups(vec![row_group])
+                .with_projection(mask.clone());
+                if let Some(size) = batch_size {
+                    builder = builder.with_batch_size(size);
+                }
+                if let Some(selection) = selection {
+                    builder = builder.with_row_selection(selection);
+                }
+                if !ranges.is_empty() {
+                    let schema_descr = metadata.metadata().file_metadata().schema_descr();
+                    builder = builder.with_row_filter(row_filter(&ranges, schema_descr));
+                }
+                Ok(builder
+                    .build()?
+                    .collect::<std::result::Result<Vec<_>, _>>()?)
+            };
+
+        Self {
+            decode: Box::new(decode),
+            row_groups,
+            buffered: VecDeque::new(),
+            skipped: Vec::new(),
+        }
+    }
+
+    fn next_batch(&mut self) -> Option<Result<RecordBatch>> {
+        loop {
+            if let Some(batch) = self.buffered.pop_front() {
+                return Some(Ok(batch));
+            }
+
+            let (index, num_rows, selection) = self.row_groups.pop_front()?;
+
+            match (self.decode)(index, selection) {
+                Ok(batches) => self.buffered.extend(batches),
+                Err(e) => self.skipped.push(SkippedRowGroup {
+                    index,
+                    num_rows,
+                    message: e.to_string(),
+                }),
+            }
+        }
     }
 }
 
 /// Iterator over rows in a Parquet file
 pub struct RowIterator<R> {
-    batch_reader: ParquetRecordBatchReader,
+    batch_reader: Batches,
     metadata: Arc<ParquetMetaData>,
     current_batch: Option<RecordBatch>,
     current_row: usize,
+    dictionaries: DictionaryCache,
     _phantom: std::marker::PhantomData<R>,
 }
 
+impl<R> RowIterator<R> {
+    fn new(batch_reader: Batches, metadata: Arc<ParquetMetaData>) -> Self {
+        Self {
+            batch_reader,
+            metadata,
+            current_batch: None,
+            current_row: 0,
+            dictionaries: DictionaryCache::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Iterate over an already built batch reader, e.g. one over salvaged
+    /// row groups
+    pub(crate) fn from_batch_reader(
+        batch_reader: ParquetRecordBatchReader,
+        metadata: Arc<ParquetMetaData>,
+    ) -> Self {
+        Self::new(Batches::Stream(batch_reader), metadata)
+    }
+
+    /// Row groups skipped since the last call
+    pub fn take_skipped_row_groups(&mut self) -> Vec<SkippedRowGroup> {
+        self.batch_reader.take_skipped()
+    }
+}
+
 impl<R> Iterator for RowIterator<R>
 where
     R: parquet::file::reader::ChunkReader + 'static,
@@ -193,7 +898,8 @@ where
                             // Fallback to leaf column if index out of bounds
                             schema_descriptor.column(i).self_type_ptr()
                         };
-                        let value = match arrow_to_parquet_value(
+                        let value = match self.dictionaries.value(
+                            i,
                             field,
                             &parquet_field,
                             column,
@@ -211,12 +917,12 @@ where
             }
 
             // Need to fetch next batch
-            match self.batch_reader.next() {
+            match self.batch_reader.next_batch() {
                 Some(Ok(batch)) => {
                     self.current_batch = Some(batch);
                     self.current_row = 0;
                 }
-                Some(Err(e)) => return Some(Err(e.into())),
+                Some(Err(e)) => return Some(Err(e)),
                 None => return None,
             }
         }
@@ -225,14 +931,39 @@ where
 
 /// Iterator over column batches in a Parquet file
 pub struct ColumnIterator<R> {
-    batch_reader: ParquetRecordBatchReader,
+    batch_reader: Batches,
     metadata: Arc<ParquetMetaData>,
     schema: Arc<arrow_schema::Schema>,
     returned_empty_batch: bool,
     is_empty_file: bool,
+    dictionaries: DictionaryCache,
     _phantom: std::marker::PhantomData<R>,
 }
 
+impl<R> ColumnIterator<R> {
+    fn new(
+        batch_reader: Batches,
+        metadata: Arc<ParquetMetaData>,
+        schema: Arc<arrow_schema::Schema>,
+    ) -> Self {
+        let is_empty_file = metadata.file_metadata().num_rows() == 0;
+        Self {
+            batch_reader,
+            metadata,
+            schema,
+            returned_empty_batch: false,
+            is_empty_file,
+            dictionaries: DictionaryCache::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Row groups skipped since the last call
+    pub fn take_skipped_row_groups(&mut self) -> Vec<SkippedRowGroup> {
+        self.batch_reader.take_skipped()
+    }
+}
+
 /// A batch of columns with their names
 pub struct ColumnBatch {
     pub columns: Vec<(String, Vec<ParquetValue>)>,
@@ -258,7 +989,7 @@ where
             return Some(Ok(ColumnBatch { columns }));
         }
 
-        match self.batch_reader.next() {
+        match self.batch_reader.next_batch() {
             Some(Ok(batch)) => {
                 let mut columns = Vec::with_capacity(batch.num_columns());
                 let schema_descriptor = self.metadata.file_metadata().schema_descr_ptr();
@@ -286,7 +1017,10 @@ where
                             // Fallback to leaf column if index out of bounds
                             schema_descriptor.column(idx).self_type_ptr()
                         };
-                        match arrow_to_parquet_value(field, &parquet_field, column, row_idx) {
+                        match self
+                            .dictionaries
+                            .value(idx, field, &parquet_field, column, row_idx)
+                        {
                             Ok(value) => values.push(value),
                             Err(e) => return Some(Err(e)),
                         }
@@ -297,7 +1031,7 @@ where
 
                 Some(Ok(ColumnBatch { columns }))
             }
-            Some(Err(e)) => Some(Err(e.into())),
+            Some(Err(e)) => Some(Err(e)),
             None => None,
         }
     }
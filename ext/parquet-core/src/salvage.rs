@@ -0,0 +1,572 @@
+//! Recovery of files whose footer is missing
+//!
+//! A writer that stops before [`Writer::close`](crate::Writer::close) leaves
+//! its finished row groups on disk without the footer that describes them.
+//! [`salvage`] walks the page headers from the start of the file, splits the
+//! pages into column chunks using the schema the file was written with, and
+//! rebuilds a footer for every row group that decodes.
+//!
+//! Pages do not record their codec, so each row group is decoded with the
+//! candidate codecs until one yields the expected row count. Column chunks
+//! are told apart by their dictionary pages and by counting rows, which a
+//! data page only tells for non-repeated columns or in its V2 header; a
+//! file whose columns are all repeated and written without V2 pages cannot
+//! be split. The first chunk of a row group has no row count to go by, so
+//! its possible ends are tried until the row group adds up and decodes.
+
+use crate::convert::write_record_batches;
+use crate::reader::{with_read_hints, RowIterator};
+use crate::verify::VerifyProblem;
+use crate::writer::{schema_to_arrow, WriterBuilder};
+use crate::{ParquetError, Result, Schema};
+use arrow_schema::SchemaRef;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
+    ParquetRecordBatchReaderBuilder,
+};
+use parquet::arrow::ArrowSchemaConverter;
+use parquet::basic::{BrotliLevel, Compression, GzipLevel, ZstdLevel};
+use parquet::file::metadata::{
+    ColumnChunkMetaData, FileMetaData, ParquetMetaData, RowGroupMetaData,
+};
+use parquet::file::reader::{ChunkReader, Length};
+use parquet::format::PageHeader;
+use parquet::schema::types::{ColumnDescPtr, SchemaDescPtr};
+use parquet::thrift::{TCompactSliceInputProtocol, TSerializable};
+use std::io::Write;
+use std::ops::Range;
+use std::sync::Arc;
+
+const MAGIC: &[u8; 4] = b"PAR1";
+
+/// Bytes read to parse a page header; headers that do not fit are retried
+/// with up to [`MAX_PAGE_HEADER_SIZE`] bytes
+const PAGE_HEADER_WINDOW: usize = 64 * 1024;
+const MAX_PAGE_HEADER_SIZE: usize = 16 * 1024 * 1024;
+
+/// The schema a damaged file was written with
+#[derive(Debug, Clone)]
+pub struct SalvageSchema {
+    parquet: SchemaDescPtr,
+    arrow: SchemaRef,
+}
+
+impl SalvageSchema {
+    /// Use the schema the file was written with
+    pub fn from_schema(schema: &Schema) -> Result<Self> {
+        let arrow = schema_to_arrow(schema)?;
+        let parquet = ArrowSchemaConverter::new().convert(&arrow)?;
+        Ok(Self {
+            parquet: Arc::new(parquet),
+            arrow,
+        })
+    }
+
+    /// Take the schema from the footer of a sibling file written the same way
+    pub fn from_file<R: ChunkReader + 'static>(reader: &R) -> Result<Self> {
+        let metadata = ArrowReaderMetadata::load(reader, ArrowReaderOptions::new())?;
+        Ok(Self {
+            parquet: metadata.metadata().file_metadata().schema_descr_ptr(),
+            arrow: metadata.schema().clone(),
+        })
+    }
+}
+
+/// Outcome of [`salvage`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SalvageReport {
+    pub file_size: u64,
+    /// Offset at which the page scan stopped
+    pub scanned_bytes: u64,
+    /// Pages whose headers were read
+    pub pages: usize,
+    /// Row groups rebuilt
+    pub row_groups: usize,
+    pub num_rows: i64,
+    /// Where the scan stopped and which row groups were given up
+    pub problems: Vec<VerifyProblem>,
+}
+
+/// A damaged file with a rebuilt footer
+pub struct Salvaged<R> {
+    reader: R,
+    metadata: ArrowReaderMetadata,
+    report: SalvageReport,
+}
+
+impl<R> Salvaged<R>
+where
+    R: ChunkReader + 'static,
+{
+    pub fn report(&self) -> &SalvageReport {
+        &self.report
+    }
+
+    /// The rebuilt footer, holding only the recovered row groups
+    pub fn metadata(&self) -> &ParquetMetaData {
+        self.metadata.metadata()
+    }
+
+    /// Read the recovered row groups as Arrow record batches
+    pub fn read_batches(self) -> Result<ParquetRecordBatchReader> {
+        Ok(
+            ParquetRecordBatchReaderBuilder::new_with_metadata(self.reader, self.metadata)
+                .build()?,
+        )
+    }
+
+    /// Read the recovered row groups row by row
+    pub fn read_rows(self) -> Result<RowIterator<R>> {
+        let metadata = self.metadata.metadata().clone();
+        Ok(RowIterator::from_batch_reader(
+            self.read_batches()?,
+            metadata,
+        ))
+    }
+
+    /// Write the recovered rows to a new file, returning the number of rows
+    pub fn write<W: Write + Send>(self, output: W, builder: WriterBuilder) -> Result<usize> {
+        write_record_batches(self.read_batches()?, output, None, builder)
+    }
+}
+
+/// A page found by the scan
+struct ScannedPage {
+    offset: u64,
+    /// Header and body
+    len: u64,
+    /// Header and decompressed body
+    uncompressed_len: u64,
+    /// Whether the body is stored at its decompressed size
+    stored: bool,
+    dictionary: bool,
+    values: i64,
+    /// Only V2 data pages record their rows
+    rows: Option<i64>,
+}
+
+/// One column chunk per leaf column, as ranges of scanned pages
+struct ScannedRowGroup {
+    chunks: Vec<Range<usize>>,
+    num_rows: i64,
+}
+
+/// Rebuild the footer of a file from its pages
+///
+/// Row groups that cannot be split or decoded are reported and left out;
+/// everything after the first page header that cannot be read is lost.
+/// Only a file that does not start with the Parquet magic is an error.
+pub fn salvage<R>(reader: R, schema: &SalvageSchema) -> Result<Salvaged<R>>
+where
+    R: ChunkReader + Clone + 'static,
+{
+    let file_size = reader.len();
+    let magic = reader.get_bytes(0, MAGIC.len().min(file_size as usize))?;
+    if magic.as_ref() != MAGIC {
+        return Err(ParquetError::invalid_argument(
+            "Not a Parquet file: the PAR1 magic is missing",
+        ));
+    }
+    if schema.parquet.num_columns() == 0 {
+        return Err(ParquetError::schema(
+            "Cannot salvage a schema without columns",
+        ));
+    }
+
+    let mut report = SalvageReport {
+        file_size,
+        ..Default::default()
+    };
+    let pages = scan_pages(&reader, &mut report);
+    let leaves = schema.parquet.columns();
+
+    let mut row_groups = Vec::new();
+    let mut codec = None;
+    let mut position = 0;
+    let mut index = 0;
+    while position < pages.len() {
+        // The first split that adds up and decodes wins; failing that, the
+        // first that adds up is reported and skipped
+        let mut split_problem = None;
+        let mut decode_problem = None;
+        let mut rebuilt = None;
+        for first_end in first_chunk_ends(&pages, position) {
+            let group = match split_row_group(&pages, position, first_end, leaves) {
+                Ok(group) => group,
+                Err(problem) => {
+                    split_problem.get_or_insert(problem);
+                    continue;
+                }
+            };
+            let end = group.chunks.last().map_or(position, |chunk| chunk.end);
+            match rebuild_row_group(
+                &reader,
+                schema,
+                &pages,
+                &group,
+                row_groups.len(),
+                &mut codec,
+            ) {
+                Ok(row_group) => {
+                    rebuilt = Some((row_group, end));
+                    break;
+                }
+                Err(message) => {
+                    decode_problem.get_or_insert((message, end));
+                }
+            }
+        }
+
+        let page_offset = Some(pages[position].offset);
+        match (rebuilt, decode_problem) {
+            (Some((row_group, end)), _) => {
+                row_groups.push(row_group);
+                position = end;
+            }
+            (None, Some((message, end))) => {
+                report.problems.push(VerifyProblem {
+                    row_group: Some(index),
+                    column: None,
+                    page_offset,
+                    message,
+                });
+                position = end;
+            }
+            (None, None) => {
+                report.problems.push(VerifyProblem {
+                    row_group: Some(index),
+                    column: None,
+                    page_offset,
+                    message: format!(
+                        "Discarded the remaining pages: {}",
+                        split_problem.unwrap_or_default()
+                    ),
+                });
+                break;
+            }
+        }
+        index += 1;
+    }
+
+    report.row_groups = row_groups.len();
+    report.num_rows = row_groups.iter().map(|rg| rg.num_rows()).sum();
+    let metadata = reader_metadata(schema, row_groups)?;
+
+    Ok(Salvaged {
+        reader,
+        metadata,
+        report,
+    })
+}
+
+/// Read page headers back to back from the first byte after the magic
+fn scan_pages<R: ChunkReader>(reader: &R, report: &mut SalvageReport) -> Vec<ScannedPage> {
+    let mut pages = Vec::new();
+    let mut offset = MAGIC.len() as u64;
+    while offset < report.file_size {
+        match read_page(reader, offset, report.file_size) {
+            Ok(page) => {
+                offset += page.len;
+                pages.push(page);
+            }
+            Err(message) => {
+                report.problems.push(VerifyProblem {
+                    row_group: None,
+                    column: None,
+                    page_offset: Some(offset),
+                    message: format!("Scan stopped: {}", message),
+                });
+                break;
+            }
+        }
+    }
+    report.scanned_bytes = offset;
+    report.pages = pages.len();
+    pages
+}
+
+fn read_page<R: ChunkReader>(
+    reader: &R,
+    offset: u64,
+    file_size: u64,
+) -> std::result::Result<ScannedPage, String> {
+    let remaining = usize::try_from(file_size - offset).unwrap_or(usize::MAX);
+    let mut window = remaining.min(PAGE_HEADER_WINDOW);
+    let (header, header_len) = loop {
+        let bytes = reader
+            .get_bytes(offset, window)
+            .map_err(|e| format!("Cannot read page header: {}", e))?;
+        let mut protocol = TCompactSliceInputProtocol::new(&bytes);
+        match PageHeader::read_from_in_protocol(&mut protocol) {
+            Ok(header) => break (header, (bytes.len() - protocol.as_slice().len()) as u64),
+            Err(_) if window < remaining.min(MAX_PAGE_HEADER_SIZE) => {
+                window = remaining.min(MAX_PAGE_HEADER_SIZE)
+            }
+            Err(e) => return Err(format!("Invalid page header: {}", e)),
+        }
+    };
+
+    let (dictionary, values, rows) = if let Some(h) = &header.dictionary_page_header {
+        (true, h.num_values, None)
+    } else if let Some(h) = &header.data_page_header {
+        (false, h.num_values, None)
+    } else if let Some(h) = &header.data_page_header_v2 {
+        (false, h.num_values, Some(h.num_rows as i64))
+    } else {
+        return Err(format!("Unexpected {:?} page", header.type_));
+    };
+
+    let body_len = u64::try_from(header.compressed_page_size);
+    let uncompressed_len = u64::try_from(header.uncompressed_page_size);
+    let (Ok(body_len), Ok(uncompressed_len)) = (body_len, uncompressed_len) else {
+        return Err("Invalid page header: negative page size".to_string());
+    };
+    if values < 0 {
+        return Err("Invalid page header: negative value count".to_string());
+    }
+    let len = header_len + body_len;
+    if offset + len > file_size {
+        return Err(format!(
+            "Page of {} bytes runs past the end of the file",
+            body_len
+        ));
+    }
+
+    Ok(ScannedPage {
+        offset,
+        len,
+        uncompressed_len: header_len + uncompressed_len,
+        stored: body_len == uncompressed_len,
+        dictionary,
+        values: values as i64,
+        rows,
+    })
+}
+
+/// Where the first column chunk of the row group at `start` may end
+///
+/// Later chunks end once they hold as many rows as the first, but nothing
+/// bounds the first besides the next dictionary page, which only starts
+/// the next chunk when that column is dictionary encoded. That end is tried
+/// first, then every earlier page boundary, shortest chunk first.
+fn first_chunk_ends(pages: &[ScannedPage], start: usize) -> Vec<usize> {
+    let data_start = start + usize::from(pages[start].dictionary);
+    let mut ends = (data_start..pages.len())
+        .take_while(|&i| !pages[i].dictionary)
+        .map(|i| i + 1)
+        .collect::<Vec<_>>();
+    match ends.pop() {
+        Some(last) => ends.insert(0, last),
+        // No data pages, which the split reports
+        None => ends.push(data_start),
+    }
+    ends
+}
+
+/// Group the pages from `start` into one column chunk per leaf column, in
+/// schema order
+///
+/// The first chunk ends at `first_end`. The others start with their
+/// dictionary page, if any, and end before the next dictionary page or once
+/// they hold as many rows as the chunks before them.
+fn split_row_group(
+    pages: &[ScannedPage],
+    start: usize,
+    first_end: usize,
+    leaves: &[ColumnDescPtr],
+) -> std::result::Result<ScannedRowGroup, String> {
+    let mut position = start;
+    let mut chunks = Vec::with_capacity(leaves.len());
+    let mut num_rows: Option<i64> = None;
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        let chunk_start = position;
+        if pages.get(position).is_some_and(|page| page.dictionary) {
+            position += 1;
+        }
+
+        // `None` once a page does not tell its rows
+        let mut rows = Some(0i64);
+        while let Some(page) = pages.get(position) {
+            let full = if index == 0 {
+                position >= first_end
+            } else {
+                matches!((rows, num_rows), (Some(rows), Some(target)) if rows >= target)
+            };
+            if page.dictionary || full {
+                break;
+            }
+            let page_rows = page
+                .rows
+                .or((leaf.max_rep_level() == 0).then_some(page.values));
+            rows = rows
+                .zip(page_rows)
+                .map(|(rows, page_rows)| rows + page_rows);
+            position += 1;
+        }
+
+        if !pages[chunk_start..position]
+            .iter()
+            .any(|page| !page.dictionary)
+        {
+            return Err(format!("No data pages for column {}", leaf.path().string()));
+        }
+        match (rows, num_rows) {
+            (Some(rows), None) => num_rows = Some(rows),
+            (Some(rows), Some(expected)) if rows != expected => {
+                return Err(format!(
+                    "Column {} holds {} rows where {} were expected",
+                    leaf.path().string(),
+                    rows,
+                    expected
+                ));
+            }
+            _ => {}
+        }
+        chunks.push(chunk_start..position);
+    }
+
+    match num_rows {
+        Some(num_rows) => Ok(ScannedRowGroup { chunks, num_rows }),
+        None => Err("Cannot count the rows of a row group".to_string()),
+    }
+}
+
+/// Find the codec a row group decodes with, starting with the one the
+/// previous row group used
+fn rebuild_row_group<R>(
+    reader: &R,
+    schema: &SalvageSchema,
+    pages: &[ScannedPage],
+    group: &ScannedRowGroup,
+    ordinal: usize,
+    last_codec: &mut Option<Compression>,
+) -> std::result::Result<RowGroupMetaData, String>
+where
+    R: ChunkReader + Clone + 'static,
+{
+    // Decoding compressed pages as uncompressed could succeed with garbage
+    let stored = group
+        .chunks
+        .iter()
+        .flat_map(|chunk| &pages[chunk.clone()])
+        .all(|page| page.stored);
+
+    let mut candidates: Vec<Compression> = last_codec.iter().copied().collect();
+    let codecs = [
+        Compression::UNCOMPRESSED,
+        Compression::SNAPPY,
+        Compression::ZSTD(ZstdLevel::default()),
+        Compression::GZIP(GzipLevel::default()),
+        Compression::LZ4_RAW,
+        Compression::BROTLI(BrotliLevel::default()),
+        Compression::LZ4,
+    ];
+    for codec in codecs {
+        if (stored || codec != Compression::UNCOMPRESSED) && !candidates.contains(&codec) {
+            candidates.push(codec);
+        }
+    }
+
+    let mut last_error = String::new();
+    for codec in candidates {
+        let row_group = build_row_group(schema, pages, group, ordinal, codec)
+            .map_err(|e| format!("Cannot rebuild row group: {}", e))?;
+        match decode_rows(reader, schema, row_group.clone()) {
+            Ok(rows) if rows == group.num_rows => {
+                *last_codec = Some(codec);
+                return Ok(row_group);
+            }
+            Ok(rows) => {
+                last_error = format!("decoded {} of {} rows", rows, group.num_rows);
+            }
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(format!("Cannot decode row group: {}", last_error))
+}
+
+fn build_row_group(
+    schema: &SalvageSchema,
+    pages: &[ScannedPage],
+    group: &ScannedRowGroup,
+    ordinal: usize,
+    codec: Compression,
+) -> Result<RowGroupMetaData> {
+    let columns = schema
+        .parquet
+        .columns()
+        .iter()
+        .zip(&group.chunks)
+        .map(|(leaf, chunk)| {
+            let chunk_pages = &pages[chunk.clone()];
+            let first = &chunk_pages[0];
+            let data_page = chunk_pages
+                .iter()
+                .find(|page| !page.dictionary)
+                .unwrap_or(first);
+            let end = chunk_pages
+                .last()
+                .map(|page| page.offset + page.len)
+                .unwrap_or(first.offset);
+
+            Ok(ColumnChunkMetaData::builder(leaf.clone())
+                .set_compression(codec)
+                .set_num_values(
+                    chunk_pages
+                        .iter()
+                        .filter(|page| !page.dictionary)
+                        .map(|page| page.values)
+                        .sum(),
+                )
+                .set_total_compressed_size((end - first.offset) as i64)
+                .set_total_uncompressed_size(
+                    chunk_pages
+                        .iter()
+                        .map(|page| page.uncompressed_len as i64)
+                        .sum(),
+                )
+                .set_data_page_offset(data_page.offset as i64)
+                .set_dictionary_page_offset(first.dictionary.then_some(first.offset as i64))
+                .build()?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let total_byte_size = columns.iter().map(|c| c.uncompressed_size()).sum();
+    Ok(RowGroupMetaData::builder(schema.parquet.clone())
+        .set_num_rows(group.num_rows)
+        .set_total_byte_size(total_byte_size)
+        .set_column_metadata(columns)
+        .set_ordinal(ordinal as i16)
+        .build()?)
+}
+
+/// Decode every column of a single row group, returning its row count
+fn decode_rows<R>(reader: &R, schema: &SalvageSchema, row_group: RowGroupMetaData) -> Result<i64>
+where
+    R: ChunkReader + Clone + 'static,
+{
+    let metadata = reader_metadata(schema, vec![row_group])?;
+    let batches =
+        ParquetRecordBatchReaderBuilder::new_with_metadata(reader.clone(), metadata).build()?;
+    let mut rows = 0;
+    for batch in batches {
+        rows += batch?.num_rows() as i64;
+    }
+    Ok(rows)
+}
+
+/// A footer holding the given row groups, read with the salvage schema
+fn reader_metadata(
+    schema: &SalvageSchema,
+    row_groups: Vec<RowGroupMetaData>,
+) -> Result<ArrowReaderMetadata> {
+    let num_rows = row_groups.iter().map(|rg| rg.num_rows()).sum();
+    let file_metadata = FileMetaData::new(1, num_rows, None, None, schema.parquet.clone(), None);
+    let metadata = ParquetMetaData::new(file_metadata, row_groups);
+    let arrow_metadata = ArrowReaderMetadata::try_new(
+        Arc::new(metadata),
+        ArrowReaderOptions::new().with_schema(schema.arrow.clone()),
+    )?;
+    with_read_hints(arrow_metadata)
+}
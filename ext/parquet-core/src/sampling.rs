@@ -0,0 +1,165 @@
+//! Random samples of rows
+//!
+//! Rows are picked from the row counts in the footer alone, spread over the
+//! row groups in proportion to their size, and read through a
+//! [`RowSelection`] per row group. Pages are still decompressed, but rows
+//! that were not picked are skipped by the decoders instead of being
+//! converted. A seed makes the same file give the same sample.
+
+use crate::{ParquetError, Result};
+use parquet::arrow::arrow_reader::RowSelection;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::ops::Range;
+
+/// How many rows a sampled read returns
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+    /// This many rows, chosen uniformly without replacement; every row when
+    /// the file has fewer
+    Rows(usize),
+    /// Every row independently with this probability, between 0 and 1
+    Rate(f64),
+}
+
+impl Sample {
+    /// Check the sample can be taken, e.g. that a rate is a probability
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            Sample::Rate(rate) if !(0.0..=1.0).contains(&rate) => {
+                Err(ParquetError::invalid_argument(format!(
+                    "Sample rate must be between 0 and 1, got {}",
+                    rate
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The sampled rows of each row group, given their row counts in read order
+pub(crate) fn sample_row_groups(
+    sample: Sample,
+    seed: Option<u64>,
+    row_counts: &[usize],
+) -> Result<Vec<RowSelection>> {
+    sample.validate()?;
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    let total_rows = row_counts.iter().sum::<usize>();
+
+    let mut picked = match sample {
+        Sample::Rows(n) => {
+            rand::seq::index::sample(&mut rng, total_rows, n.min(total_rows)).into_vec()
+        }
+        Sample::Rate(rate) => bernoulli(&mut rng, total_rows, rate),
+    };
+    picked.sort_unstable();
+
+    let mut picked = picked.into_iter().peekable();
+    let mut offset = 0;
+    Ok(row_counts
+        .iter()
+        .map(|&num_rows| {
+            let end = offset + num_rows;
+            let mut rows = Vec::new();
+            while let Some(row) = picked.next_if(|&row| row < end) {
+                rows.push(row - offset);
+            }
+            offset = end;
+            let ranges = consecutive_ranges(&rows);
+            RowSelection::from_consecutive_ranges(ranges.into_iter(), num_rows)
+        })
+        .collect())
+}
+
+/// Indices of the rows picked with probability `rate` each
+///
+/// Gaps between picked rows are drawn from the geometric distribution, so
+/// the work is proportional to the rows picked rather than the file.
+fn bernoulli(rng: &mut StdRng, total_rows: usize, rate: f64) -> Vec<usize> {
+    if rate <= 0.0 {
+        return Vec::new();
+    }
+    if rate >= 1.0 {
+        return (0..total_rows).collect();
+    }
+
+    let log_miss = (1.0 - rate).ln();
+    let mut picked = Vec::new();
+    let mut row = 0usize;
+    loop {
+        // In (0, 1], so the logarithm is finite
+        let uniform = 1.0 - rng.random::<f64>();
+        let gap = (uniform.ln() / log_miss).floor();
+        if gap >= (total_rows - row) as f64 {
+            return picked;
+        }
+        row += gap as usize;
+        picked.push(row);
+        row += 1;
+    }
+}
+
+/// Merge sorted row indices into ranges of consecutive rows
+fn consecutive_ranges(rows: &[usize]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for &row in rows {
+        match ranges.last_mut() {
+            Some(range) if range.end == row => range.end += 1,
+            _ => ranges.push(row..row + 1),
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selected(selections: &[RowSelection]) -> Vec<usize> {
+        selections.iter().map(RowSelection::row_count).collect()
+    }
+
+    #[test]
+    fn test_consecutive_ranges() {
+        assert_eq!(consecutive_ranges(&[]), Vec::<Range<usize>>::new());
+        assert_eq!(
+            consecutive_ranges(&[1, 2, 3, 7, 9, 10]),
+            vec![1..4, 7..8, 9..11]
+        );
+    }
+
+    #[test]
+    fn test_sample_rows_across_row_groups() {
+        let row_counts = [100, 300, 0, 600];
+        let selections = sample_row_groups(Sample::Rows(50), Some(7), &row_counts).unwrap();
+        assert_eq!(selections.len(), 4);
+        assert_eq!(selected(&selections).iter().sum::<usize>(), 50);
+        assert_eq!(selected(&selections)[2], 0);
+
+        // Seeded samples repeat
+        let again = sample_row_groups(Sample::Rows(50), Some(7), &row_counts).unwrap();
+        assert_eq!(selections, again);
+
+        let all = sample_row_groups(Sample::Rows(5000), None, &row_counts).unwrap();
+        assert_eq!(selected(&all), vec![100, 300, 0, 600]);
+    }
+
+    #[test]
+    fn test_sample_rate() {
+        let row_counts = [10_000, 10_000];
+        let picked = sample_row_groups(Sample::Rate(0.1), Some(1), &row_counts).unwrap();
+        let total = selected(&picked).iter().sum::<usize>();
+        assert!((1600..2400).contains(&total), "{}", total);
+
+        let none = sample_row_groups(Sample::Rate(0.0), None, &row_counts).unwrap();
+        assert_eq!(selected(&none), vec![0, 0]);
+        let all = sample_row_groups(Sample::Rate(1.0), None, &row_counts).unwrap();
+        assert_eq!(selected(&all), vec![10_000, 10_000]);
+
+        assert!(sample_row_groups(Sample::Rate(1.5), None, &row_counts).is_err());
+    }
+}
@@ -62,6 +62,8 @@ pub enum PrimitiveType {
     Boolean,
     String,
     Binary,
+    Json,                      // UTF8 annotated with the JSON logical type
+    Enum(Option<Vec<String>>), // Dictionary encoded UTF8 with optional allowed values
 
     // Date/Time types
     Date32,
@@ -74,6 +76,20 @@ pub enum PrimitiveType {
     TimeMicros,
     TimeNanos,
 
+    // Elapsed time types
+    DurationSecond,
+    DurationMillis,
+    DurationMicros,
+    DurationNanos,
+    Interval, // FLBA(12) of months, days and milliseconds
+
+    // Semi-structured types
+    Variant, // Group of metadata and value binaries
+
+    // Geospatial types, WKB binary with an optional PROJJSON CRS
+    Geometry(Option<Arc<str>>),
+    Geography(Option<Arc<str>>), // Geometry with spherical edges
+
     // Fixed-length byte array
     FixedLenByteArray(i32),
 }
@@ -139,6 +155,8 @@ impl PrimitiveType {
             PrimitiveType::Boolean => "Boolean",
             PrimitiveType::String => "String",
             PrimitiveType::Binary => "Binary",
+            PrimitiveType::Json => "Json",
+            PrimitiveType::Enum(_) => "Enum",
             PrimitiveType::Date32 => "Date32",
             PrimitiveType::Date64 => "Date64",
             PrimitiveType::TimestampSecond(_) => "TimestampSecond",
@@ -148,6 +166,14 @@ impl PrimitiveType {
             PrimitiveType::TimeMillis => "TimeMillis",
             PrimitiveType::TimeMicros => "TimeMicros",
             PrimitiveType::TimeNanos => "TimeNanos",
+            PrimitiveType::DurationSecond => "DurationSecond",
+            PrimitiveType::DurationMillis => "DurationMillis",
+            PrimitiveType::DurationMicros => "DurationMicros",
+            PrimitiveType::DurationNanos => "DurationNanos",
+            PrimitiveType::Interval => "Interval",
+            PrimitiveType::Variant => "Variant",
+            PrimitiveType::Geometry(_) => "Geometry",
+            PrimitiveType::Geography(_) => "Geography",
             PrimitiveType::FixedLenByteArray(_) => "FixedLenByteArray",
         }
     }
@@ -0,0 +1,646 @@
+//! Declared sort order of written files, and range reads that use it
+//!
+//! A writer given [`SortColumn`]s records them as the `sorting_columns` of
+//! every row group and can check that rows really arrive in that order. On
+//! read, a [`RangeFilter`] keeps only the row groups whose min/max
+//! statistics overlap the range, and only the rows within it. When every
+//! row group declares the filtered column as its leading sort column, the
+//! row groups are ordered by it as well, so the first and last matching row
+//! group are found by binary search instead of checking each one.
+
+use crate::{arrow_conversion::parquet_values_to_arrow_array, ParquetError, ParquetValue, Result};
+use arrow::array::{make_comparator, BooleanArray, Scalar};
+use arrow::compute::kernels::cmp;
+use arrow::compute::{and, cast, is_not_null, SortOptions};
+use arrow::record_batch::RecordBatch;
+use arrow::row::{OwnedRow, RowConverter, SortField};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float16Type, Float32Type, Float64Type};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, TimeUnit};
+use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+use parquet::arrow::arrow_reader::{ArrowPredicateFn, ArrowReaderMetadata, RowFilter};
+use parquet::arrow::ProjectionMask;
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::format::SortingColumn;
+use parquet::schema::types::SchemaDescriptor;
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+/// A column written rows are sorted by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortColumn {
+    /// Name of a top level primitive column
+    pub column: String,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+impl SortColumn {
+    /// Sort ascending by `column`, with nulls last
+    pub fn new(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            descending: false,
+            nulls_first: false,
+        }
+    }
+
+    pub fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
+    pub fn with_nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = nulls_first;
+        self
+    }
+
+    fn sort_options(&self) -> SortOptions {
+        SortOptions {
+            descending: self.descending,
+            nulls_first: self.nulls_first,
+        }
+    }
+}
+
+impl std::fmt::Display for SortColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.column,
+            if self.descending { "DESC" } else { "ASC" }
+        )?;
+        if self.nulls_first {
+            write!(f, " NULLS FIRST")?;
+        }
+        Ok(())
+    }
+}
+
+/// Index of a top level primitive column among the leaf columns
+pub(crate) fn leaf_index(schema_descr: &SchemaDescriptor, column: &str) -> Result<usize> {
+    let root_idx = schema_descr
+        .root_schema()
+        .get_fields()
+        .iter()
+        .position(|field| field.name() == column)
+        .ok_or_else(|| ParquetError::schema(format!("Unknown column {:?}", column)))?;
+
+    if !schema_descr.root_schema().get_fields()[root_idx].is_primitive() {
+        return Err(ParquetError::schema(format!(
+            "Column {:?} is not a primitive column; only top level primitive columns can be sorted by or filtered on",
+            column
+        )));
+    }
+
+    (0..schema_descr.num_columns())
+        .find(|&leaf_idx| schema_descr.get_column_root_idx(leaf_idx) == root_idx)
+        .ok_or_else(|| ParquetError::internal(format!("Column {:?} has no leaf", column)))
+}
+
+/// The `sorting_columns` row group metadata for `sort_columns`
+pub(crate) fn sorting_columns(
+    schema_descr: &SchemaDescriptor,
+    sort_columns: &[SortColumn],
+) -> Result<Vec<SortingColumn>> {
+    sort_columns
+        .iter()
+        .map(|sort_column| {
+            Ok(SortingColumn {
+                column_idx: leaf_index(schema_descr, &sort_column.column)? as i32,
+                descending: sort_column.descending,
+                nulls_first: sort_column.nulls_first,
+            })
+        })
+        .collect()
+}
+
+/// Checks that consecutive record batches keep the declared sort order
+pub(crate) struct SortCheck {
+    columns: Vec<usize>,
+    description: String,
+    converter: RowConverter,
+    last: Option<OwnedRow>,
+    rows_checked: usize,
+}
+
+impl SortCheck {
+    pub(crate) fn try_new(arrow_schema: &Schema, sort_columns: &[SortColumn]) -> Result<Self> {
+        let mut columns = Vec::with_capacity(sort_columns.len());
+        let mut fields = Vec::with_capacity(sort_columns.len());
+        for sort_column in sort_columns {
+            let (idx, field) = arrow_schema
+                .column_with_name(&sort_column.column)
+                .ok_or_else(|| {
+                    ParquetError::schema(format!("Unknown sort column {:?}", sort_column.column))
+                })?;
+            columns.push(idx);
+            fields.push(SortField::new_with_options(
+                field.data_type().clone(),
+                sort_column.sort_options(),
+            ));
+        }
+
+        Ok(Self {
+            columns,
+            description: sort_columns
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            converter: RowConverter::new(fields)?,
+            last: None,
+            rows_checked: 0,
+        })
+    }
+
+    /// Fail at the first row that sorts before the row written ahead of it
+    pub(crate) fn check(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let arrays = self
+            .columns
+            .iter()
+            .map(|&idx| batch.column(idx).clone())
+            .collect::<Vec<_>>();
+        let rows = self.converter.convert_columns(&arrays)?;
+
+        let out_of_order = match &self.last {
+            Some(last) if last.row() > rows.row(0) => Some(0),
+            _ => (1..rows.num_rows()).find(|&i| rows.row(i - 1) > rows.row(i)),
+        };
+        if let Some(i) = out_of_order {
+            return Err(ParquetError::data_validation(format!(
+                "Row {} is out of order for sorted_by {}",
+                self.rows_checked + i,
+                self.description
+            )));
+        }
+
+        self.last = Some(rows.row(rows.num_rows() - 1).owned());
+        self.rows_checked += batch.num_rows();
+        Ok(())
+    }
+}
+
+/// Rows whose value in a top level column lies within bounds
+///
+/// Rows where the column is null never match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeFilter {
+    pub column: String,
+    pub lower: Bound<ParquetValue>,
+    pub upper: Bound<ParquetValue>,
+}
+
+impl RangeFilter {
+    pub fn new(
+        column: impl Into<String>,
+        lower: Bound<ParquetValue>,
+        upper: Bound<ParquetValue>,
+    ) -> Self {
+        Self {
+            column: column.into(),
+            lower,
+            upper,
+        }
+    }
+
+    /// Rows whose value in `column` equals `value`
+    pub fn eq(column: impl Into<String>, value: ParquetValue) -> Self {
+        Self::new(
+            column,
+            Bound::Included(value.clone()),
+            Bound::Included(value),
+        )
+    }
+}
+
+/// A range filter with its bounds as single value arrays of the type the
+/// column is compared as
+#[derive(Clone)]
+pub(crate) struct ResolvedRange {
+    field: FieldRef,
+    leaf_idx: usize,
+    lower: Bound<ArrayRef>,
+    upper: Bound<ArrayRef>,
+}
+
+impl ResolvedRange {
+    pub(crate) fn leaf_idx(&self) -> usize {
+        self.leaf_idx
+    }
+}
+
+/// Look up the filtered columns and convert the bounds to their types
+pub(crate) fn resolve_ranges(
+    filters: &[RangeFilter],
+    metadata: &ArrowReaderMetadata,
+) -> Result<Vec<ResolvedRange>> {
+    let schema_descr = metadata.metadata().file_metadata().schema_descr();
+    filters
+        .iter()
+        .map(|filter| {
+            let leaf_idx = leaf_index(schema_descr, &filter.column)?;
+            let field = metadata
+                .schema()
+                .field_with_name(&filter.column)
+                .map_err(|_| {
+                    ParquetError::schema(format!("Unknown filter column {:?}", filter.column))
+                })?
+                .clone();
+            let data_type = comparison_type(field.data_type());
+            let bound = |bound: &Bound<ParquetValue>| -> Result<Bound<ArrayRef>> {
+                Ok(match bound {
+                    Bound::Included(value) => Bound::Included(literal(&field, value, data_type)?),
+                    Bound::Excluded(value) => Bound::Excluded(literal(&field, value, data_type)?),
+                    Bound::Unbounded => Bound::Unbounded,
+                })
+            };
+            Ok(ResolvedRange {
+                leaf_idx,
+                lower: bound(&filter.lower)?,
+                upper: bound(&filter.upper)?,
+                field: field.into(),
+            })
+        })
+        .collect()
+}
+
+/// Row groups that may hold rows within every range, in file order
+pub(crate) fn select_row_groups(
+    ranges: &[ResolvedRange],
+    metadata: &ArrowReaderMetadata,
+) -> Result<Vec<usize>> {
+    let parquet_metadata = metadata.metadata();
+    let row_groups = parquet_metadata.row_groups();
+    let mut selected = (0..row_groups.len()).collect::<Vec<_>>();
+
+    for range in ranges {
+        let converter = StatisticsConverter::try_new(
+            range.field.name(),
+            metadata.schema(),
+            parquet_metadata.file_metadata().schema_descr(),
+        )?;
+        let data_type = comparison_type(range.field.data_type());
+        let mins = comparable(converter.row_group_mins(row_groups.iter())?, data_type)?;
+        let maxes = comparable(converter.row_group_maxes(row_groups.iter())?, data_type)?;
+
+        // Row groups entirely below or above the range
+        let below: Box<dyn Fn(usize) -> bool> = match &range.lower {
+            Bound::Included(lower) => ordering_test(&maxes, lower, Ordering::is_lt)?,
+            Bound::Excluded(lower) => ordering_test(&maxes, lower, Ordering::is_le)?,
+            Bound::Unbounded => Box::new(|_| false),
+        };
+        let above: Box<dyn Fn(usize) -> bool> = match &range.upper {
+            Bound::Included(upper) => ordering_test(&mins, upper, Ordering::is_gt)?,
+            Bound::Excluded(upper) => ordering_test(&mins, upper, Ordering::is_ge)?,
+            Bound::Unbounded => Box::new(|_| false),
+        };
+
+        let has_statistics = mins.null_count() == 0 && maxes.null_count() == 0;
+        let sort_order = match leading_sort_order(parquet_metadata.row_groups(), range.leaf_idx) {
+            Some(descending) if has_statistics && bounds_in_order(&mins, &maxes, descending)? => {
+                Some(descending)
+            }
+            _ => None,
+        };
+        let candidates = match sort_order {
+            Some(descending) => {
+                let (before, after) = if descending {
+                    (&above, &below)
+                } else {
+                    (&below, &above)
+                };
+                let start = partition_point(row_groups.len(), |i| before(i));
+                let end = partition_point(row_groups.len(), |i| !after(i)).max(start);
+                (start..end).collect::<Vec<_>>()
+            }
+            _ => (0..row_groups.len())
+                .filter(|&i| mins.is_null(i) || maxes.is_null(i) || !(below(i) || above(i)))
+                .collect(),
+        };
+
+        selected.retain(|i| candidates.binary_search(i).is_ok());
+    }
+
+    Ok(selected)
+}
+
+/// Row groups whose statistics show every row lies within every range
+///
+/// Min and max statistics are only ever widened, e.g. by truncation, so an
+/// inexact pair still proves a row group is covered. Rows with a null in a
+/// filtered column do not match, so those need a null count of zero.
+pub(crate) fn covered_row_groups(
+    ranges: &[ResolvedRange],
+    metadata: &ArrowReaderMetadata,
+) -> Result<Vec<bool>> {
+    let parquet_metadata = metadata.metadata();
+    let row_groups = parquet_metadata.row_groups();
+    let mut covered = vec![true; row_groups.len()];
+
+    for range in ranges {
+        if is_float(range.field.data_type()) {
+            return Ok(vec![false; row_groups.len()]);
+        }
+        let converter = StatisticsConverter::try_new(
+            range.field.name(),
+            metadata.schema(),
+            parquet_metadata.file_metadata().schema_descr(),
+        )?;
+        let data_type = comparison_type(range.field.data_type());
+        let mins = comparable(converter.row_group_mins(row_groups.iter())?, data_type)?;
+        let maxes = comparable(converter.row_group_maxes(row_groups.iter())?, data_type)?;
+        let null_counts = converter.row_group_null_counts(row_groups.iter())?;
+
+        let above_lower: Box<dyn Fn(usize) -> bool> = match &range.lower {
+            Bound::Included(lower) => ordering_test(&mins, lower, Ordering::is_ge)?,
+            Bound::Excluded(lower) => ordering_test(&mins, lower, Ordering::is_gt)?,
+            Bound::Unbounded => Box::new(|_| true),
+        };
+        let below_upper: Box<dyn Fn(usize) -> bool> = match &range.upper {
+            Bound::Included(upper) => ordering_test(&maxes, upper, Ordering::is_le)?,
+            Bound::Excluded(upper) => ordering_test(&maxes, upper, Ordering::is_lt)?,
+            Bound::Unbounded => Box::new(|_| true),
+        };
+
+        for (i, covered) in covered.iter_mut().enumerate() {
+            *covered = *covered
+                && null_counts.is_valid(i)
+                && null_counts.value(i) == 0
+                && !mins.is_null(i)
+                && !maxes.is_null(i)
+                && above_lower(i)
+                && below_upper(i);
+        }
+    }
+
+    Ok(covered)
+}
+
+/// Whether every row group is sorted by the leaf column first, and which way
+fn leading_sort_order(row_groups: &[RowGroupMetaData], leaf_idx: usize) -> Option<bool> {
+    let mut order = None;
+    for row_group in row_groups {
+        let leading = row_group.sorting_columns()?.first()?;
+        if leading.column_idx as usize != leaf_idx
+            || order.is_some_and(|descending| descending != leading.descending)
+        {
+            return None;
+        }
+        order = Some(leading.descending);
+    }
+    order
+}
+
+/// Whether row group bounds only move in the sort direction through the file
+///
+/// `sorting_columns` only describes the rows within each row group, so a
+/// file appended to in several sorted runs can't be bisected.
+fn bounds_in_order(mins: &ArrayRef, maxes: &ArrayRef, descending: bool) -> Result<bool> {
+    for values in [mins, maxes] {
+        let compare = make_comparator(values.as_ref(), values.as_ref(), SortOptions::default())?;
+        let in_order = (1..values.len()).all(|i| {
+            let ordering = compare(i - 1, i);
+            if descending {
+                ordering.is_ge()
+            } else {
+                ordering.is_le()
+            }
+        });
+        if !in_order {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// First index in `0..len` for which `predicate` no longer holds
+fn partition_point(len: usize, predicate: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if predicate(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// A test of how each value of `values` compares with the single `bound`
+///
+/// Null values never pass.
+fn ordering_test(
+    values: &ArrayRef,
+    bound: &ArrayRef,
+    test: fn(Ordering) -> bool,
+) -> Result<Box<dyn Fn(usize) -> bool>> {
+    let values = values.clone();
+    let compare = make_comparator(values.as_ref(), bound.as_ref(), SortOptions::default())?;
+    Ok(Box::new(move |i| !values.is_null(i) && test(compare(i, 0))))
+}
+
+/// Keep only the rows within every range
+pub(crate) fn row_filter(ranges: &[ResolvedRange], schema_descr: &SchemaDescriptor) -> RowFilter {
+    let predicates = ranges
+        .iter()
+        .map(|range| {
+            let mask = ProjectionMask::leaves(schema_descr, [range.leaf_idx]);
+            let range = range.clone();
+            Box::new(ArrowPredicateFn::new(mask, move |batch: RecordBatch| {
+                range_mask(&range, batch.column(0))
+            })) as _
+        })
+        .collect();
+    RowFilter::new(predicates)
+}
+
+fn range_mask(
+    range: &ResolvedRange,
+    column: &ArrayRef,
+) -> std::result::Result<BooleanArray, ArrowError> {
+    let column = comparable(column.clone(), comparison_type(range.field.data_type()))
+        .map_err(|e| ArrowError::ComputeError(e.to_string()))?;
+
+    let lower = match &range.lower {
+        Bound::Included(lower) => Some(cmp::gt_eq(&column, &Scalar::new(lower.clone()))?),
+        Bound::Excluded(lower) => Some(cmp::gt(&column, &Scalar::new(lower.clone()))?),
+        Bound::Unbounded => None,
+    };
+    let upper = match &range.upper {
+        Bound::Included(upper) => Some(cmp::lt_eq(&column, &Scalar::new(upper.clone()))?),
+        Bound::Excluded(upper) => Some(cmp::lt(&column, &Scalar::new(upper.clone()))?),
+        Bound::Unbounded => None,
+    };
+
+    let mask = match (lower, upper) {
+        (Some(lower), Some(upper)) => and(&lower, &upper)?,
+        (Some(mask), None) | (None, Some(mask)) => mask,
+        // Unbounded on both ends still leaves out nulls
+        (None, None) => is_not_null(&column)?,
+    };
+    match not_nan(&column) {
+        Some(not_nan) => and(&mask, &not_nan),
+        None => Ok(mask),
+    }
+}
+
+/// Which values of a float column aren't NaN
+///
+/// NaN matches no range, like null. Float statistics leave it out, so a row
+/// group pruned by its max may still hold NaN, which the comparison kernels
+/// would otherwise order above every bound.
+fn not_nan(column: &ArrayRef) -> Option<BooleanArray> {
+    let mask = match column.data_type() {
+        DataType::Float16 => column
+            .as_primitive::<Float16Type>()
+            .iter()
+            .map(|v| v.map(|v| !v.is_nan()))
+            .collect(),
+        DataType::Float32 => column
+            .as_primitive::<Float32Type>()
+            .iter()
+            .map(|v| v.map(|v| !v.is_nan()))
+            .collect(),
+        DataType::Float64 => column
+            .as_primitive::<Float64Type>()
+            .iter()
+            .map(|v| v.map(|v| !v.is_nan()))
+            .collect(),
+        _ => return None,
+    };
+    Some(mask)
+}
+
+/// Whether values are floats, whose min and max statistics leave out NaN
+///
+/// Such statistics can't show that every row of a row group lies within a
+/// range, or which row holds its largest value.
+pub(crate) fn is_float(data_type: &DataType) -> bool {
+    matches!(
+        comparison_type(data_type),
+        DataType::Float16 | DataType::Float32 | DataType::Float64
+    )
+}
+
+/// Dictionary columns are compared by their values
+fn comparison_type(data_type: &DataType) -> &DataType {
+    match data_type {
+        DataType::Dictionary(_, value_type) => value_type,
+        data_type => data_type,
+    }
+}
+
+fn comparable(array: ArrayRef, data_type: &DataType) -> Result<ArrayRef> {
+    if array.data_type() == data_type {
+        Ok(array)
+    } else {
+        Ok(cast(&array, data_type)?)
+    }
+}
+
+/// A range bound as a single value array of `data_type`
+///
+/// Values of another type, e.g. an `Int64` for an `Int32` column or a
+/// millisecond timestamp for a microsecond one, are cast.
+fn literal(field: &Field, value: &ParquetValue, data_type: &DataType) -> Result<ArrayRef> {
+    if matches!(value, ParquetValue::Null) {
+        return Err(ParquetError::invalid_argument(format!(
+            "Range bound for {} is null; leave the end unbounded instead",
+            field.name()
+        )));
+    }
+
+    let target = Field::new(field.name(), data_type.clone(), true);
+    if let Ok(array) = parquet_values_to_arrow_array(vec![value.clone()], &target) {
+        return Ok(array);
+    }
+
+    let natural = natural_type(value).ok_or_else(|| {
+        ParquetError::unsupported(format!(
+            "Cannot filter {} by a {} value",
+            field.name(),
+            value.type_name()
+        ))
+    })?;
+    let array = parquet_values_to_arrow_array(
+        vec![value.clone()],
+        &Field::new(field.name(), natural, true),
+    )?;
+    let array = cast(&array, data_type)?;
+    if array.is_null(0) {
+        return Err(ParquetError::invalid_argument(format!(
+            "{:?} cannot be compared with column {} of type {}",
+            value,
+            field.name(),
+            data_type
+        )));
+    }
+    Ok(array)
+}
+
+/// The Arrow type a scalar value is built as before casting
+fn natural_type(value: &ParquetValue) -> Option<DataType> {
+    Some(match value {
+        ParquetValue::Int8(_) => DataType::Int8,
+        ParquetValue::Int16(_) => DataType::Int16,
+        ParquetValue::Int32(_) => DataType::Int32,
+        ParquetValue::Int64(_) => DataType::Int64,
+        ParquetValue::UInt8(_) => DataType::UInt8,
+        ParquetValue::UInt16(_) => DataType::UInt16,
+        ParquetValue::UInt32(_) => DataType::UInt32,
+        ParquetValue::UInt64(_) => DataType::UInt64,
+        ParquetValue::Float32(_) => DataType::Float32,
+        ParquetValue::Float64(_) => DataType::Float64,
+        ParquetValue::Boolean(_) => DataType::Boolean,
+        ParquetValue::String(_) | ParquetValue::Enum(_) => DataType::Utf8,
+        ParquetValue::Bytes(_) => DataType::Binary,
+        ParquetValue::Date32(_) => DataType::Date32,
+        ParquetValue::Date64(_) => DataType::Date64,
+        ParquetValue::Decimal128(_, scale) => DataType::Decimal128(38, *scale),
+        ParquetValue::TimestampSecond(_, tz) => DataType::Timestamp(TimeUnit::Second, tz.clone()),
+        ParquetValue::TimestampMillis(_, tz) => {
+            DataType::Timestamp(TimeUnit::Millisecond, tz.clone())
+        }
+        ParquetValue::TimestampMicros(_, tz) => {
+            DataType::Timestamp(TimeUnit::Microsecond, tz.clone())
+        }
+        ParquetValue::TimestampNanos(_, tz) => {
+            DataType::Timestamp(TimeUnit::Nanosecond, tz.clone())
+        }
+        ParquetValue::TimeMillis(_) => DataType::Time32(TimeUnit::Millisecond),
+        ParquetValue::TimeMicros(_) => DataType::Time64(TimeUnit::Microsecond),
+        ParquetValue::TimeNanos(_) => DataType::Time64(TimeUnit::Nanosecond),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_point() {
+        let values = [1, 3, 3, 5, 8];
+        assert_eq!(partition_point(values.len(), |i| values[i] < 3), 1);
+        assert_eq!(partition_point(values.len(), |i| values[i] <= 3), 3);
+        assert_eq!(partition_point(values.len(), |i| values[i] < 10), 5);
+        assert_eq!(partition_point(0, |_| true), 0);
+    }
+
+    #[test]
+    fn test_sort_column_display() {
+        let column = SortColumn::new("ts")
+            .with_descending(true)
+            .with_nulls_first(true);
+        assert_eq!(column.to_string(), "ts DESC NULLS FIRST");
+        assert_eq!(SortColumn::new("id").to_string(), "id ASC");
+    }
+}
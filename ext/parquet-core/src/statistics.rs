@@ -0,0 +1,318 @@
+//! Decoded column statistics from the Parquet footer
+//!
+//! Column chunk statistics are stored with physical types: a decimal is an
+//! integer or byte array, a timestamp a bare `i64`. Top level columns are
+//! converted to their Arrow types with [`StatisticsConverter`] and from there
+//! to [`ParquetValue`] with [`arrow_to_parquet_value`], so min and max come
+//! out exactly as the values would when reading rows. The same applies to
+//! the per page values of the page index, see [`page_statistics`].
+
+use crate::{
+    arrow_conversion::arrow_to_parquet_value, geometry::GEOMETRY_EXTENSION_NAME,
+    variant::VARIANT_EXTENSION_NAME, ParquetValue, Result,
+};
+use arrow::compute::{sort_to_indices, SortOptions};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{Field, Schema};
+use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+use parquet::file::metadata::{ColumnChunkMetaData, ParquetMetaData};
+use parquet::schema::types::{SchemaDescriptor, Type};
+
+/// Statistics for one leaf column, in a single row group or the whole file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStatistics {
+    /// Dotted path of the leaf column, e.g. `address.city`
+    pub path: String,
+    /// Smallest value, only decoded for top level primitive columns
+    pub min: Option<ParquetValue>,
+    /// Largest value, only decoded for top level primitive columns
+    pub max: Option<ParquetValue>,
+    pub null_count: Option<u64>,
+    /// Only reported for the file when it has a single row group, since
+    /// per row group counts cannot be combined
+    pub distinct_count: Option<u64>,
+    pub num_values: i64,
+    pub compressed_size: i64,
+    pub uncompressed_size: i64,
+}
+
+/// Statistics for the columns of one row group
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowGroupStatistics {
+    pub num_rows: i64,
+    pub columns: Vec<ColumnStatistics>,
+}
+
+/// Location and statistics of one data page, from the page index
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageStatistics {
+    pub offset: i64,
+    pub compressed_size: i32,
+    /// Index of the page's first row within its row group
+    pub first_row_index: i64,
+    /// Smallest value, only decoded for top level primitive columns
+    pub min: Option<ParquetValue>,
+    /// Largest value, only decoded for top level primitive columns
+    pub max: Option<ParquetValue>,
+    pub null_count: Option<u64>,
+}
+
+/// Column statistics aggregated over the file, plus the row group breakdown
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileStatistics {
+    pub num_rows: i64,
+    pub columns: Vec<ColumnStatistics>,
+    pub row_groups: Vec<RowGroupStatistics>,
+}
+
+/// Collect statistics for the leaf columns under the given top level columns
+///
+/// With `columns` of `None` every column is included.
+pub fn read_statistics(
+    metadata: &ParquetMetaData,
+    arrow_schema: &Schema,
+    columns: Option<&[String]>,
+) -> Result<FileStatistics> {
+    let schema_descr = metadata.file_metadata().schema_descr();
+    let root_fields = schema_descr.root_schema().get_fields();
+    let row_groups = metadata.row_groups();
+
+    let mut leaves = Vec::new();
+    for (leaf_idx, column) in schema_descr.columns().iter().enumerate() {
+        let root = &root_fields[schema_descr.get_column_root_idx(leaf_idx)];
+        if columns.is_some_and(|columns| !columns.iter().any(|c| c == root.name())) {
+            continue;
+        }
+
+        let decoded = match LeafDecoder::try_new(schema_descr, arrow_schema, leaf_idx)? {
+            Some(decoder) => {
+                let mins = decoder.converter.row_group_mins(row_groups.iter())?;
+                let maxes = decoder.converter.row_group_maxes(row_groups.iter())?;
+                Some((decoder, mins, maxes))
+            }
+            None => None,
+        };
+        leaves.push((leaf_idx, column.path().string(), decoded));
+    }
+
+    let mut file_columns = Vec::with_capacity(leaves.len());
+    let mut row_group_columns = vec![Vec::with_capacity(leaves.len()); row_groups.len()];
+    for (leaf_idx, path, decoded) in &leaves {
+        let mut per_row_group = Vec::with_capacity(row_groups.len());
+        for (rg_idx, row_group) in row_groups.iter().enumerate() {
+            let chunk = row_group.column(*leaf_idx);
+            let (min, max) = match decoded {
+                Some((decoder, mins, maxes)) => {
+                    (decoder.value(mins, rg_idx)?, decoder.value(maxes, rg_idx)?)
+                }
+                None => (None, None),
+            };
+            per_row_group.push(chunk_statistics(path, chunk, min, max));
+        }
+
+        let (min, max) = match decoded {
+            Some((decoder, mins, maxes)) => (
+                decoder.extreme(mins, &per_row_group, false)?,
+                decoder.extreme(maxes, &per_row_group, true)?,
+            ),
+            None => (None, None),
+        };
+        file_columns.push(aggregate(path, &per_row_group, min, max));
+
+        for (rg_idx, stats) in per_row_group.into_iter().enumerate() {
+            row_group_columns[rg_idx].push(stats);
+        }
+    }
+
+    Ok(FileStatistics {
+        num_rows: metadata.file_metadata().num_rows(),
+        columns: file_columns,
+        row_groups: row_groups
+            .iter()
+            .zip(row_group_columns)
+            .map(|(row_group, columns)| RowGroupStatistics {
+                num_rows: row_group.num_rows(),
+                columns,
+            })
+            .collect(),
+    })
+}
+
+/// Pages of one column chunk, or `None` when the file has no page index for it
+///
+/// The page index is only present when the metadata was loaded with
+/// `ArrowReaderOptions::with_page_index`.
+pub fn page_statistics(
+    metadata: &ParquetMetaData,
+    arrow_schema: &Schema,
+    row_group: usize,
+    column: usize,
+) -> Result<Option<Vec<PageStatistics>>> {
+    let row_groups = [row_group];
+    let Some(offset_index) = metadata.offset_index() else {
+        return Ok(None);
+    };
+    let Some(chunk_offsets) = offset_index.get(row_group).and_then(|rg| rg.get(column)) else {
+        return Ok(None);
+    };
+
+    let schema_descr = metadata.file_metadata().schema_descr();
+    let decoded = match (
+        metadata.column_index(),
+        LeafDecoder::try_new(schema_descr, arrow_schema, column)?,
+    ) {
+        (Some(column_index), Some(decoder)) => {
+            let converter = &decoder.converter;
+            let mins = converter.data_page_mins(column_index, offset_index, &row_groups)?;
+            let maxes = converter.data_page_maxes(column_index, offset_index, &row_groups)?;
+            let null_counts =
+                converter.data_page_null_counts(column_index, offset_index, &row_groups)?;
+            Some((decoder, mins, maxes, null_counts))
+        }
+        _ => None,
+    };
+
+    chunk_offsets
+        .page_locations()
+        .iter()
+        .enumerate()
+        .map(|(idx, location)| {
+            let (min, max, null_count) = match &decoded {
+                Some((decoder, mins, maxes, null_counts)) => (
+                    decoder.value(mins, idx)?,
+                    decoder.value(maxes, idx)?,
+                    (idx < null_counts.len() && null_counts.is_valid(idx))
+                        .then(|| null_counts.value(idx)),
+                ),
+                None => (None, None, None),
+            };
+            Ok(PageStatistics {
+                offset: location.offset,
+                compressed_size: location.compressed_page_size,
+                first_row_index: location.first_row_index,
+                min,
+                max,
+                null_count,
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Converts the statistics of a top level primitive column to values
+///
+/// Nested leaves have no Arrow field of their own, so only their counts and
+/// sizes are reported.
+struct LeafDecoder<'a> {
+    converter: StatisticsConverter<'a>,
+    field: &'a Field,
+    parquet_field: &'a Type,
+}
+
+impl<'a> LeafDecoder<'a> {
+    fn try_new(
+        schema_descr: &'a SchemaDescriptor,
+        arrow_schema: &'a Schema,
+        leaf_idx: usize,
+    ) -> Result<Option<Self>> {
+        let root_idx = schema_descr.get_column_root_idx(leaf_idx);
+        let parquet_field = &schema_descr.root_schema().get_fields()[root_idx];
+        match arrow_schema.fields().get(root_idx) {
+            Some(field) if parquet_field.is_primitive() && has_ordered_values(field) => {
+                Ok(Some(Self {
+                    converter: StatisticsConverter::try_new(
+                        field.name(),
+                        arrow_schema,
+                        schema_descr,
+                    )?,
+                    field,
+                    parquet_field,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn value(&self, array: &ArrayRef, index: usize) -> Result<Option<ParquetValue>> {
+        if index >= array.len() || array.is_null(index) {
+            return Ok(None);
+        }
+        arrow_to_parquet_value(self.field, self.parquet_field, array.as_ref(), index).map(Some)
+    }
+
+    /// Smallest (or largest) value across row groups, if the type is sortable
+    ///
+    /// Unknown when a row group with values has no bound, the way missing
+    /// statistics leave aggregates to scanning.
+    fn extreme(
+        &self,
+        array: &ArrayRef,
+        row_groups: &[ColumnStatistics],
+        descending: bool,
+    ) -> Result<Option<ParquetValue>> {
+        let unbounded = row_groups.iter().enumerate().any(|(idx, stats)| {
+            let all_null = stats.null_count == Some(stats.num_values as u64);
+            !all_null && (idx >= array.len() || array.is_null(idx))
+        });
+        if unbounded {
+            return Ok(None);
+        }
+
+        let options = SortOptions {
+            descending,
+            nulls_first: false,
+        };
+        match sort_to_indices(array.as_ref(), Some(options), Some(1)) {
+            Ok(indices) if !indices.is_empty() => self.value(array, indices.value(0) as usize),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Geometries and variants are stored as bytes whose order means nothing
+pub(crate) fn has_ordered_values(field: &Field) -> bool {
+    !matches!(
+        field.extension_type_name(),
+        Some(GEOMETRY_EXTENSION_NAME | VARIANT_EXTENSION_NAME)
+    )
+}
+
+fn chunk_statistics(
+    path: &str,
+    chunk: &ColumnChunkMetaData,
+    min: Option<ParquetValue>,
+    max: Option<ParquetValue>,
+) -> ColumnStatistics {
+    let stats = chunk.statistics();
+    ColumnStatistics {
+        path: path.to_string(),
+        min,
+        max,
+        null_count: stats.and_then(|s| s.null_count_opt()),
+        distinct_count: stats.and_then(|s| s.distinct_count_opt()),
+        num_values: chunk.num_values(),
+        compressed_size: chunk.compressed_size(),
+        uncompressed_size: chunk.uncompressed_size(),
+    }
+}
+
+fn aggregate(
+    path: &str,
+    row_groups: &[ColumnStatistics],
+    min: Option<ParquetValue>,
+    max: Option<ParquetValue>,
+) -> ColumnStatistics {
+    ColumnStatistics {
+        path: path.to_string(),
+        min,
+        max,
+        null_count: row_groups.iter().map(|rg| rg.null_count).sum(),
+        distinct_count: match row_groups {
+            [only] => only.distinct_count,
+            _ => None,
+        },
+        num_values: row_groups.iter().map(|rg| rg.num_values).sum(),
+        compressed_size: row_groups.iter().map(|rg| rg.compressed_size).sum(),
+        uncompressed_size: row_groups.iter().map(|rg| rg.uncompressed_size).sum(),
+    }
+}
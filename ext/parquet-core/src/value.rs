@@ -4,6 +4,8 @@ use num::BigInt;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::variant::Variant;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParquetValue {
     // Numeric types
@@ -22,7 +24,10 @@ pub enum ParquetValue {
     // Basic types
     Boolean(bool),
     String(Arc<str>),
+    Json(Arc<str>), // JSON encoded text
+    Enum(Arc<str>), // Member of a categorical (ENUM) column
     Bytes(Bytes),
+    Geometry(Bytes), // WKB encoded geometry
     Uuid(Uuid),
 
     // Date/Time types
@@ -44,10 +49,20 @@ pub enum ParquetValue {
     TimeMicros(i64), // Time of day in microseconds since midnight
     TimeNanos(i64),  // Time of day in nanoseconds since midnight
 
+    // Duration types - elapsed time in the given unit
+    DurationSecond(i64),
+    DurationMillis(i64),
+    DurationMicros(i64),
+    DurationNanos(i64),
+
+    // Interval type
+    Interval(i32, i32, i32), // months, days, milliseconds (Parquet INTERVAL layout)
+
     // Complex types
     List(Vec<ParquetValue>),
     Map(Vec<(ParquetValue, ParquetValue)>), // Using Vec of tuples for deterministic ordering
     Record(IndexMap<Arc<str>, ParquetValue>), // For struct/record types, preserves field order
+    Variant(Box<Variant>),                  // Semi-structured VARIANT value
 
     // Null value
     Null,
@@ -70,7 +85,10 @@ impl std::hash::Hash for ParquetValue {
             ParquetValue::Float64(f) => f.hash(state),
             ParquetValue::Boolean(b) => b.hash(state),
             ParquetValue::String(s) => s.hash(state),
+            ParquetValue::Json(s) => s.hash(state),
+            ParquetValue::Enum(s) => s.hash(state),
             ParquetValue::Bytes(b) => b.hash(state),
+            ParquetValue::Geometry(b) => b.hash(state),
             ParquetValue::Uuid(u) => u.hash(state),
             ParquetValue::Date32(d) => d.hash(state),
             ParquetValue::Date64(d) => d.hash(state),
@@ -101,6 +119,15 @@ impl std::hash::Hash for ParquetValue {
             ParquetValue::TimeMillis(t) => t.hash(state),
             ParquetValue::TimeMicros(t) => t.hash(state),
             ParquetValue::TimeNanos(t) => t.hash(state),
+            ParquetValue::DurationSecond(d) => d.hash(state),
+            ParquetValue::DurationMillis(d) => d.hash(state),
+            ParquetValue::DurationMicros(d) => d.hash(state),
+            ParquetValue::DurationNanos(d) => d.hash(state),
+            ParquetValue::Interval(months, days, millis) => {
+                months.hash(state);
+                days.hash(state);
+                millis.hash(state);
+            }
             ParquetValue::List(l) => l.hash(state),
             ParquetValue::Map(m) => m.hash(state),
             ParquetValue::Record(r) => {
@@ -110,6 +137,7 @@ impl std::hash::Hash for ParquetValue {
                     v.hash(state);
                 }
             }
+            ParquetValue::Variant(v) => v.hash(state),
             ParquetValue::Null => 0_i32.hash(state),
         }
     }
@@ -137,7 +165,10 @@ impl ParquetValue {
             ParquetValue::Float64(_) => "Float64",
             ParquetValue::Boolean(_) => "Boolean",
             ParquetValue::String(_) => "String",
+            ParquetValue::Json(_) => "Json",
+            ParquetValue::Enum(_) => "Enum",
             ParquetValue::Bytes(_) => "Bytes",
+            ParquetValue::Geometry(_) => "Geometry",
             ParquetValue::Uuid(_) => "Uuid",
             ParquetValue::Date32(_) => "Date32",
             ParquetValue::Date64(_) => "Date64",
@@ -150,9 +181,15 @@ impl ParquetValue {
             ParquetValue::TimeMillis(_) => "TimeMillis",
             ParquetValue::TimeMicros(_) => "TimeMicros",
             ParquetValue::TimeNanos(_) => "TimeNanos",
+            ParquetValue::DurationSecond(_) => "DurationSecond",
+            ParquetValue::DurationMillis(_) => "DurationMillis",
+            ParquetValue::DurationMicros(_) => "DurationMicros",
+            ParquetValue::DurationNanos(_) => "DurationNanos",
+            ParquetValue::Interval(_, _, _) => "Interval",
             ParquetValue::List(_) => "List",
             ParquetValue::Map(_) => "Map",
             ParquetValue::Record(_) => "Record",
+            ParquetValue::Variant(_) => "Variant",
             ParquetValue::Null => "Null",
         }
     }
@@ -0,0 +1,801 @@
+//! Encoding and decoding of the Parquet VARIANT binary format
+//!
+//! A variant is stored as a group of two binaries: `metadata`, a dictionary of
+//! the object keys used by the value, and `value`, a self-describing encoding
+//! of the data itself. Shredded variants also carry a `typed_value` column
+//! holding parts of the value as regular Parquet columns, in which case
+//! `value` only contains whatever didn't fit the shredded schema.
+
+use crate::{ParquetError, ParquetValue, Result};
+use bytes::Bytes;
+use indexmap::IndexMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// Arrow extension name marking a struct column as a VARIANT group
+pub const VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
+
+const METADATA_VERSION: u8 = 1;
+
+// Basic types, stored in the lowest two bits of a value header
+const BASIC_PRIMITIVE: u8 = 0;
+const BASIC_SHORT_STRING: u8 = 1;
+const BASIC_OBJECT: u8 = 2;
+const BASIC_ARRAY: u8 = 3;
+
+// Primitive type ids
+const PRIMITIVE_NULL: u8 = 0;
+const PRIMITIVE_TRUE: u8 = 1;
+const PRIMITIVE_FALSE: u8 = 2;
+const PRIMITIVE_INT8: u8 = 3;
+const PRIMITIVE_INT16: u8 = 4;
+const PRIMITIVE_INT32: u8 = 5;
+const PRIMITIVE_INT64: u8 = 6;
+const PRIMITIVE_DOUBLE: u8 = 7;
+const PRIMITIVE_DECIMAL4: u8 = 8;
+const PRIMITIVE_DECIMAL8: u8 = 9;
+const PRIMITIVE_DECIMAL16: u8 = 10;
+const PRIMITIVE_DATE: u8 = 11;
+const PRIMITIVE_TIMESTAMP_MICROS: u8 = 12;
+const PRIMITIVE_TIMESTAMP_NTZ_MICROS: u8 = 13;
+const PRIMITIVE_FLOAT: u8 = 14;
+const PRIMITIVE_BINARY: u8 = 15;
+const PRIMITIVE_STRING: u8 = 16;
+const PRIMITIVE_TIME_NTZ_MICROS: u8 = 17;
+const PRIMITIVE_TIMESTAMP_NANOS: u8 = 18;
+const PRIMITIVE_TIMESTAMP_NTZ_NANOS: u8 = 19;
+const PRIMITIVE_UUID: u8 = 20;
+
+const MAX_SHORT_STRING_LEN: usize = 63;
+
+/// A VARIANT value as stored in Parquet
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Variant {
+    /// Key dictionary shared by all objects in the value
+    pub metadata: Bytes,
+    /// Encoded value, absent when the value is fully shredded
+    pub value: Option<Bytes>,
+    /// Shredded part of the value, read from the `typed_value` column
+    pub typed_value: Option<Box<ParquetValue>>,
+}
+
+impl Variant {
+    /// Encode a value as an unshredded variant
+    ///
+    /// Records and maps with string keys become objects and lists become
+    /// arrays; scalars use the closest variant primitive type.
+    pub fn from_value(value: &ParquetValue) -> Result<Self> {
+        let mut keys = BTreeSet::new();
+        collect_keys(value, &mut keys)?;
+        let keys: Vec<Arc<str>> = keys.into_iter().collect();
+
+        let mut encoded = Vec::new();
+        encode_value(value, &keys, &mut encoded)?;
+
+        Ok(Self {
+            metadata: Bytes::from(encode_metadata(&keys)),
+            value: Some(Bytes::from(encoded)),
+            typed_value: None,
+        })
+    }
+
+    /// Decode the variant into plain values, reassembling any shredded parts
+    pub fn decode(&self) -> Result<ParquetValue> {
+        let metadata = Metadata::parse(&self.metadata)?;
+        Ok(unshred(
+            &metadata,
+            self.value.as_deref(),
+            self.typed_value.as_deref(),
+        )?
+        .unwrap_or(ParquetValue::Null))
+    }
+}
+
+/// Parsed view over a variant metadata dictionary
+struct Metadata<'a> {
+    bytes: &'a [u8],
+    offset_size: usize,
+    dictionary_size: usize,
+}
+
+impl<'a> Metadata<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self> {
+        let header = *bytes
+            .first()
+            .ok_or_else(|| variant_error("Variant metadata is empty"))?;
+        let version = header & 0x0F;
+        if version != METADATA_VERSION {
+            return Err(variant_error(format!(
+                "Unsupported variant metadata version {}",
+                version
+            )));
+        }
+        let offset_size = ((header >> 6) & 0x03) as usize + 1;
+        let dictionary_size = read_uint(bytes, 1, offset_size)?;
+
+        Ok(Self {
+            bytes,
+            offset_size,
+            dictionary_size,
+        })
+    }
+
+    fn key(&self, id: usize) -> Result<Arc<str>> {
+        if id >= self.dictionary_size {
+            return Err(variant_error(format!(
+                "Variant field id {} out of range for dictionary of {} keys",
+                id, self.dictionary_size
+            )));
+        }
+        let offsets_start = 1 + self.offset_size;
+        let start = read_uint(
+            self.bytes,
+            offsets_start + id * self.offset_size,
+            self.offset_size,
+        )?;
+        let end = read_uint(
+            self.bytes,
+            offsets_start + (id + 1) * self.offset_size,
+            self.offset_size,
+        )?;
+        let data_start = offsets_start + (self.dictionary_size + 1) * self.offset_size;
+        let key = slice(self.bytes, data_start + start, data_start + end)?;
+        Ok(Arc::from(std::str::from_utf8(key)?))
+    }
+}
+
+/// Rebuild a value from its encoded and shredded parts
+///
+/// Returns `None` when both parts are missing, which inside a shredded object
+/// means the field is absent rather than null.
+fn unshred(
+    metadata: &Metadata,
+    value: Option<&[u8]>,
+    typed_value: Option<&ParquetValue>,
+) -> Result<Option<ParquetValue>> {
+    match typed_value {
+        None | Some(ParquetValue::Null) => value.map(|v| decode_value(metadata, v)).transpose(),
+        Some(ParquetValue::Record(fields)) => {
+            let mut object = IndexMap::with_capacity(fields.len());
+            for (name, field) in fields {
+                let (field_value, field_typed_value) = shredded_parts(field)?;
+                if let Some(v) = unshred(metadata, field_value, field_typed_value)? {
+                    object.insert(name.clone(), v);
+                }
+            }
+
+            // Partially shredded objects keep the remaining fields in `value`
+            if let Some(value) = value {
+                match decode_value(metadata, value)? {
+                    ParquetValue::Record(rest) => object.extend(rest),
+                    other => {
+                        return Err(variant_error(format!(
+                            "Expected object for partially shredded variant, got {}",
+                            other.type_name()
+                        )))
+                    }
+                }
+            }
+
+            Ok(Some(ParquetValue::Record(object)))
+        }
+        Some(ParquetValue::List(elements)) => {
+            let items = elements
+                .iter()
+                .map(|element| {
+                    let (element_value, element_typed_value) = shredded_parts(element)?;
+                    Ok(unshred(metadata, element_value, element_typed_value)?
+                        .unwrap_or(ParquetValue::Null))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Some(ParquetValue::List(items)))
+        }
+        Some(typed) => Ok(Some(typed.clone())),
+    }
+}
+
+/// Split a shredded `{value, typed_value}` group into its parts
+fn shredded_parts(group: &ParquetValue) -> Result<(Option<&[u8]>, Option<&ParquetValue>)> {
+    match group {
+        ParquetValue::Record(parts) => {
+            let value = match parts.get("value") {
+                Some(ParquetValue::Bytes(b)) => Some(b.as_ref()),
+                None | Some(ParquetValue::Null) => None,
+                Some(other) => {
+                    return Err(variant_error(format!(
+                        "Expected binary shredded value, got {}",
+                        other.type_name()
+                    )))
+                }
+            };
+            Ok((value, parts.get("typed_value")))
+        }
+        ParquetValue::Null => Ok((None, None)),
+        other => Err(variant_error(format!(
+            "Expected shredded variant group, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+fn decode_value(metadata: &Metadata, bytes: &[u8]) -> Result<ParquetValue> {
+    let header = *bytes
+        .first()
+        .ok_or_else(|| variant_error("Variant value is empty"))?;
+    let value_header = header >> 2;
+    let data = &bytes[1..];
+
+    match header & 0x03 {
+        BASIC_PRIMITIVE => decode_primitive(value_header, data),
+        BASIC_SHORT_STRING => {
+            let s = std::str::from_utf8(slice(data, 0, value_header as usize)?)?;
+            Ok(ParquetValue::String(Arc::from(s)))
+        }
+        BASIC_OBJECT => {
+            let offset_size = (value_header & 0x03) as usize + 1;
+            let id_size = ((value_header >> 2) & 0x03) as usize + 1;
+            let is_large = (value_header >> 4) & 0x01 == 1;
+
+            let (num_elements, ids_start) = read_num_elements(data, is_large)?;
+            let offsets_start = ids_start + num_elements * id_size;
+            let values_start = offsets_start + (num_elements + 1) * offset_size;
+            let total = read_uint(
+                data,
+                offsets_start + num_elements * offset_size,
+                offset_size,
+            )?;
+            let values = slice(data, values_start, values_start + total)?;
+
+            let mut object = IndexMap::with_capacity(num_elements);
+            for i in 0..num_elements {
+                let id = read_uint(data, ids_start + i * id_size, id_size)?;
+                let offset = read_uint(data, offsets_start + i * offset_size, offset_size)?;
+                let field = slice(values, offset, values.len())?;
+                object.insert(metadata.key(id)?, decode_value(metadata, field)?);
+            }
+            Ok(ParquetValue::Record(object))
+        }
+        BASIC_ARRAY => {
+            let offset_size = (value_header & 0x03) as usize + 1;
+            let is_large = (value_header >> 2) & 0x01 == 1;
+
+            let (num_elements, offsets_start) = read_num_elements(data, is_large)?;
+            let values_start = offsets_start + (num_elements + 1) * offset_size;
+
+            let mut items = Vec::with_capacity(num_elements);
+            for i in 0..num_elements {
+                let start = read_uint(data, offsets_start + i * offset_size, offset_size)?;
+                let end = read_uint(data, offsets_start + (i + 1) * offset_size, offset_size)?;
+                let item = slice(data, values_start + start, values_start + end)?;
+                items.push(decode_value(metadata, item)?);
+            }
+            Ok(ParquetValue::List(items))
+        }
+        _ => unreachable!("basic type is two bits"),
+    }
+}
+
+fn decode_primitive(type_id: u8, data: &[u8]) -> Result<ParquetValue> {
+    let utc = || Some(Arc::from("UTC"));
+
+    Ok(match type_id {
+        PRIMITIVE_NULL => ParquetValue::Null,
+        PRIMITIVE_TRUE => ParquetValue::Boolean(true),
+        PRIMITIVE_FALSE => ParquetValue::Boolean(false),
+        PRIMITIVE_INT8 => ParquetValue::Int8(i8::from_le_bytes(take(data, 0)?)),
+        PRIMITIVE_INT16 => ParquetValue::Int16(i16::from_le_bytes(take(data, 0)?)),
+        PRIMITIVE_INT32 => ParquetValue::Int32(i32::from_le_bytes(take(data, 0)?)),
+        PRIMITIVE_INT64 => ParquetValue::Int64(i64::from_le_bytes(take(data, 0)?)),
+        PRIMITIVE_DOUBLE => ParquetValue::Float64(f64::from_le_bytes(take(data, 0)?).into()),
+        PRIMITIVE_FLOAT => ParquetValue::Float32(f32::from_le_bytes(take(data, 0)?).into()),
+        PRIMITIVE_DECIMAL4 => {
+            let scale = decimal_scale(data)?;
+            ParquetValue::Decimal128(i32::from_le_bytes(take(data, 1)?) as i128, scale)
+        }
+        PRIMITIVE_DECIMAL8 => {
+            let scale = decimal_scale(data)?;
+            ParquetValue::Decimal128(i64::from_le_bytes(take(data, 1)?) as i128, scale)
+        }
+        PRIMITIVE_DECIMAL16 => {
+            let scale = decimal_scale(data)?;
+            ParquetValue::Decimal128(i128::from_le_bytes(take(data, 1)?), scale)
+        }
+        PRIMITIVE_DATE => ParquetValue::Date32(i32::from_le_bytes(take(data, 0)?)),
+        PRIMITIVE_TIMESTAMP_MICROS => {
+            ParquetValue::TimestampMicros(i64::from_le_bytes(take(data, 0)?), utc())
+        }
+        PRIMITIVE_TIMESTAMP_NTZ_MICROS => {
+            ParquetValue::TimestampMicros(i64::from_le_bytes(take(data, 0)?), None)
+        }
+        PRIMITIVE_TIMESTAMP_NANOS => {
+            ParquetValue::TimestampNanos(i64::from_le_bytes(take(data, 0)?), utc())
+        }
+        PRIMITIVE_TIMESTAMP_NTZ_NANOS => {
+            ParquetValue::TimestampNanos(i64::from_le_bytes(take(data, 0)?), None)
+        }
+        PRIMITIVE_TIME_NTZ_MICROS => ParquetValue::TimeMicros(i64::from_le_bytes(take(data, 0)?)),
+        PRIMITIVE_BINARY => {
+            let len = u32::from_le_bytes(take(data, 0)?) as usize;
+            ParquetValue::Bytes(Bytes::copy_from_slice(slice(data, 4, 4 + len)?))
+        }
+        PRIMITIVE_STRING => {
+            let len = u32::from_le_bytes(take(data, 0)?) as usize;
+            let s = std::str::from_utf8(slice(data, 4, 4 + len)?)?;
+            ParquetValue::String(Arc::from(s))
+        }
+        PRIMITIVE_UUID => ParquetValue::Uuid(uuid::Uuid::from_bytes(take(data, 0)?)),
+        other => {
+            return Err(variant_error(format!(
+                "Unsupported variant primitive type {}",
+                other
+            )))
+        }
+    })
+}
+
+fn decimal_scale(data: &[u8]) -> Result<i8> {
+    let scale = *data
+        .first()
+        .ok_or_else(|| variant_error("Variant decimal is missing its scale"))?;
+    i8::try_from(scale).map_err(|_| variant_error(format!("Invalid decimal scale {}", scale)))
+}
+
+/// Gather every object key used in a value, sorted for the metadata dictionary
+fn collect_keys(value: &ParquetValue, keys: &mut BTreeSet<Arc<str>>) -> Result<()> {
+    match value {
+        ParquetValue::Record(fields) => {
+            for (name, field) in fields {
+                keys.insert(name.clone());
+                collect_keys(field, keys)?;
+            }
+        }
+        ParquetValue::Map(entries) => {
+            for (key, entry) in entries {
+                keys.insert(object_key(key)?);
+                collect_keys(entry, keys)?;
+            }
+        }
+        ParquetValue::List(items) => {
+            for item in items {
+                collect_keys(item, keys)?;
+            }
+        }
+        ParquetValue::Variant(variant) => collect_keys(&variant.decode()?, keys)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn encode_metadata(keys: &[Arc<str>]) -> Vec<u8> {
+    let total_len: usize = keys.iter().map(|k| k.len()).sum();
+    let offset_size = uint_size(total_len.max(keys.len()));
+
+    // Keys are always written sorted, so readers may binary search them
+    let header = METADATA_VERSION | (1 << 4) | ((offset_size as u8 - 1) << 6);
+
+    let mut metadata = Vec::with_capacity(1 + (keys.len() + 2) * offset_size + total_len);
+    metadata.push(header);
+    write_uint(&mut metadata, keys.len(), offset_size);
+
+    let mut offset = 0;
+    write_uint(&mut metadata, offset, offset_size);
+    for key in keys {
+        offset += key.len();
+        write_uint(&mut metadata, offset, offset_size);
+    }
+    for key in keys {
+        metadata.extend_from_slice(key.as_bytes());
+    }
+    metadata
+}
+
+fn encode_value(value: &ParquetValue, keys: &[Arc<str>], out: &mut Vec<u8>) -> Result<()> {
+    match value {
+        ParquetValue::Null => out.push(primitive_header(PRIMITIVE_NULL)),
+        ParquetValue::Boolean(true) => out.push(primitive_header(PRIMITIVE_TRUE)),
+        ParquetValue::Boolean(false) => out.push(primitive_header(PRIMITIVE_FALSE)),
+        ParquetValue::Int8(i) => encode_int(*i as i64, out),
+        ParquetValue::Int16(i) => encode_int(*i as i64, out),
+        ParquetValue::Int32(i) => encode_int(*i as i64, out),
+        ParquetValue::Int64(i) => encode_int(*i, out),
+        ParquetValue::UInt8(i) => encode_int(*i as i64, out),
+        ParquetValue::UInt16(i) => encode_int(*i as i64, out),
+        ParquetValue::UInt32(i) => encode_int(*i as i64, out),
+        ParquetValue::UInt64(i) => match i64::try_from(*i) {
+            Ok(i) => encode_int(i, out),
+            Err(_) => encode_decimal(*i as i128, 0, out)?,
+        },
+        ParquetValue::Float16(f) | ParquetValue::Float32(f) => {
+            out.push(primitive_header(PRIMITIVE_FLOAT));
+            out.extend_from_slice(&f.0.to_le_bytes());
+        }
+        ParquetValue::Float64(f) => {
+            out.push(primitive_header(PRIMITIVE_DOUBLE));
+            out.extend_from_slice(&f.0.to_le_bytes());
+        }
+        ParquetValue::Decimal128(v, scale) => encode_decimal(*v, *scale, out)?,
+        ParquetValue::Decimal256(v, scale) => {
+            let v = i128::try_from(v.clone())
+                .map_err(|_| variant_error(format!("Decimal {} is too large for a variant", v)))?;
+            encode_decimal(v, *scale, out)?
+        }
+        ParquetValue::String(s) | ParquetValue::Json(s) | ParquetValue::Enum(s) => {
+            encode_string(s, out)
+        }
+        ParquetValue::Bytes(b) | ParquetValue::Geometry(b) => {
+            out.push(primitive_header(PRIMITIVE_BINARY));
+            out.extend_from_slice(&checked_len(b.len())?.to_le_bytes());
+            out.extend_from_slice(b);
+        }
+        ParquetValue::Uuid(u) => {
+            out.push(primitive_header(PRIMITIVE_UUID));
+            out.extend_from_slice(u.as_bytes());
+        }
+        ParquetValue::Date32(days) => {
+            out.push(primitive_header(PRIMITIVE_DATE));
+            out.extend_from_slice(&days.to_le_bytes());
+        }
+        ParquetValue::Date64(millis) => {
+            let days = i32::try_from(millis.div_euclid(86_400_000))
+                .map_err(|_| variant_error(format!("Date64 {} is out of range", millis)))?;
+            out.push(primitive_header(PRIMITIVE_DATE));
+            out.extend_from_slice(&days.to_le_bytes());
+        }
+        ParquetValue::TimestampSecond(t, tz) => encode_timestamp_micros(*t, 1_000_000, tz, out)?,
+        ParquetValue::TimestampMillis(t, tz) => encode_timestamp_micros(*t, 1_000, tz, out)?,
+        ParquetValue::TimestampMicros(t, tz) => encode_timestamp_micros(*t, 1, tz, out)?,
+        ParquetValue::TimestampNanos(t, tz) => {
+            let type_id = if tz.is_some() {
+                PRIMITIVE_TIMESTAMP_NANOS
+            } else {
+                PRIMITIVE_TIMESTAMP_NTZ_NANOS
+            };
+            out.push(primitive_header(type_id));
+            out.extend_from_slice(&t.to_le_bytes());
+        }
+        ParquetValue::TimeMillis(t) => encode_time_micros(*t as i64 * 1_000, out),
+        ParquetValue::TimeMicros(t) => encode_time_micros(*t, out),
+        ParquetValue::TimeNanos(t) => encode_time_micros(t / 1_000, out),
+        ParquetValue::List(items) => {
+            let mut values = Vec::new();
+            let mut offsets = Vec::with_capacity(items.len() + 1);
+            offsets.push(0);
+            for item in items {
+                encode_value(item, keys, &mut values)?;
+                offsets.push(values.len());
+            }
+
+            let offset_size = uint_size(values.len());
+            let is_large = items.len() > u8::MAX as usize;
+            let value_header = (offset_size as u8 - 1) | ((is_large as u8) << 2);
+            out.push(BASIC_ARRAY | (value_header << 2));
+            write_num_elements(out, items.len(), is_large);
+            for offset in offsets {
+                write_uint(out, offset, offset_size);
+            }
+            out.extend_from_slice(&values);
+        }
+        ParquetValue::Record(fields) => {
+            let fields = fields
+                .iter()
+                .map(|(name, field)| (name.clone(), field))
+                .collect::<Vec<_>>();
+            encode_object(fields, keys, out)?
+        }
+        ParquetValue::Map(entries) => {
+            let fields = entries
+                .iter()
+                .map(|(key, entry)| Ok((object_key(key)?, entry)))
+                .collect::<Result<Vec<_>>>()?;
+            encode_object(fields, keys, out)?
+        }
+        ParquetValue::Variant(variant) => encode_value(&variant.decode()?, keys, out)?,
+        ParquetValue::DurationSecond(_)
+        | ParquetValue::DurationMillis(_)
+        | ParquetValue::DurationMicros(_)
+        | ParquetValue::DurationNanos(_)
+        | ParquetValue::Interval(_, _, _) => {
+            return Err(variant_error(format!(
+                "{} values cannot be stored in a variant",
+                value.type_name()
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn encode_object(
+    mut fields: Vec<(Arc<str>, &ParquetValue)>,
+    keys: &[Arc<str>],
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    // The spec requires fields ordered by key name; later duplicates win
+    fields.sort_by(|a, b| a.0.cmp(&b.0));
+    fields.reverse();
+    fields.dedup_by(|a, b| a.0 == b.0);
+    fields.reverse();
+
+    let mut ids = Vec::with_capacity(fields.len());
+    let mut offsets = Vec::with_capacity(fields.len() + 1);
+    let mut values = Vec::new();
+    for (name, field) in &fields {
+        let id = keys
+            .binary_search(name)
+            .map_err(|_| variant_error(format!("Key {:?} missing from variant metadata", name)))?;
+        ids.push(id);
+        offsets.push(values.len());
+        encode_value(field, keys, &mut values)?;
+    }
+    offsets.push(values.len());
+
+    let offset_size = uint_size(values.len());
+    let id_size = uint_size(keys.len().saturating_sub(1));
+    let is_large = fields.len() > u8::MAX as usize;
+    let value_header =
+        (offset_size as u8 - 1) | ((id_size as u8 - 1) << 2) | ((is_large as u8) << 4);
+    out.push(BASIC_OBJECT | (value_header << 2));
+    write_num_elements(out, fields.len(), is_large);
+    for id in ids {
+        write_uint(out, id, id_size);
+    }
+    for offset in offsets {
+        write_uint(out, offset, offset_size);
+    }
+    out.extend_from_slice(&values);
+    Ok(())
+}
+
+fn encode_int(value: i64, out: &mut Vec<u8>) {
+    if let Ok(v) = i8::try_from(value) {
+        out.push(primitive_header(PRIMITIVE_INT8));
+        out.extend_from_slice(&v.to_le_bytes());
+    } else if let Ok(v) = i16::try_from(value) {
+        out.push(primitive_header(PRIMITIVE_INT16));
+        out.extend_from_slice(&v.to_le_bytes());
+    } else if let Ok(v) = i32::try_from(value) {
+        out.push(primitive_header(PRIMITIVE_INT32));
+        out.extend_from_slice(&v.to_le_bytes());
+    } else {
+        out.push(primitive_header(PRIMITIVE_INT64));
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn encode_decimal(value: i128, scale: i8, out: &mut Vec<u8>) -> Result<()> {
+    if !(0..=38).contains(&scale) {
+        return Err(variant_error(format!(
+            "Decimal scale {} is not supported in a variant",
+            scale
+        )));
+    }
+
+    // Pick the narrowest width whose precision (9, 18 or 38 digits) fits
+    let magnitude = value.unsigned_abs();
+    if magnitude < 10u128.pow(9) {
+        out.push(primitive_header(PRIMITIVE_DECIMAL4));
+        out.push(scale as u8);
+        out.extend_from_slice(&(value as i32).to_le_bytes());
+    } else if magnitude < 10u128.pow(18) {
+        out.push(primitive_header(PRIMITIVE_DECIMAL8));
+        out.push(scale as u8);
+        out.extend_from_slice(&(value as i64).to_le_bytes());
+    } else if magnitude < 10u128.pow(38) {
+        out.push(primitive_header(PRIMITIVE_DECIMAL16));
+        out.push(scale as u8);
+        out.extend_from_slice(&value.to_le_bytes());
+    } else {
+        return Err(variant_error(format!(
+            "Decimal {} exceeds 38 digits of precision",
+            value
+        )));
+    }
+    Ok(())
+}
+
+fn encode_string(s: &str, out: &mut Vec<u8>) {
+    if s.len() <= MAX_SHORT_STRING_LEN {
+        out.push(BASIC_SHORT_STRING | ((s.len() as u8) << 2));
+    } else {
+        out.push(primitive_header(PRIMITIVE_STRING));
+        out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    }
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn encode_timestamp_micros(
+    value: i64,
+    micros_per_unit: i64,
+    tz: &Option<Arc<str>>,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    let micros = value
+        .checked_mul(micros_per_unit)
+        .ok_or_else(|| variant_error(format!("Timestamp {} is out of range", value)))?;
+    let type_id = if tz.is_some() {
+        PRIMITIVE_TIMESTAMP_MICROS
+    } else {
+        PRIMITIVE_TIMESTAMP_NTZ_MICROS
+    };
+    out.push(primitive_header(type_id));
+    out.extend_from_slice(&micros.to_le_bytes());
+    Ok(())
+}
+
+fn encode_time_micros(micros: i64, out: &mut Vec<u8>) {
+    out.push(primitive_header(PRIMITIVE_TIME_NTZ_MICROS));
+    out.extend_from_slice(&micros.to_le_bytes());
+}
+
+fn object_key(key: &ParquetValue) -> Result<Arc<str>> {
+    match key {
+        ParquetValue::String(s) | ParquetValue::Enum(s) => Ok(s.clone()),
+        other => Err(variant_error(format!(
+            "Variant object keys must be strings, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+fn primitive_header(type_id: u8) -> u8 {
+    BASIC_PRIMITIVE | (type_id << 2)
+}
+
+fn checked_len(len: usize) -> Result<u32> {
+    u32::try_from(len).map_err(|_| variant_error("Variant binary exceeds 4GiB"))
+}
+
+/// Number of bytes needed to store `max` as an unsigned integer
+fn uint_size(max: usize) -> usize {
+    match max {
+        0..=0xFF => 1,
+        0x100..=0xFFFF => 2,
+        0x1_0000..=0xFF_FFFF => 3,
+        _ => 4,
+    }
+}
+
+fn write_uint(out: &mut Vec<u8>, value: usize, size: usize) {
+    out.extend_from_slice(&(value as u32).to_le_bytes()[..size]);
+}
+
+fn write_num_elements(out: &mut Vec<u8>, count: usize, is_large: bool) {
+    write_uint(out, count, if is_large { 4 } else { 1 });
+}
+
+fn read_uint(bytes: &[u8], pos: usize, size: usize) -> Result<usize> {
+    let raw = slice(bytes, pos, pos + size)?;
+    let mut buf = [0u8; 4];
+    buf[..size].copy_from_slice(raw);
+    Ok(u32::from_le_bytes(buf) as usize)
+}
+
+fn read_num_elements(data: &[u8], is_large: bool) -> Result<(usize, usize)> {
+    let size = if is_large { 4 } else { 1 };
+    Ok((read_uint(data, 0, size)?, size))
+}
+
+fn take<const N: usize>(data: &[u8], pos: usize) -> Result<[u8; N]> {
+    let mut buf = [0u8; N];
+    buf.copy_from_slice(slice(data, pos, pos + N)?);
+    Ok(buf)
+}
+
+fn slice(bytes: &[u8], start: usize, end: usize) -> Result<&[u8]> {
+    bytes
+        .get(start..end)
+        .ok_or_else(|| variant_error("Variant data is truncated"))
+}
+
+fn variant_error(message: impl Into<String>) -> ParquetError {
+    ParquetError::Conversion(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ordered_float::OrderedFloat;
+
+    fn record(fields: Vec<(&str, ParquetValue)>) -> ParquetValue {
+        ParquetValue::Record(fields.into_iter().map(|(k, v)| (Arc::from(k), v)).collect())
+    }
+
+    #[test]
+    fn test_scalar_roundtrip() {
+        let values = vec![
+            ParquetValue::Null,
+            ParquetValue::Boolean(true),
+            ParquetValue::Int8(-3),
+            ParquetValue::Int16(1_000),
+            ParquetValue::Int32(100_000),
+            ParquetValue::Int64(10_000_000_000),
+            ParquetValue::Float64(OrderedFloat(1.25)),
+            ParquetValue::Decimal128(12_345, 2),
+            ParquetValue::String(Arc::from("short")),
+            ParquetValue::String(Arc::from("x".repeat(100).as_str())),
+            ParquetValue::Bytes(Bytes::from_static(b"\x00\x01")),
+            ParquetValue::Date32(19_000),
+            ParquetValue::TimestampMicros(1_700_000_000_000_000, Some(Arc::from("UTC"))),
+            ParquetValue::TimestampMicros(1_700_000_000_000_000, None),
+        ];
+
+        for value in values {
+            let variant = Variant::from_value(&value).unwrap();
+            assert_eq!(variant.decode().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_integers_use_narrowest_width() {
+        let variant = Variant::from_value(&ParquetValue::Int64(7)).unwrap();
+        assert_eq!(variant.decode().unwrap(), ParquetValue::Int8(7));
+        assert_eq!(variant.value.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_nested_roundtrip() {
+        let value = record(vec![
+            ("name", ParquetValue::String(Arc::from("alice"))),
+            (
+                "tags",
+                ParquetValue::List(vec![
+                    ParquetValue::String(Arc::from("a")),
+                    ParquetValue::Null,
+                ]),
+            ),
+            ("address", record(vec![("city", ParquetValue::Int8(1))])),
+        ]);
+
+        let decoded = Variant::from_value(&value).unwrap().decode().unwrap();
+
+        // Object fields come back sorted by key
+        let expected = record(vec![
+            ("address", record(vec![("city", ParquetValue::Int8(1))])),
+            ("name", ParquetValue::String(Arc::from("alice"))),
+            (
+                "tags",
+                ParquetValue::List(vec![
+                    ParquetValue::String(Arc::from("a")),
+                    ParquetValue::Null,
+                ]),
+            ),
+        ]);
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn test_unshred_partially_shredded_object() {
+        let keys: Vec<Arc<str>> = vec![Arc::from("a"), Arc::from("b")];
+        let mut value = Vec::new();
+        encode_value(
+            &record(vec![("b", ParquetValue::Int8(2))]),
+            &keys,
+            &mut value,
+        )
+        .unwrap();
+
+        let variant = Variant {
+            metadata: Bytes::from(encode_metadata(&keys)),
+            value: Some(Bytes::from(value)),
+            typed_value: Some(Box::new(record(vec![(
+                "a",
+                record(vec![
+                    ("value", ParquetValue::Null),
+                    ("typed_value", ParquetValue::Int64(1)),
+                ]),
+            )]))),
+        };
+
+        assert_eq!(
+            variant.decode().unwrap(),
+            record(vec![
+                ("a", ParquetValue::Int64(1)),
+                ("b", ParquetValue::Int8(2)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_rejects_durations() {
+        assert!(Variant::from_value(&ParquetValue::DurationSecond(1)).is_err());
+    }
+}
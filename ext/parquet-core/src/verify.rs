@@ -0,0 +1,222 @@
+//! File integrity verification
+//!
+//! A file whose footer parses can still have corrupt pages, which only show
+//! up when a reader reaches them. [`verify`] visits every column chunk of
+//! every row group twice: once walking the raw page headers, checking page
+//! CRCs and value counts, and once decoding the chunk through Arrow, which
+//! decompresses and decodes every page. Problems are collected into a
+//! [`VerifyReport`] rather than stopping at the first one.
+
+use crate::Result;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+};
+use parquet::arrow::ProjectionMask;
+use parquet::file::metadata::ColumnChunkMetaData;
+use parquet::file::reader::ChunkReader;
+use parquet::format::PageHeader;
+use parquet::thrift::{TCompactSliceInputProtocol, TSerializable};
+
+/// Something wrong with the file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyProblem {
+    /// Row group index, `None` for problems with the file as a whole
+    pub row_group: Option<usize>,
+    /// Dotted path of the leaf column, when the problem is in a column chunk
+    pub column: Option<String>,
+    /// File offset of the page header, when the problem is in one page
+    pub page_offset: Option<u64>,
+    pub message: String,
+}
+
+/// Outcome of [`verify`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    pub num_rows: i64,
+    pub row_groups: usize,
+    /// Pages whose headers were read
+    pub pages: usize,
+    /// Pages whose CRC was present and checked
+    pub checksums_verified: usize,
+    pub problems: Vec<VerifyProblem>,
+}
+
+impl VerifyReport {
+    /// Whether no problem was found
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Check every page of every column chunk
+///
+/// With `checksums` set, page CRCs are compared when the writer stored them.
+/// Only failures to read the file at all are returned as errors; a footer
+/// that cannot be parsed is reported as a problem.
+pub fn verify<R>(reader: &R, checksums: bool) -> Result<VerifyReport>
+where
+    R: ChunkReader + Clone + 'static,
+{
+    let mut report = VerifyReport::default();
+
+    let metadata = match ArrowReaderMetadata::load(reader, ArrowReaderOptions::new()) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            report.problems.push(VerifyProblem {
+                row_group: None,
+                column: None,
+                page_offset: None,
+                message: format!("Cannot read footer: {}", e),
+            });
+            return Ok(report);
+        }
+    };
+
+    let parquet_metadata = metadata.metadata();
+    let file_metadata = parquet_metadata.file_metadata();
+    let schema_descr = file_metadata.schema_descr();
+    report.num_rows = file_metadata.num_rows();
+    report.row_groups = parquet_metadata.num_row_groups();
+
+    let row_group_rows: i64 = parquet_metadata
+        .row_groups()
+        .iter()
+        .map(|rg| rg.num_rows())
+        .sum();
+    if row_group_rows != report.num_rows {
+        report.problems.push(VerifyProblem {
+            row_group: None,
+            column: None,
+            page_offset: None,
+            message: format!(
+                "Row groups hold {} rows but the footer says {}",
+                row_group_rows, report.num_rows
+            ),
+        });
+    }
+
+    for (rg_idx, row_group) in parquet_metadata.row_groups().iter().enumerate() {
+        for (col_idx, chunk) in row_group.columns().iter().enumerate() {
+            let path = chunk.column_path().string();
+            let problem = |page_offset: Option<u64>, message: String| VerifyProblem {
+                row_group: Some(rg_idx),
+                column: Some(path.clone()),
+                page_offset,
+                message,
+            };
+
+            // Walk the raw pages first so a broken one can be pinned to an offset
+            if let Err((offset, message)) = check_pages(reader, chunk, checksums, &mut report) {
+                report.problems.push(problem(offset, message));
+                continue;
+            }
+
+            let decoded = ParquetRecordBatchReaderBuilder::new_with_metadata(
+                reader.clone(),
+                metadata.clone(),
+            )
+            .with_row_groups(vec![rg_idx])
+            .with_projection(ProjectionMask::leaves(schema_descr, [col_idx]))
+            .build()
+            .map_err(|e| e.to_string())
+            .and_then(|mut batches| {
+                batches.try_fold(0i64, |rows, batch| {
+                    batch
+                        .map(|batch| rows + batch.num_rows() as i64)
+                        .map_err(|e| e.to_string())
+                })
+            });
+            match decoded {
+                Ok(rows) if rows != row_group.num_rows() => report.problems.push(problem(
+                    None,
+                    format!(
+                        "Decoded {} rows but the row group has {}",
+                        rows,
+                        row_group.num_rows()
+                    ),
+                )),
+                Ok(_) => {}
+                Err(message) => report
+                    .problems
+                    .push(problem(None, format!("Cannot decode: {}", message))),
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Read the page headers of a column chunk, checking CRCs and value counts
+///
+/// Returns the offset of the offending page with the error, if any.
+fn check_pages<R: ChunkReader>(
+    reader: &R,
+    chunk: &ColumnChunkMetaData,
+    checksums: bool,
+    report: &mut VerifyReport,
+) -> std::result::Result<(), (Option<u64>, String)> {
+    let (start, length) = chunk.byte_range();
+    let bytes = reader
+        .get_bytes(start, length as usize)
+        .map_err(|e| (None, format!("Cannot read column chunk: {}", e)))?;
+
+    let mut position = 0usize;
+    let mut values = 0i64;
+    while position < bytes.len() {
+        let page_offset = start + position as u64;
+        let remaining = &bytes[position..];
+
+        let mut protocol = TCompactSliceInputProtocol::new(remaining);
+        let header = PageHeader::read_from_in_protocol(&mut protocol)
+            .map_err(|e| (Some(page_offset), format!("Invalid page header: {}", e)))?;
+        let header_len = remaining.len() - protocol.as_slice().len();
+
+        let page_len = usize::try_from(header.compressed_page_size)
+            .ok()
+            .filter(|len| header_len + len <= remaining.len())
+            .ok_or_else(|| {
+                (
+                    Some(page_offset),
+                    format!(
+                        "Page of {} bytes runs past the end of the column chunk",
+                        header.compressed_page_size
+                    ),
+                )
+            })?;
+        let page = &remaining[header_len..header_len + page_len];
+        report.pages += 1;
+
+        if let (true, Some(expected)) = (checksums, header.crc) {
+            let actual = crc32fast::hash(page);
+            if actual != expected as u32 {
+                return Err((
+                    Some(page_offset),
+                    format!(
+                        "Page CRC mismatch: expected {:08x}, found {:08x}",
+                        expected as u32, actual
+                    ),
+                ));
+            }
+            report.checksums_verified += 1;
+        }
+
+        if let Some(data_header) = &header.data_page_header {
+            values += data_header.num_values as i64;
+        } else if let Some(data_header) = &header.data_page_header_v2 {
+            values += data_header.num_values as i64;
+        }
+        position += header_len + page_len;
+    }
+
+    if values != chunk.num_values() {
+        return Err((
+            None,
+            format!(
+                "Pages hold {} values but the column chunk has {}",
+                values,
+                chunk.num_values()
+            ),
+        ));
+    }
+    Ok(())
+}
@@ -1,14 +1,20 @@
 //! Core Parquet writing functionality
 
 use crate::{
-    arrow_conversion::parquet_values_to_arrow_array, ParquetError, ParquetValue, Result, Schema,
-    SchemaNode,
+    arrow_conversion::{
+        parquet_values_to_arrow_array, ENUM_EXTENSION_NAME, INTERVAL_EXTENSION_NAME,
+    },
+    file_writer::{annotated_schema, FileWriter},
+    geometry::{GeoMetadata, GEOMETRY_EXTENSION_NAME, GEO_METADATA_KEY},
+    sorting::{sorting_columns, SortCheck, SortColumn},
+    variant::VARIANT_EXTENSION_NAME,
+    ParquetError, ParquetValue, Result, Schema, SchemaNode,
 };
 use arrow::record_batch::RecordBatch;
 use arrow_schema::{DataType, Field};
-use parquet::arrow::ArrowWriter;
 use parquet::basic::Compression;
 use parquet::file::properties::WriterProperties;
+use parquet::format::KeyValue;
 use rand::Rng;
 use std::sync::Arc;
 
@@ -25,6 +31,9 @@ pub struct WriterBuilder {
     batch_size: Option<usize>,
     memory_threshold: usize,
     sample_size: usize,
+    max_row_group_size: Option<usize>,
+    sorted_by: Vec<SortColumn>,
+    verify_sorted: bool,
 }
 
 impl Default for WriterBuilder {
@@ -34,6 +43,9 @@ impl Default for WriterBuilder {
             batch_size: None,
             memory_threshold: DEFAULT_MEMORY_THRESHOLD,
             sample_size: DEFAULT_SAMPLE_SIZE,
+            max_row_group_size: None,
+            sorted_by: Vec::new(),
+            verify_sorted: false,
         }
     }
 }
@@ -68,18 +80,91 @@ impl WriterBuilder {
         self
     }
 
+    /// Set the maximum number of rows per row group
+    pub fn with_max_row_group_size(mut self, rows: usize) -> Self {
+        self.max_row_group_size = Some(rows);
+        self
+    }
+
+    /// Declare the order rows are written in as the `sorting_columns` of
+    /// every row group
+    ///
+    /// Only top level primitive columns can be sorted by. Readers use the
+    /// leading column to find row groups for a range without checking each.
+    pub fn with_sorted_by(mut self, columns: Vec<SortColumn>) -> Self {
+        self.sorted_by = columns;
+        self
+    }
+
+    /// Fail the write at the first row out of the `sorted_by` order
+    pub fn with_verify_sorted(mut self, verify: bool) -> Self {
+        self.verify_sorted = verify;
+        self
+    }
+
+    fn properties(&self, arrow_schema: &arrow_schema::Schema) -> Result<WriterProperties> {
+        let mut builder = WriterProperties::builder().set_compression(self.compression);
+        if let Some(rows) = self.max_row_group_size {
+            builder = builder.set_max_row_group_size(rows);
+        }
+        if !self.sorted_by.is_empty() {
+            // The builder never sets coerce_types
+            let schema_descr = annotated_schema(arrow_schema, false)?;
+            builder =
+                builder.set_sorting_columns(Some(sorting_columns(&schema_descr, &self.sorted_by)?));
+        }
+        Ok(builder.build())
+    }
+
+    fn sort_check(&self, arrow_schema: &arrow_schema::Schema) -> Result<Option<SortCheck>> {
+        if self.verify_sorted && !self.sorted_by.is_empty() {
+            Ok(Some(SortCheck::try_new(arrow_schema, &self.sorted_by)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Build a Writer for an Arrow schema, e.g. one inferred from JSON or CSV
+    ///
+    /// Such writers are fed with [`Writer::write_batch`]; rows and columns
+    /// can be written too, as long as they match the Arrow types.
+    pub fn build_with_arrow_schema<W: std::io::Write + Send>(
+        self,
+        writer: W,
+        arrow_schema: Arc<arrow_schema::Schema>,
+    ) -> Result<Writer<W>> {
+        let props = self.properties(&arrow_schema)?;
+        let sort_check = self.sort_check(&arrow_schema)?;
+
+        let file_writer = FileWriter::try_new(writer, arrow_schema.clone(), props)?;
+
+        Ok(Writer {
+            file_writer: Some(file_writer),
+            arrow_schema,
+            buffered_rows: Vec::new(),
+            current_batch_size: self.batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
+            memory_threshold: self.memory_threshold,
+            sample_size: self.sample_size,
+            size_samples: Vec::with_capacity(self.sample_size),
+            total_rows_written: 0,
+            fixed_batch_size: self.batch_size,
+            geo_metadata: None,
+            sort_check,
+        })
+    }
+
     /// Build a Writer with the configured settings
     pub fn build<W: std::io::Write + Send>(self, writer: W, schema: Schema) -> Result<Writer<W>> {
         let arrow_schema = schema_to_arrow(&schema)?;
+        let geo_metadata = GeoMetadata::from_schema(&schema);
 
-        let props = WriterProperties::builder()
-            .set_compression(self.compression)
-            .build();
+        let props = self.properties(&arrow_schema)?;
+        let sort_check = self.sort_check(&arrow_schema)?;
 
-        let arrow_writer = ArrowWriter::try_new(writer, arrow_schema.clone(), Some(props))?;
+        let file_writer = FileWriter::try_new(writer, arrow_schema.clone(), props)?;
 
         Ok(Writer {
-            arrow_writer: Some(arrow_writer),
+            file_writer: Some(file_writer),
             arrow_schema,
             buffered_rows: Vec::new(),
             current_batch_size: self.batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
@@ -88,13 +173,15 @@ impl WriterBuilder {
             size_samples: Vec::with_capacity(self.sample_size),
             total_rows_written: 0,
             fixed_batch_size: self.batch_size,
+            geo_metadata,
+            sort_check,
         })
     }
 }
 
 /// Core Parquet writer that works with any type implementing Write
 pub struct Writer<W: std::io::Write> {
-    arrow_writer: Option<ArrowWriter<W>>,
+    file_writer: Option<FileWriter<W>>,
     arrow_schema: Arc<arrow_schema::Schema>,
     buffered_rows: Vec<Vec<ParquetValue>>,
     current_batch_size: usize,
@@ -103,6 +190,8 @@ pub struct Writer<W: std::io::Write> {
     size_samples: Vec<usize>,
     total_rows_written: usize,
     fixed_batch_size: Option<usize>,
+    geo_metadata: Option<GeoMetadata>,
+    sort_check: Option<SortCheck>,
 }
 
 impl<W> Writer<W>
@@ -117,11 +206,12 @@ where
     /// Create a new writer with custom properties
     pub fn new_with_properties(writer: W, schema: Schema, props: WriterProperties) -> Result<Self> {
         let arrow_schema = schema_to_arrow(&schema)?;
+        let geo_metadata = GeoMetadata::from_schema(&schema);
 
-        let arrow_writer = ArrowWriter::try_new(writer, arrow_schema.clone(), Some(props))?;
+        let file_writer = FileWriter::try_new(writer, arrow_schema.clone(), props)?;
 
         Ok(Self {
-            arrow_writer: Some(arrow_writer),
+            file_writer: Some(file_writer),
             arrow_schema,
             buffered_rows: Vec::new(),
             current_batch_size: DEFAULT_BATCH_SIZE,
@@ -130,9 +220,62 @@ where
             size_samples: Vec::with_capacity(DEFAULT_SAMPLE_SIZE),
             total_rows_written: 0,
             fixed_batch_size: None,
+            geo_metadata,
+            sort_check: None,
         })
     }
 
+    /// The Arrow schema record batches must match
+    pub fn arrow_schema(&self) -> &Arc<arrow_schema::Schema> {
+        &self.arrow_schema
+    }
+
+    /// Write an Arrow record batch as is
+    ///
+    /// Any buffered rows are written first so the order is kept. Field names
+    /// may differ, but the batch must have the writer's column types.
+    pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
+        let fields = self.arrow_schema.fields();
+        if batch.num_columns() != fields.len() {
+            return Err(ParquetError::Schema(format!(
+                "Batch has {} columns but schema has {} fields",
+                batch.num_columns(),
+                fields.len()
+            )));
+        }
+        for (column, field) in batch.columns().iter().zip(fields) {
+            if column.data_type() != field.data_type() {
+                return Err(ParquetError::Schema(format!(
+                    "Column {} has type {} but schema expects {}",
+                    field.name(),
+                    column.data_type(),
+                    field.data_type()
+                )));
+            }
+        }
+
+        self.flush_buffered_rows()?;
+
+        // Rebuild with the writer's schema so its field names and metadata are used
+        let batch = RecordBatch::try_new(self.arrow_schema.clone(), batch.columns().to_vec())?;
+        self.check_sort_order(&batch)?;
+        self.total_rows_written += batch.num_rows();
+
+        match &mut self.file_writer {
+            Some(writer) => {
+                writer.write(&batch)?;
+                if writer.in_progress_size() >= self.memory_threshold {
+                    writer.flush()?;
+                }
+                Ok(())
+            }
+            None => Err(ParquetError::Io(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Writer has been closed",
+            ))),
+        }
+    }
+
     /// Write a batch of rows to the Parquet file
     ///
     /// Each row is a vector of values corresponding to the schema fields
@@ -147,7 +290,31 @@ where
     ///
     /// Rows are buffered internally and written in batches to optimize memory usage
     pub fn write_row(&mut self, row: Vec<ParquetValue>) -> Result<()> {
-        // Validate row length
+        self.check_row_length(&row)?;
+
+        // Validate each value matches its schema
+        for (idx, value) in row.iter().enumerate() {
+            self.validate_value(idx, value)?;
+        }
+
+        self.buffer_row(row)
+    }
+
+    /// Write rows whose values were already checked with [`Writer::validate_value`]
+    ///
+    /// Skips the per-value validation of [`Writer::write_rows`]. Failures that
+    /// only show up once a batch is built, such as rows out of `sorted_by`
+    /// order, still fail the whole batch.
+    pub fn write_rows_unchecked(&mut self, rows: Vec<Vec<ParquetValue>>) -> Result<()> {
+        for row in rows {
+            self.check_row_length(&row)?;
+            self.buffer_row(row)?;
+        }
+        Ok(())
+    }
+
+    /// Fail unless `row` has one value per schema field
+    fn check_row_length(&self, row: &[ParquetValue]) -> Result<()> {
         let num_cols = self.arrow_schema.fields().len();
         if row.len() != num_cols {
             return Err(ParquetError::Schema(format!(
@@ -156,10 +323,16 @@ where
                 num_cols
             )));
         }
+        Ok(())
+    }
 
-        // Validate each value matches its schema
-        for (idx, (value, field)) in row.iter().zip(self.arrow_schema.fields()).enumerate() {
-            validate_value_against_field(value, field, &format!("row[{}]", idx))?;
+    /// Buffer a row, flushing once the batch is full
+    fn buffer_row(&mut self, row: Vec<ParquetValue>) -> Result<()> {
+        // Track geometry types and bounds for the GeoParquet metadata
+        if let Some(geo) = &mut self.geo_metadata {
+            for (idx, value) in row.iter().enumerate() {
+                geo.observe(idx, value)?;
+            }
         }
 
         // Sample row size for dynamic batch sizing
@@ -178,6 +351,22 @@ where
         Ok(())
     }
 
+    /// Check that `value` fits the field of column `column` without writing it
+    ///
+    /// Lets callers drop a bad row before it reaches a batch, where it would
+    /// fail `write_rows` for every row in the batch.
+    pub fn validate_value(&self, column: usize, value: &ParquetValue) -> Result<()> {
+        let fields = self.arrow_schema.fields();
+        let field = fields.get(column).ok_or_else(|| {
+            ParquetError::Schema(format!(
+                "Column {} is out of range for a schema with {} fields",
+                column,
+                fields.len()
+            ))
+        })?;
+        validate_value_against_field(value, field, &format!("row[{}]", column))
+    }
+
     /// Sample row size for dynamic batch sizing using reservoir sampling
     fn sample_row_size(&mut self, row: &[ParquetValue]) -> Result<()> {
         let row_size = self.estimate_row_size(row)?;
@@ -240,11 +429,27 @@ where
             (TimestampMillis(_, _), DataType::Timestamp(_, _)) => 8,
             (TimestampMicros(_, _), DataType::Timestamp(_, _)) => 8,
             (TimestampNanos(_, _), DataType::Timestamp(_, _)) => 8,
+            (DurationSecond(_), DataType::Duration(_)) => 8,
+            (DurationMillis(_), DataType::Duration(_)) => 8,
+            (DurationMicros(_), DataType::Duration(_)) => 8,
+            (DurationNanos(_), DataType::Duration(_)) => 8,
+            (Interval(_, _, _), DataType::FixedSizeBinary(12)) => 12,
+            (Variant(v), DataType::Struct(_)) => {
+                v.metadata.len()
+                    + v.value.as_ref().map_or(0, |b| b.len())
+                    + std::mem::size_of::<usize>() * 6
+            }
             (Decimal128(_, _), DataType::Decimal128(_, _)) => 16,
 
             // Variable size types
             (String(s), DataType::Utf8) => s.len() + std::mem::size_of::<usize>() * 3,
-            (Bytes(b), DataType::Binary) => b.len() + std::mem::size_of::<usize>() * 3,
+            (Json(s), DataType::Utf8) => s.len() + std::mem::size_of::<usize>() * 3,
+            (Enum(s) | String(s), DataType::Dictionary(_, _)) => {
+                s.len() + std::mem::size_of::<i32>()
+            }
+            (Bytes(b) | Geometry(b), DataType::Binary) => {
+                b.len() + std::mem::size_of::<usize>() * 3
+            }
             (Bytes(_), DataType::FixedSizeBinary(len)) => *len as usize,
 
             (Decimal256(v, _), DataType::Decimal256(_, _)) => {
@@ -359,9 +564,10 @@ where
 
         // Create RecordBatch
         let batch = RecordBatch::try_new(self.arrow_schema.clone(), arrow_columns)?;
+        self.check_sort_order(&batch)?;
 
         // Write the batch
-        if let Some(writer) = &mut self.arrow_writer {
+        if let Some(writer) = &mut self.file_writer {
             writer.write(&batch)?;
 
             // Check if we need to flush based on memory usage
@@ -401,21 +607,28 @@ where
         // Sort columns to match schema order and convert to arrays
         let mut arrow_columns = Vec::with_capacity(columns.len());
 
-        for field in schema_fields {
+        for (idx, field) in schema_fields.iter().enumerate() {
             let column_data = columns
                 .iter()
                 .find(|(name, _)| name == field.name())
                 .ok_or_else(|| ParquetError::Schema(format!("Missing column: {}", field.name())))?;
 
+            if let Some(geo) = &mut self.geo_metadata {
+                for value in &column_data.1 {
+                    geo.observe(idx, value)?;
+                }
+            }
+
             let array = parquet_values_to_arrow_array(column_data.1.clone(), field)?;
             arrow_columns.push(array);
         }
 
         // Create RecordBatch
         let batch = RecordBatch::try_new(self.arrow_schema.clone(), arrow_columns)?;
+        self.check_sort_order(&batch)?;
 
         // Write the batch
-        if let Some(writer) = &mut self.arrow_writer {
+        if let Some(writer) = &mut self.file_writer {
             writer.write(&batch)?;
         } else {
             return Err(ParquetError::Io(std::io::Error::new(
@@ -427,18 +640,44 @@ where
         Ok(())
     }
 
+    /// Check a batch against the `sorted_by` order when verifying it
+    fn check_sort_order(&mut self, batch: &RecordBatch) -> Result<()> {
+        match &mut self.sort_check {
+            Some(check) => check.check(batch),
+            None => Ok(()),
+        }
+    }
+
     /// Flush any buffered data
     pub fn flush(&mut self) -> Result<()> {
         // First flush any buffered rows
         self.flush_buffered_rows()?;
 
-        // Then flush the arrow writer
-        if let Some(writer) = &mut self.arrow_writer {
+        // Then flush the file writer
+        if let Some(writer) = &mut self.file_writer {
             writer.flush()?;
         }
         Ok(())
     }
 
+    /// Add a key/value pair to the file footer metadata
+    pub fn append_key_value_metadata(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<()> {
+        match &mut self.file_writer {
+            Some(writer) => {
+                writer.append_key_value_metadata(KeyValue::new(key.into(), value.into()));
+                Ok(())
+            }
+            None => Err(ParquetError::Io(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Writer has been closed",
+            ))),
+        }
+    }
+
     /// Close the writer and write the file footer
     ///
     /// This must be called to finalize the Parquet file
@@ -446,8 +685,14 @@ where
         // Flush any remaining buffered rows
         self.flush_buffered_rows()?;
 
-        // Close the arrow writer
-        if let Some(writer) = self.arrow_writer.take() {
+        // GeoParquet readers expect the column summaries in the footer
+        if let Some(geo) = self.geo_metadata.take() {
+            let geo = geo.to_json()?;
+            self.append_key_value_metadata(GEO_METADATA_KEY, geo)?;
+        }
+
+        // Close the file writer
+        if let Some(writer) = self.file_writer.take() {
             writer.close()?;
         }
         Ok(())
@@ -491,7 +736,14 @@ fn validate_value_against_field(value: &ParquetValue, field: &Field, path: &str)
 
         // String and binary
         (String(_), DataType::Utf8) => Ok(()),
+        (Json(_), DataType::Utf8) => Ok(()),
+        (Enum(_) | String(_), DataType::Dictionary(_, value_type))
+            if value_type.as_ref() == &DataType::Utf8 =>
+        {
+            Ok(())
+        }
         (Bytes(_), DataType::Binary) => Ok(()),
+        (Geometry(_), DataType::Binary) => Ok(()),
         (Bytes(_), DataType::FixedSizeBinary(_)) => Ok(()), // Size check done during conversion
 
         // Date/time types
@@ -504,6 +756,16 @@ fn validate_value_against_field(value: &ParquetValue, field: &Field, path: &str)
         (TimestampMillis(_, _), DataType::Timestamp(_, _)) => Ok(()),
         (TimestampMicros(_, _), DataType::Timestamp(_, _)) => Ok(()),
         (TimestampNanos(_, _), DataType::Timestamp(_, _)) => Ok(()),
+        (DurationSecond(_), DataType::Duration(_)) => Ok(()),
+        (DurationMillis(_), DataType::Duration(_)) => Ok(()),
+        (DurationMicros(_), DataType::Duration(_)) => Ok(()),
+        (DurationNanos(_), DataType::Duration(_)) => Ok(()),
+        (Interval(_, _, _), DataType::FixedSizeBinary(12)) => Ok(()),
+
+        // Variants are encoded into their metadata/value group when converted
+        (_, DataType::Struct(_)) if field.extension_type_name() == Some(VARIANT_EXTENSION_NAME) => {
+            Ok(())
+        }
 
         // Decimal types
         (Decimal128(_, _), DataType::Decimal128(_, _)) => Ok(()),
@@ -572,7 +834,7 @@ fn validate_value_against_field(value: &ParquetValue, field: &Field, path: &str)
 }
 
 /// Convert our Schema to Arrow Schema
-fn schema_to_arrow(schema: &Schema) -> Result<Arc<arrow_schema::Schema>> {
+pub(crate) fn schema_to_arrow(schema: &Schema) -> Result<Arc<arrow_schema::Schema>> {
     match &schema.root {
         SchemaNode::Struct { fields, .. } => {
             let arrow_fields = fields
@@ -601,6 +863,31 @@ fn schema_node_to_arrow_field(node: &SchemaNode) -> Result<Field> {
             let field = Field::new(name, data_type, *nullable);
             let extended_field = if format.as_deref() == Some("uuid") {
                 field.with_extension_type(arrow_schema::extension::Uuid)
+            } else if matches!(primitive_type, crate::PrimitiveType::Json) {
+                field.with_extension_type(arrow_schema::extension::Json::default())
+            } else if matches!(primitive_type, crate::PrimitiveType::Enum(_)) {
+                field.with_metadata(std::collections::HashMap::from([(
+                    arrow_schema::extension::EXTENSION_TYPE_NAME_KEY.to_string(),
+                    ENUM_EXTENSION_NAME.to_string(),
+                )]))
+            } else if matches!(primitive_type, crate::PrimitiveType::Interval) {
+                field.with_metadata(std::collections::HashMap::from([(
+                    arrow_schema::extension::EXTENSION_TYPE_NAME_KEY.to_string(),
+                    INTERVAL_EXTENSION_NAME.to_string(),
+                )]))
+            } else if matches!(
+                primitive_type,
+                crate::PrimitiveType::Geometry(_) | crate::PrimitiveType::Geography(_)
+            ) {
+                field.with_metadata(std::collections::HashMap::from([(
+                    arrow_schema::extension::EXTENSION_TYPE_NAME_KEY.to_string(),
+                    GEOMETRY_EXTENSION_NAME.to_string(),
+                )]))
+            } else if matches!(primitive_type, crate::PrimitiveType::Variant) {
+                field.with_metadata(std::collections::HashMap::from([(
+                    arrow_schema::extension::EXTENSION_TYPE_NAME_KEY.to_string(),
+                    VARIANT_EXTENSION_NAME.to_string(),
+                )]))
             } else {
                 field
             };
@@ -612,11 +899,10 @@ fn schema_node_to_arrow_field(node: &SchemaNode) -> Result<Field> {
             nullable,
         } => {
             let item_field = schema_node_to_arrow_field(item)?;
-            let list_type = DataType::List(Arc::new(Field::new(
-                "item",
-                item_field.data_type().clone(),
-                true,
-            )));
+            let list_type = DataType::List(Arc::new(
+                Field::new("item", item_field.data_type().clone(), true)
+                    .with_metadata(item_field.metadata().clone()),
+            ));
             Ok(Field::new(name, list_type, *nullable))
         }
         SchemaNode::Map {
@@ -629,8 +915,10 @@ fn schema_node_to_arrow_field(node: &SchemaNode) -> Result<Field> {
             let value_field = schema_node_to_arrow_field(value)?;
 
             let struct_fields = vec![
-                Field::new("key", key_field.data_type().clone(), false),
-                Field::new("value", value_field.data_type().clone(), true),
+                Field::new("key", key_field.data_type().clone(), false)
+                    .with_metadata(key_field.metadata().clone()),
+                Field::new("value", value_field.data_type().clone(), true)
+                    .with_metadata(value_field.metadata().clone()),
             ];
 
             let map_type = DataType::Map(
@@ -677,6 +965,9 @@ fn primitive_type_to_arrow(ptype: &crate::PrimitiveType) -> Result<DataType> {
         Float32 => DataType::Float32,
         Float64 => DataType::Float64,
         String => DataType::Utf8,
+        Json => DataType::Utf8,
+        // Annotated as a Parquet ENUM by the file writer
+        Enum(_) => DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
         Binary => DataType::Binary,
         Date32 => DataType::Date32,
         TimeMillis => DataType::Time32(arrow_schema::TimeUnit::Millisecond),
@@ -685,14 +976,16 @@ fn primitive_type_to_arrow(ptype: &crate::PrimitiveType) -> Result<DataType> {
         TimestampMillis(tz) => DataType::Timestamp(
             arrow_schema::TimeUnit::Millisecond,
             // PARQUET SPEC: ANY timezone (e.g., "+09:00", "America/New_York") means
-            // UTC-normalized storage (isAdjustedToUTC = true). Original timezone is lost.
-            tz.as_ref().map(|_| Arc::from("UTC")),
+            // UTC-normalized storage (isAdjustedToUTC = true). The zone itself is kept
+            // in the Arrow schema for readers to localise values into.
+            tz.clone(),
         ),
         TimestampMicros(tz) => DataType::Timestamp(
             arrow_schema::TimeUnit::Microsecond,
             // PARQUET SPEC: ANY timezone (e.g., "+09:00", "America/New_York") means
-            // UTC-normalized storage (isAdjustedToUTC = true). Original timezone is lost.
-            tz.as_ref().map(|_| Arc::from("UTC")),
+            // UTC-normalized storage (isAdjustedToUTC = true). The zone itself is kept
+            // in the Arrow schema for readers to localise values into.
+            tz.clone(),
         ),
         Decimal128(precision, scale) => DataType::Decimal128(*precision, *scale),
         Decimal256(precision, scale) => DataType::Decimal256(*precision, *scale),
@@ -700,15 +993,35 @@ fn primitive_type_to_arrow(ptype: &crate::PrimitiveType) -> Result<DataType> {
         TimestampSecond(tz) => DataType::Timestamp(
             arrow_schema::TimeUnit::Second,
             // PARQUET SPEC: ANY timezone (e.g., "+09:00", "America/New_York") means
-            // UTC-normalized storage (isAdjustedToUTC = true). Original timezone is lost.
-            tz.as_ref().map(|_| Arc::from("UTC")),
+            // UTC-normalized storage (isAdjustedToUTC = true). The zone itself is kept
+            // in the Arrow schema for readers to localise values into.
+            tz.clone(),
         ),
         TimestampNanos(tz) => DataType::Timestamp(
             arrow_schema::TimeUnit::Nanosecond,
             // PARQUET SPEC: ANY timezone (e.g., "+09:00", "America/New_York") means
-            // UTC-normalized storage (isAdjustedToUTC = true). Original timezone is lost.
-            tz.as_ref().map(|_| Arc::from("UTC")),
+            // UTC-normalized storage (isAdjustedToUTC = true). The zone itself is kept
+            // in the Arrow schema for readers to localise values into.
+            tz.clone(),
+        ),
+        DurationSecond => DataType::Duration(arrow_schema::TimeUnit::Second),
+        DurationMillis => DataType::Duration(arrow_schema::TimeUnit::Millisecond),
+        DurationMicros => DataType::Duration(arrow_schema::TimeUnit::Microsecond),
+        DurationNanos => DataType::Duration(arrow_schema::TimeUnit::Nanosecond),
+        // Arrow has no month/day/millisecond interval of this layout, so the
+        // raw bytes are written and annotated as INTERVAL by the file writer
+        Interval => DataType::FixedSizeBinary(12),
+        // Annotated as a Parquet VARIANT group by the file writer
+        Variant => DataType::Struct(
+            vec![
+                Field::new("metadata", DataType::Binary, false),
+                Field::new("value", DataType::Binary, false),
+            ]
+            .into(),
         ),
+        // Stored as WKB; the native GEOMETRY/GEOGRAPHY annotation isn't
+        // supported by the Arrow writer, so GeoParquet metadata describes it
+        Geometry(_) | Geography(_) => DataType::Binary,
         FixedLenByteArray(len) => DataType::FixedSizeBinary(*len),
     })
 }
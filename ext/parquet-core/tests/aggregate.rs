@@ -0,0 +1,198 @@
+use bytes::Bytes;
+use ordered_float::OrderedFloat;
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet_core::*;
+use std::ops::Bound;
+use std::sync::Arc;
+
+fn primitive(name: &str, primitive_type: PrimitiveType) -> SchemaNode {
+    SchemaNode::Primitive {
+        name: name.to_string(),
+        primitive_type,
+        nullable: true,
+        format: None,
+    }
+}
+
+fn test_schema() -> Schema {
+    SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("tenant_id", PrimitiveType::Int32),
+                primitive("ts", PrimitiveType::Int64),
+                primitive("name", PrimitiveType::String),
+            ],
+        })
+        .build()
+        .unwrap()
+}
+
+/// Five row groups of ten rows, one tenant each; `ts` is null every third
+/// row and `name` is only set for tenant 2
+fn write_tenants() -> Vec<u8> {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, test_schema()).unwrap();
+        for tenant_id in 0..5 {
+            let rows = (0..10)
+                .map(|i| {
+                    let ts = tenant_id as i64 * 100 + i;
+                    vec![
+                        ParquetValue::Int32(tenant_id),
+                        match i % 3 {
+                            0 => ParquetValue::Null,
+                            _ => ParquetValue::Int64(ts),
+                        },
+                        match tenant_id {
+                            2 => ParquetValue::String(Arc::from(format!("name {}", i))),
+                            _ => ParquetValue::Null,
+                        },
+                    ]
+                })
+                .collect();
+            writer.write_rows(rows).unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+    }
+    buffer
+}
+
+/// Overwrite a row group's column chunk so any attempt to read it fails
+fn damage(file: &mut [u8], row_group: usize, column: usize) {
+    let metadata =
+        ArrowReaderMetadata::load(&Bytes::from(file.to_vec()), ArrowReaderOptions::new()).unwrap();
+    let (start, len) = metadata
+        .metadata()
+        .row_group(row_group)
+        .column(column)
+        .byte_range();
+    for byte in &mut file[start as usize..(start + len) as usize] {
+        *byte = 0xff;
+    }
+}
+
+fn tenants(lower: i32, upper: Bound<i32>) -> RangeFilter {
+    RangeFilter::new(
+        "tenant_id",
+        Bound::Included(ParquetValue::Int32(lower)),
+        upper.map(ParquetValue::Int32),
+    )
+}
+
+#[test]
+fn test_aggregates_without_filters() {
+    let reader = Reader::new(Bytes::from(write_tenants()));
+
+    assert_eq!(reader.count().unwrap(), 50);
+    assert_eq!(reader.min("ts").unwrap(), Some(ParquetValue::Int64(1)));
+    assert_eq!(reader.max("ts").unwrap(), Some(ParquetValue::Int64(408)));
+    assert_eq!(reader.null_count("ts").unwrap(), 20);
+    assert_eq!(reader.null_count("name").unwrap(), 40);
+    assert_eq!(
+        reader.max("name").unwrap(),
+        Some(ParquetValue::String(Arc::from("name 9")))
+    );
+
+    assert!(reader.min("missing").is_err());
+}
+
+#[test]
+fn test_aggregates_from_statistics_alone() {
+    // Every chunk of every column unreadable; the footer must answer
+    let mut file = write_tenants();
+    for row_group in 0..5 {
+        for column in 0..3 {
+            damage(&mut file, row_group, column);
+        }
+    }
+    let file = Bytes::from(file);
+
+    let reader = Reader::new(file.clone()).with_range_filter(tenants(1, Bound::Included(3)));
+    assert_eq!(reader.count().unwrap(), 30);
+    assert_eq!(reader.min("ts").unwrap(), Some(ParquetValue::Int64(101)));
+    assert_eq!(reader.max("ts").unwrap(), Some(ParquetValue::Int64(308)));
+    assert_eq!(reader.null_count("name").unwrap(), 20);
+
+    let reader =
+        Reader::new(file).with_range_filter(RangeFilter::eq("tenant_id", ParquetValue::Int32(9)));
+    assert_eq!(reader.count().unwrap(), 0);
+    assert_eq!(reader.max("ts").unwrap(), None);
+}
+
+#[test]
+fn test_aggregates_scan_undecided_row_groups() {
+    // Row groups outside the range stay unread
+    let mut file = write_tenants();
+    damage(&mut file, 0, 1);
+    damage(&mut file, 4, 1);
+    let file = Bytes::from(file);
+
+    // Row groups 1 and 3 straddle the bounds and row group 2 has null ts
+    let reader = Reader::new(file.clone()).with_range_filter(RangeFilter::new(
+        "ts",
+        Bound::Included(ParquetValue::Int64(105)),
+        Bound::Excluded(ParquetValue::Int64(304)),
+    ));
+    // 105, 107 and 108, the six non null values of row group 2, 301 and 302
+    assert_eq!(reader.count().unwrap(), 3 + 6 + 2);
+    assert_eq!(reader.min("ts").unwrap(), Some(ParquetValue::Int64(105)));
+    assert_eq!(reader.max("ts").unwrap(), Some(ParquetValue::Int64(302)));
+    assert_eq!(
+        reader.min("tenant_id").unwrap(),
+        Some(ParquetValue::Int32(1))
+    );
+    assert_eq!(reader.null_count("name").unwrap(), 5);
+
+    assert!(Reader::new(file).count().is_ok());
+}
+
+#[test]
+fn test_aggregates_scan_float_row_groups() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![primitive("score", PrimitiveType::Float64)],
+        })
+        .build()
+        .unwrap();
+    let score = |v: f64| vec![ParquetValue::Float64(OrderedFloat(v))];
+
+    // Statistics of the first row group are [1, 3], leaving out the NaN
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer
+            .write_rows(vec![score(1.0), score(f64::NAN), score(3.0)])
+            .unwrap();
+        writer.flush().unwrap();
+        writer.write_rows(vec![score(4.0), score(2.0)]).unwrap();
+        writer.close().unwrap();
+    }
+    let file = Bytes::from(buffer);
+
+    // NaN is the largest float, so it's outside the range and the max
+    let reader = Reader::new(file.clone()).with_range_filter(RangeFilter::new(
+        "score",
+        Bound::Unbounded,
+        Bound::Included(ParquetValue::Float64(OrderedFloat(5.0))),
+    ));
+    assert_eq!(reader.count().unwrap(), 4);
+    assert_eq!(
+        reader.max("score").unwrap(),
+        Some(ParquetValue::Float64(OrderedFloat(4.0)))
+    );
+
+    let reader = Reader::new(file);
+    assert!(matches!(
+        reader.max("score").unwrap(),
+        Some(ParquetValue::Float64(max)) if max.is_nan()
+    ));
+    assert_eq!(
+        reader.min("score").unwrap(),
+        Some(ParquetValue::Float64(OrderedFloat(1.0)))
+    );
+}
@@ -463,3 +463,170 @@ fn test_empty_collections() {
     assert!(struct_array.column(0).is_null(0));
     assert!(struct_array.column(1).is_null(0));
 }
+
+#[test]
+fn test_large_and_view_string_binary_layouts() {
+    let parquet_type = Type::primitive_type_builder("col", parquet::basic::Type::BYTE_ARRAY)
+        .build()
+        .unwrap();
+
+    let strings: Vec<(DataType, ArrayRef)> = vec![
+        (
+            DataType::LargeUtf8,
+            Arc::new(LargeStringArray::from(vec![Some("a"), None])),
+        ),
+        (
+            DataType::Utf8View,
+            Arc::new(StringViewArray::from(vec![Some("a"), None])),
+        ),
+    ];
+    for (data_type, array) in strings {
+        let field = Field::new("col", data_type, true);
+        assert_eq!(
+            arrow_to_parquet_value(&field, &parquet_type, array.as_ref(), 0).unwrap(),
+            ParquetValue::String(Arc::from("a"))
+        );
+        assert_eq!(
+            arrow_to_parquet_value(&field, &parquet_type, array.as_ref(), 1).unwrap(),
+            ParquetValue::Null
+        );
+    }
+
+    let binaries: Vec<(DataType, ArrayRef)> = vec![
+        (
+            DataType::LargeBinary,
+            Arc::new(LargeBinaryArray::from(vec![Some(&b"ab"[..]), None])),
+        ),
+        (
+            DataType::BinaryView,
+            Arc::new(BinaryViewArray::from(vec![Some(&b"ab"[..]), None])),
+        ),
+    ];
+    for (data_type, array) in binaries {
+        let field = Field::new("col", data_type, true);
+        assert_eq!(
+            arrow_to_parquet_value(&field, &parquet_type, array.as_ref(), 0).unwrap(),
+            ParquetValue::Bytes(Bytes::from_static(b"ab"))
+        );
+        assert_eq!(
+            arrow_to_parquet_value(&field, &parquet_type, array.as_ref(), 1).unwrap(),
+            ParquetValue::Null
+        );
+    }
+}
+
+#[test]
+fn test_dictionary_array_conversion() {
+    let array: DictionaryArray<arrow_array::types::Int8Type> =
+        vec![Some("red"), Some("green"), None, Some("red")]
+            .into_iter()
+            .collect();
+    let field = Field::new("color", array.data_type().clone(), true);
+    let parquet_type = Type::primitive_type_builder("color", parquet::basic::Type::BYTE_ARRAY)
+        .build()
+        .unwrap();
+
+    let values: Vec<_> = (0..array.len())
+        .map(|i| arrow_to_parquet_value(&field, &parquet_type, &array, i).unwrap())
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            ParquetValue::String(Arc::from("red")),
+            ParquetValue::String(Arc::from("green")),
+            ParquetValue::Null,
+            ParquetValue::String(Arc::from("red")),
+        ]
+    );
+}
+
+#[test]
+fn test_large_and_fixed_size_list_conversion() {
+    let int_type = Type::primitive_type_builder("item", parquet::basic::Type::INT32)
+        .build()
+        .unwrap();
+    let parquet_type = Type::group_type_builder("list")
+        .with_fields(vec![Arc::new(int_type)])
+        .build()
+        .unwrap();
+
+    let large_list =
+        LargeListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>(vec![
+            Some(vec![Some(1), None, Some(3)]),
+            None,
+        ]);
+    let field = Field::new("list", large_list.data_type().clone(), true);
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &large_list, 0).unwrap(),
+        ParquetValue::List(vec![
+            ParquetValue::Int32(1),
+            ParquetValue::Null,
+            ParquetValue::Int32(3),
+        ])
+    );
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &large_list, 1).unwrap(),
+        ParquetValue::Null
+    );
+
+    let fixed_list = FixedSizeListArray::from_iter_primitive::<arrow_array::types::Int32Type, _, _>(
+        vec![Some(vec![Some(4), Some(5)]), Some(vec![Some(6), None])],
+        2,
+    );
+    let field = Field::new("list", fixed_list.data_type().clone(), true);
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &fixed_list, 1).unwrap(),
+        ParquetValue::List(vec![ParquetValue::Int32(6), ParquetValue::Null])
+    );
+}
+
+#[test]
+fn test_duration_interval_and_null_conversion() {
+    let parquet_type = Type::primitive_type_builder("col", parquet::basic::Type::INT64)
+        .build()
+        .unwrap();
+
+    let durations = DurationMillisecondArray::from(vec![Some(1500), None]);
+    let field = Field::new("col", DataType::Duration(TimeUnit::Millisecond), true);
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &durations, 0).unwrap(),
+        ParquetValue::DurationMillis(1500)
+    );
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &durations, 1).unwrap(),
+        ParquetValue::Null
+    );
+
+    let year_month = IntervalYearMonthArray::from(vec![14]);
+    let field = Field::new("col", year_month.data_type().clone(), false);
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &year_month, 0).unwrap(),
+        ParquetValue::Interval(14, 0, 0)
+    );
+
+    let day_time = IntervalDayTimeArray::from(vec![arrow_buffer::IntervalDayTime::new(3, 250)]);
+    let field = Field::new("col", day_time.data_type().clone(), false);
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &day_time, 0).unwrap(),
+        ParquetValue::Interval(0, 3, 250)
+    );
+
+    let month_day_nano = IntervalMonthDayNanoArray::from(vec![
+        arrow_buffer::IntervalMonthDayNano::new(1, 2, 3_000_000),
+        arrow_buffer::IntervalMonthDayNano::new(1, 2, 3_000_999),
+    ]);
+    let field = Field::new("col", month_day_nano.data_type().clone(), false);
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &month_day_nano, 0).unwrap(),
+        ParquetValue::Interval(1, 2, 3)
+    );
+    // Sub-millisecond precision has no Parquet representation
+    assert!(arrow_to_parquet_value(&field, &parquet_type, &month_day_nano, 1).is_err());
+
+    let nulls = NullArray::new(2);
+    let field = Field::new("col", DataType::Null, true);
+    assert_eq!(
+        arrow_to_parquet_value(&field, &parquet_type, &nulls, 1).unwrap(),
+        ParquetValue::Null
+    );
+}
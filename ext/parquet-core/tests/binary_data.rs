@@ -435,3 +435,67 @@ fn test_binary_in_complex_types() {
         assert_eq!(expected, actual);
     }
 }
+
+#[test]
+fn test_geometry_roundtrip_with_geo_metadata() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                SchemaNode::Primitive {
+                    name: "id".to_string(),
+                    primitive_type: PrimitiveType::Int32,
+                    nullable: false,
+                    format: None,
+                },
+                SchemaNode::Primitive {
+                    name: "geom".to_string(),
+                    primitive_type: PrimitiveType::Geometry(Some(Arc::from(
+                        r#"{"id":{"authority":"EPSG","code":4326}}"#,
+                    ))),
+                    nullable: true,
+                    format: None,
+                },
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let wkb =
+        |wkt: &str| ParquetValue::Geometry(Bytes::from(Geometry::from_wkt(wkt).unwrap().to_wkb()));
+    let rows = vec![
+        vec![ParquetValue::Int32(1), wkb("POINT (1 2)")],
+        vec![ParquetValue::Int32(2), wkb("LINESTRING (-3 0, 4 5)")],
+        vec![ParquetValue::Int32(3), ParquetValue::Null],
+    ];
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer.write_rows(rows.clone()).unwrap();
+        writer.close().unwrap();
+    }
+
+    let bytes = Bytes::from(buffer);
+    let mut reader = Reader::new(bytes);
+
+    let metadata = reader.metadata().unwrap();
+    let geo = metadata
+        .key_value_metadata()
+        .and_then(|kv| kv.iter().find(|kv| kv.key == "geo"))
+        .and_then(|kv| kv.value.clone())
+        .expect("geo metadata should be written");
+    assert!(geo.contains(r#""primary_column":"geom""#));
+    assert!(geo.contains(r#""encoding":"WKB""#));
+    assert!(geo.contains(r#""geometry_types":["LineString","Point"]"#));
+    assert!(geo.contains(r#""bbox":[-3.0,0.0,4.0,5.0]"#));
+    assert!(geo.contains(r#""authority":"EPSG""#));
+
+    let read_rows: Vec<_> = reader
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(rows, read_rows);
+}
@@ -819,3 +819,85 @@ fn test_decimal256_complex_type() {
         assert_eq!(expected, actual);
     }
 }
+
+#[test]
+fn test_variant_roundtrip() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                SchemaNode::Primitive {
+                    name: "id".to_string(),
+                    primitive_type: PrimitiveType::Int32,
+                    nullable: false,
+                    format: None,
+                },
+                SchemaNode::Primitive {
+                    name: "payload".to_string(),
+                    primitive_type: PrimitiveType::Variant,
+                    nullable: true,
+                    format: None,
+                },
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let mut event = IndexMap::new();
+    event.insert(Arc::from("kind"), ParquetValue::String(Arc::from("click")));
+    event.insert(
+        Arc::from("position"),
+        ParquetValue::List(vec![ParquetValue::Int8(3), ParquetValue::Int8(4)]),
+    );
+    let event = ParquetValue::Record(event);
+
+    let values = vec![
+        event.clone(),
+        ParquetValue::Float64(OrderedFloat(2.5)),
+        ParquetValue::Null,
+    ];
+
+    let rows = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let payload = match value {
+                ParquetValue::Null => ParquetValue::Null,
+                value => ParquetValue::Variant(Box::new(Variant::from_value(value).unwrap())),
+            };
+            vec![ParquetValue::Int32(i as i32), payload]
+        })
+        .collect::<Vec<_>>();
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer.write_rows(rows).unwrap();
+        writer.close().unwrap();
+    }
+
+    let mut reader = Reader::new(Bytes::from(buffer));
+
+    let metadata = reader.metadata().unwrap();
+    let payload = &metadata.schema_descr().root_schema().get_fields()[1];
+    assert!(matches!(
+        payload.get_basic_info().logical_type(),
+        Some(parquet::basic::LogicalType::Variant { .. })
+    ));
+
+    let read_rows = reader
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(read_rows.len(), values.len());
+    for (row, expected) in read_rows.iter().zip(values.iter()) {
+        let decoded = match &row[1] {
+            ParquetValue::Variant(variant) => variant.decode().unwrap(),
+            other => other.clone(),
+        };
+        assert_eq!(&decoded, expected);
+    }
+}
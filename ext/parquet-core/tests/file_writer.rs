@@ -0,0 +1,140 @@
+use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+use arrow_schema::{DataType, Field, Schema};
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::file::properties::WriterProperties;
+use parquet::format::KeyValue;
+use parquet_core::file_writer::FileWriter;
+use std::sync::Arc;
+
+fn id_batch(ids: std::ops::Range<i32>) -> RecordBatch {
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+    let ids: ArrayRef = Arc::new(Int32Array::from_iter_values(ids));
+    RecordBatch::try_new(schema, vec![ids]).unwrap()
+}
+
+/// Row counts of each row group and the ids read back
+fn read_back(buffer: Vec<u8>) -> (Vec<i64>, Vec<i32>) {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+    let row_groups = builder
+        .metadata()
+        .row_groups()
+        .iter()
+        .map(|row_group| row_group.num_rows())
+        .collect();
+
+    let mut ids = Vec::new();
+    for batch in builder.build().unwrap() {
+        let batch = batch.unwrap();
+        let column = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        ids.extend(column.values().iter().copied());
+    }
+    (row_groups, ids)
+}
+
+#[test]
+fn test_file_writer_splits_row_groups_at_max_size() {
+    let batch = id_batch(0..10);
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(4)
+        .build();
+
+    let mut buffer = Vec::new();
+    let mut writer = FileWriter::try_new(&mut buffer, batch.schema(), props).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let (row_groups, ids) = read_back(buffer);
+    assert_eq!(row_groups, vec![4, 4, 2]);
+    assert_eq!(ids, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_file_writer_flush_ends_row_group() {
+    let mut buffer = Vec::new();
+    let mut writer = FileWriter::try_new(
+        &mut buffer,
+        id_batch(0..0).schema(),
+        WriterProperties::builder().build(),
+    )
+    .unwrap();
+
+    // Empty batches and flushes with nothing in progress don't add row groups
+    writer.write(&id_batch(0..0)).unwrap();
+    writer.flush().unwrap();
+
+    writer.write(&id_batch(0..3)).unwrap();
+    assert!(writer.in_progress_size() > 0);
+    writer.flush().unwrap();
+    assert_eq!(writer.in_progress_size(), 0);
+    writer.write(&id_batch(3..5)).unwrap();
+    writer.close().unwrap();
+
+    let (row_groups, ids) = read_back(buffer);
+    assert_eq!(row_groups, vec![3, 2]);
+    assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_file_writer_footer_metadata() {
+    let batch = id_batch(0..2);
+    let mut buffer = Vec::new();
+    let mut writer = FileWriter::try_new(
+        &mut buffer,
+        batch.schema(),
+        WriterProperties::builder().build(),
+    )
+    .unwrap();
+    writer.write(&batch).unwrap();
+    writer.append_key_value_metadata(KeyValue::new("source".to_string(), "test".to_string()));
+    writer.close().unwrap();
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+    let key_values = builder
+        .metadata()
+        .file_metadata()
+        .key_value_metadata()
+        .unwrap();
+    let value = |key: &str| {
+        key_values
+            .iter()
+            .find(|kv| kv.key == key)
+            .and_then(|kv| kv.value.clone())
+    };
+
+    assert_eq!(value("source").as_deref(), Some("test"));
+    // The Arrow schema is kept like ArrowWriter does, so readers get it back
+    assert!(value("ARROW:schema").is_some());
+    assert_eq!(builder.schema().as_ref(), batch.schema().as_ref());
+}
+
+#[test]
+fn test_file_writer_coerce_types() {
+    let item = Arc::new(Field::new("item", DataType::Int32, true));
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "ids",
+        DataType::List(item),
+        true,
+    )]));
+    let leaf_path = |coerce_types: bool| {
+        let props = WriterProperties::builder()
+            .set_coerce_types(coerce_types)
+            .build();
+        let mut buffer = Vec::new();
+        FileWriter::try_new(&mut buffer, schema.clone(), props)
+            .unwrap()
+            .close()
+            .unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+        builder.parquet_schema().column(0).path().string()
+    };
+
+    // List elements are named as the Parquet spec asks only when coercing
+    assert_eq!(leaf_path(false), "ids.list.item");
+    assert_eq!(leaf_path(true), "ids.list.element");
+}
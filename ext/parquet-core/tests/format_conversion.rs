@@ -0,0 +1,547 @@
+use bytes::Bytes;
+use parquet_core::convert::{
+    arrow_c_array_to_parquet, arrow_c_stream_to_parquet, arrow_ipc_to_parquet, csv_to_parquet,
+    ndjson_to_parquet, parquet_to_arrow_ipc, parquet_to_csv, parquet_to_ndjson, CsvOptions,
+    IpcFormat,
+};
+use parquet_core::*;
+use std::io::Cursor;
+use std::sync::Arc;
+
+fn primitive(name: &str, primitive_type: PrimitiveType, nullable: bool) -> SchemaNode {
+    SchemaNode::Primitive {
+        name: name.to_string(),
+        primitive_type,
+        nullable,
+        format: None,
+    }
+}
+
+fn people_schema() -> Schema {
+    SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("id", PrimitiveType::Int64, false),
+                primitive("name", PrimitiveType::String, true),
+                primitive("score", PrimitiveType::Float64, true),
+            ],
+        })
+        .build()
+        .unwrap()
+}
+
+fn read_all(bytes: Bytes) -> Vec<Vec<ParquetValue>> {
+    Reader::new(bytes)
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap()
+}
+
+#[test]
+fn test_parquet_to_ndjson() {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, people_schema()).unwrap();
+        writer
+            .write_rows(vec![
+                vec![
+                    ParquetValue::Int64(1),
+                    ParquetValue::String(Arc::from("Alice")),
+                    ParquetValue::Float64(1.5.into()),
+                ],
+                vec![
+                    ParquetValue::Int64(2),
+                    ParquetValue::Null,
+                    ParquetValue::Float64(2.0.into()),
+                ],
+            ])
+            .unwrap();
+        writer.close().unwrap();
+    }
+
+    let mut json = Vec::new();
+    let rows =
+        parquet_to_ndjson(Reader::new(Bytes::from(buffer.clone())), &mut json, None).unwrap();
+    assert_eq!(rows, 2);
+    assert_eq!(
+        String::from_utf8(json).unwrap(),
+        "{\"id\":1,\"name\":\"Alice\",\"score\":1.5}\n{\"id\":2,\"score\":2.0}\n"
+    );
+
+    let columns = vec!["name".to_string()];
+    let mut json = Vec::new();
+    parquet_to_ndjson(Reader::new(Bytes::from(buffer)), &mut json, Some(&columns)).unwrap();
+    assert_eq!(
+        String::from_utf8(json).unwrap(),
+        "{\"name\":\"Alice\"}\n{}\n"
+    );
+}
+
+#[test]
+fn test_ndjson_to_parquet_with_inferred_schema() {
+    let json = "{\"id\":1,\"name\":\"Alice\",\"tags\":[\"a\",\"b\"]}\n\
+                {\"id\":2,\"name\":null,\"tags\":[]}\n\
+                {\"id\":3,\"name\":\"Carol\"}\n";
+
+    let mut buffer = Vec::new();
+    let rows =
+        ndjson_to_parquet(Cursor::new(json), &mut buffer, None, WriterBuilder::new()).unwrap();
+    assert_eq!(rows, 3);
+
+    let read = read_all(Bytes::from(buffer));
+    assert_eq!(read.len(), 3);
+    assert_eq!(read[0][0], ParquetValue::Int64(1));
+    assert_eq!(read[0][1], ParquetValue::String(Arc::from("Alice")));
+    assert_eq!(
+        read[0][2],
+        ParquetValue::List(vec![
+            ParquetValue::String(Arc::from("a")),
+            ParquetValue::String(Arc::from("b")),
+        ])
+    );
+    assert_eq!(read[1][1], ParquetValue::Null);
+    assert_eq!(read[2][2], ParquetValue::Null);
+}
+
+#[test]
+fn test_ndjson_to_parquet_with_schema() {
+    let json = "{\"id\":1,\"name\":\"Alice\",\"score\":3}\n{\"id\":2,\"extra\":true}\n";
+
+    let mut buffer = Vec::new();
+    ndjson_to_parquet(
+        Cursor::new(json),
+        &mut buffer,
+        Some(people_schema()),
+        WriterBuilder::new(),
+    )
+    .unwrap();
+
+    let read = read_all(Bytes::from(buffer));
+    assert_eq!(
+        read,
+        vec![
+            vec![
+                ParquetValue::Int64(1),
+                ParquetValue::String(Arc::from("Alice")),
+                ParquetValue::Float64(3.0.into()),
+            ],
+            vec![
+                ParquetValue::Int64(2),
+                ParquetValue::Null,
+                ParquetValue::Null
+            ],
+        ]
+    );
+}
+
+#[test]
+fn test_write_batch_rejects_mismatched_types() {
+    use arrow::record_batch::RecordBatch;
+    use arrow_array::{ArrayRef, Int32Array};
+
+    let mut buffer = Vec::new();
+    let mut writer = Writer::new(&mut buffer, people_schema()).unwrap();
+    let column: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+    let batch = RecordBatch::try_from_iter(vec![
+        ("id", column.clone()),
+        ("name", column.clone()),
+        ("score", column),
+    ])
+    .unwrap();
+
+    assert!(matches!(
+        writer.write_batch(&batch),
+        Err(ParquetError::Schema(_))
+    ));
+}
+
+#[test]
+fn test_csv_to_parquet_with_inferred_schema() {
+    let csv = "id,name,score,joined\n1,Alice,1.5,2024-01-15\n2,,2,2024-02-01\n";
+
+    let mut buffer = Vec::new();
+    let rows = csv_to_parquet(
+        Cursor::new(csv),
+        &mut buffer,
+        None,
+        &CsvOptions::new(),
+        WriterBuilder::new(),
+    )
+    .unwrap();
+    assert_eq!(rows, 2);
+
+    let read = read_all(Bytes::from(buffer));
+    assert_eq!(read[0][0], ParquetValue::Int64(1));
+    assert_eq!(read[0][1], ParquetValue::String(Arc::from("Alice")));
+    assert_eq!(read[0][2], ParquetValue::Float64(1.5.into()));
+    assert_eq!(read[0][3], ParquetValue::Date32(19737));
+    assert_eq!(read[1][1], ParquetValue::Null);
+    assert_eq!(read[1][2], ParquetValue::Float64(2.0.into()));
+}
+
+#[test]
+fn test_csv_to_parquet_with_schema_and_formats() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("id", PrimitiveType::Int32, false),
+                SchemaNode::Primitive {
+                    name: "joined".to_string(),
+                    primitive_type: PrimitiveType::Date32,
+                    nullable: true,
+                    format: Some("%d/%m/%Y".to_string()),
+                },
+                primitive("seen_at", PrimitiveType::TimestampMillis(None), true),
+            ],
+        })
+        .build()
+        .unwrap();
+    let csv = "1;15/01/2024;2024-01-15 10:30\n2;NA;NA\n";
+    let options = CsvOptions::new()
+        .with_header(false)
+        .with_delimiter(b';')
+        .with_null_values(vec!["NA".to_string()])
+        .with_timestamp_format("%Y-%m-%d %H:%M");
+
+    let mut buffer = Vec::new();
+    csv_to_parquet(
+        Cursor::new(csv),
+        &mut buffer,
+        Some(schema),
+        &options,
+        WriterBuilder::new(),
+    )
+    .unwrap();
+
+    let read = read_all(Bytes::from(buffer));
+    assert_eq!(read[0][0], ParquetValue::Int32(1));
+    assert_eq!(read[0][1], ParquetValue::Date32(19737));
+    assert!(matches!(
+        read[0][2],
+        ParquetValue::TimestampMillis(1_705_314_600_000, _)
+    ));
+    assert_eq!(read[1][1], ParquetValue::Null);
+    assert_eq!(read[1][2], ParquetValue::Null);
+}
+
+#[test]
+fn test_csv_to_parquet_reports_unparseable_dates() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![primitive("joined", PrimitiveType::Date32, true)],
+        })
+        .build()
+        .unwrap();
+    let options = CsvOptions::new().with_date_format("%d/%m/%Y");
+
+    let mut buffer = Vec::new();
+    let result = csv_to_parquet(
+        Cursor::new("joined\n2024-01-15\n"),
+        &mut buffer,
+        Some(schema),
+        &options,
+        WriterBuilder::new(),
+    );
+    assert!(matches!(result, Err(ParquetError::Conversion(_))));
+}
+
+#[test]
+fn test_parquet_to_csv() {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, people_schema()).unwrap();
+        writer
+            .write_rows(vec![
+                vec![
+                    ParquetValue::Int64(1),
+                    ParquetValue::String(Arc::from("Alice, B.")),
+                    ParquetValue::Float64(1.5.into()),
+                ],
+                vec![
+                    ParquetValue::Int64(2),
+                    ParquetValue::Null,
+                    ParquetValue::Float64(2.0.into()),
+                ],
+            ])
+            .unwrap();
+        writer.close().unwrap();
+    }
+
+    let mut csv = Vec::new();
+    let rows = parquet_to_csv(
+        Reader::new(Bytes::from(buffer.clone())),
+        &mut csv,
+        None,
+        &CsvOptions::new(),
+    )
+    .unwrap();
+    assert_eq!(rows, 2);
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "id,name,score\n1,\"Alice, B.\",1.5\n2,,2.0\n"
+    );
+
+    let options = CsvOptions::new()
+        .with_header(false)
+        .with_delimiter(b'\t')
+        .with_null_values(vec!["NULL".to_string()]);
+    let columns = vec!["id".to_string(), "name".to_string()];
+    let mut csv = Vec::new();
+    parquet_to_csv(
+        Reader::new(Bytes::from(buffer)),
+        &mut csv,
+        Some(&columns),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(csv).unwrap(), "1\tAlice, B.\n2\tNULL\n");
+}
+
+#[test]
+fn test_arrow_ipc_roundtrip() {
+    let rows = vec![
+        vec![
+            ParquetValue::Int64(1),
+            ParquetValue::String(Arc::from("Alice")),
+            ParquetValue::Float64(1.5.into()),
+        ],
+        vec![
+            ParquetValue::Int64(2),
+            ParquetValue::Null,
+            ParquetValue::Null,
+        ],
+    ];
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, people_schema()).unwrap();
+        writer.write_rows(rows.clone()).unwrap();
+        writer.close().unwrap();
+    }
+
+    for format in [IpcFormat::File, IpcFormat::Stream] {
+        let mut ipc = Vec::new();
+        let written = parquet_to_arrow_ipc(
+            Reader::new(Bytes::from(buffer.clone())),
+            &mut ipc,
+            None,
+            format,
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(ipc.starts_with(b"ARROW1"), format == IpcFormat::File);
+
+        let mut copy = Vec::new();
+        let read =
+            arrow_ipc_to_parquet(Cursor::new(ipc), &mut copy, None, WriterBuilder::new()).unwrap();
+        assert_eq!(read, 2);
+        assert_eq!(read_all(Bytes::from(copy)), rows);
+    }
+}
+
+#[test]
+fn test_parquet_to_arrow_ipc_columns() {
+    use arrow::ipc::reader::FileReader;
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, people_schema()).unwrap();
+        writer
+            .write_rows(vec![vec![
+                ParquetValue::Int64(1),
+                ParquetValue::String(Arc::from("Alice")),
+                ParquetValue::Float64(1.5.into()),
+            ]])
+            .unwrap();
+        writer.close().unwrap();
+    }
+
+    let columns = vec!["name".to_string()];
+    let mut ipc = Vec::new();
+    parquet_to_arrow_ipc(
+        Reader::new(Bytes::from(buffer)),
+        &mut ipc,
+        Some(&columns),
+        IpcFormat::File,
+    )
+    .unwrap();
+
+    let reader = FileReader::try_new(Cursor::new(ipc), None).unwrap();
+    let schema = reader.schema();
+    assert_eq!(schema.fields().len(), 1);
+    assert_eq!(schema.field(0).name(), "name");
+    assert_eq!(reader.map(|b| b.unwrap().num_rows()).sum::<usize>(), 1);
+}
+
+#[test]
+fn test_arrow_c_stream_to_parquet() {
+    use arrow::ffi_stream::FFI_ArrowArrayStream;
+    use arrow::record_batch::{RecordBatch, RecordBatchIterator};
+    use arrow_array::{Int64Array, StringArray};
+    use arrow_schema::{DataType, Field, Schema as ArrowSchema};
+
+    let arrow_schema = Arc::new(ArrowSchema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    let batches = [vec![1, 2], vec![3]]
+        .into_iter()
+        .map(|ids: Vec<i64>| {
+            let names: StringArray = ids
+                .iter()
+                .map(|id| (id % 2 == 1).then(|| format!("name {}", id)))
+                .collect();
+            RecordBatch::try_new(
+                arrow_schema.clone(),
+                vec![Arc::new(Int64Array::from(ids)), Arc::new(names)],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut stream =
+        FFI_ArrowArrayStream::new(Box::new(RecordBatchIterator::new(batches, arrow_schema)));
+    let mut buffer = Vec::new();
+    let rows =
+        unsafe { arrow_c_stream_to_parquet(&mut stream, &mut buffer, None, WriterBuilder::new()) }
+            .unwrap();
+    assert_eq!(rows, 3);
+
+    assert_eq!(
+        read_all(Bytes::from(buffer)),
+        vec![
+            vec![
+                ParquetValue::Int64(1),
+                ParquetValue::String(Arc::from("name 1"))
+            ],
+            vec![ParquetValue::Int64(2), ParquetValue::Null],
+            vec![
+                ParquetValue::Int64(3),
+                ParquetValue::String(Arc::from("name 3"))
+            ],
+        ]
+    );
+}
+
+#[test]
+fn test_arrow_c_array_with_null_rows() {
+    use arrow::ffi::to_ffi;
+    use arrow_array::{Array, Int64Array, StructArray};
+    use arrow_buffer::NullBuffer;
+    use arrow_schema::{DataType, Field};
+
+    let ids = Arc::new(Int64Array::from(vec![1, 2])) as arrow_array::ArrayRef;
+    let fields = vec![Field::new("id", DataType::Int64, false)];
+    let columns = StructArray::new(
+        fields.into(),
+        vec![ids],
+        Some(NullBuffer::from(vec![true, false])),
+    );
+    let (mut array, mut array_schema) = to_ffi(&columns.to_data()).unwrap();
+
+    let result = unsafe {
+        arrow_c_array_to_parquet(
+            &mut array,
+            &mut array_schema,
+            Vec::new(),
+            None,
+            WriterBuilder::new(),
+        )
+    };
+    assert!(matches!(result, Err(ParquetError::Schema(_))));
+}
+
+#[test]
+fn test_arrow_ipc_to_parquet_with_schema() {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, people_schema()).unwrap();
+        writer
+            .write_rows(vec![vec![
+                ParquetValue::Int64(1),
+                ParquetValue::String(Arc::from("Alice")),
+                ParquetValue::Float64(1.5.into()),
+            ]])
+            .unwrap();
+        writer.close().unwrap();
+    }
+    let mut ipc = Vec::new();
+    parquet_to_arrow_ipc(
+        Reader::new(Bytes::from(buffer)),
+        &mut ipc,
+        None,
+        IpcFormat::Stream,
+    )
+    .unwrap();
+
+    // Columns are matched by name and cast to the given schema
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("score", PrimitiveType::Float32, true),
+                primitive("id", PrimitiveType::Int32, false),
+                primitive("name", PrimitiveType::String, true),
+            ],
+        })
+        .build()
+        .unwrap();
+    let mut copy = Vec::new();
+    arrow_ipc_to_parquet(
+        Cursor::new(ipc.clone()),
+        &mut copy,
+        Some(schema),
+        WriterBuilder::new(),
+    )
+    .unwrap();
+    assert_eq!(
+        read_all(Bytes::from(copy)),
+        vec![vec![
+            ParquetValue::Float32(1.5.into()),
+            ParquetValue::Int32(1),
+            ParquetValue::String(Arc::from("Alice")),
+        ]]
+    );
+
+    let renamed = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("id", PrimitiveType::Int64, false),
+                primitive("full_name", PrimitiveType::String, true),
+                primitive("score", PrimitiveType::Float64, true),
+            ],
+        })
+        .build()
+        .unwrap();
+    let result = arrow_ipc_to_parquet(
+        Cursor::new(ipc.clone()),
+        Vec::new(),
+        Some(renamed),
+        WriterBuilder::new(),
+    );
+    assert!(matches!(result, Err(ParquetError::Schema(_))));
+
+    let too_few = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![primitive("id", PrimitiveType::Int64, false)],
+        })
+        .build()
+        .unwrap();
+    let result = arrow_ipc_to_parquet(
+        Cursor::new(ipc),
+        Vec::new(),
+        Some(too_few),
+        WriterBuilder::new(),
+    );
+    assert!(result.is_err());
+}
@@ -215,6 +215,367 @@ fn test_string_roundtrip() {
     }
 }
 
+#[test]
+fn test_json_roundtrip() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![SchemaNode::Primitive {
+                name: "payload".to_string(),
+                primitive_type: PrimitiveType::Json,
+                nullable: true,
+                format: None,
+            }],
+        })
+        .build()
+        .unwrap();
+
+    let rows = vec![
+        vec![ParquetValue::Json(Arc::from(r#"{"a":1,"b":[true,null]}"#))],
+        vec![ParquetValue::Json(Arc::from("[1,2,3]"))],
+        vec![ParquetValue::Null],
+    ];
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer.write_rows(rows.clone()).unwrap();
+        writer.close().unwrap();
+    }
+
+    // Read back and verify
+    let bytes = Bytes::from(buffer);
+    let mut reader = Reader::new(bytes);
+
+    let metadata = reader.metadata().unwrap();
+    assert_eq!(
+        metadata.schema_descr().column(0).logical_type(),
+        Some(parquet::basic::LogicalType::Json)
+    );
+
+    let read_rows: Vec<_> = reader
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(rows, read_rows);
+}
+
+#[test]
+fn test_enum_roundtrip() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![SchemaNode::Primitive {
+                name: "status".to_string(),
+                primitive_type: PrimitiveType::Enum(None),
+                nullable: true,
+                format: None,
+            }],
+        })
+        .build()
+        .unwrap();
+
+    let rows = vec![
+        vec![ParquetValue::Enum(Arc::from("active"))],
+        vec![ParquetValue::Enum(Arc::from("inactive"))],
+        vec![ParquetValue::Null],
+        vec![ParquetValue::Enum(Arc::from("active"))],
+    ];
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer.write_rows(rows.clone()).unwrap();
+        writer.close().unwrap();
+    }
+
+    let bytes = Bytes::from(buffer);
+    let mut reader = Reader::new(bytes);
+
+    let metadata = reader.metadata().unwrap();
+    assert_eq!(
+        metadata.schema_descr().column(0).logical_type(),
+        Some(parquet::basic::LogicalType::Enum)
+    );
+    assert_eq!(
+        metadata.schema_descr().column(0).converted_type(),
+        parquet::basic::ConvertedType::ENUM
+    );
+
+    let read_rows: Vec<_> = reader
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(rows, read_rows);
+}
+
+#[test]
+fn test_read_parquet_enum_annotation() {
+    use parquet::data_type::{ByteArray, ByteArrayType};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+
+    // Written like other Parquet writers do, with no Arrow schema in the footer
+    let schema = parse_message_type("message root { optional binary status (ENUM); }").unwrap();
+    let mut buffer = Vec::new();
+    {
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer = SerializedFileWriter::new(&mut buffer, Arc::new(schema), props).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut column = row_group.next_column().unwrap().unwrap();
+        column
+            .typed::<ByteArrayType>()
+            .write_batch(
+                &[ByteArray::from("active"), ByteArray::from("inactive")],
+                Some(&[1, 0, 1]),
+                None,
+            )
+            .unwrap();
+        column.close().unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+    }
+
+    let reader = Reader::new(Bytes::from(buffer));
+    let read_rows: Vec<_> = reader
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        read_rows,
+        vec![
+            vec![ParquetValue::Enum(Arc::from("active"))],
+            vec![ParquetValue::Null],
+            vec![ParquetValue::Enum(Arc::from("inactive"))],
+        ]
+    );
+}
+
+#[test]
+fn test_string_dictionary_columns() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                SchemaNode::Primitive {
+                    name: "city".to_string(),
+                    primitive_type: PrimitiveType::String,
+                    nullable: true,
+                    format: None,
+                },
+                SchemaNode::Primitive {
+                    name: "id".to_string(),
+                    primitive_type: PrimitiveType::Int64,
+                    nullable: false,
+                    format: None,
+                },
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let cities = ["Lisbon", "Oslo", "Lima"];
+    let rows: Vec<Vec<ParquetValue>> = (0..300)
+        .map(|i| {
+            let city = match i % 4 {
+                3 => ParquetValue::Null,
+                n => ParquetValue::String(Arc::from(cities[n])),
+            };
+            vec![city, ParquetValue::Int64(i)]
+        })
+        .collect();
+
+    let write = |props: Option<parquet::file::properties::WriterProperties>| {
+        let mut buffer = Vec::new();
+        {
+            let mut writer = match props {
+                Some(props) => Writer::new_with_properties(&mut buffer, schema.clone(), props),
+                None => Writer::new(&mut buffer, schema.clone()),
+            }
+            .unwrap();
+            writer.write_rows(rows.clone()).unwrap();
+            writer.close().unwrap();
+        }
+        Bytes::from(buffer)
+    };
+
+    let bytes = write(None);
+    let reader = Reader::new(bytes.clone());
+    assert_eq!(reader.dictionary_encoded_columns().unwrap(), vec!["city"]);
+
+    let read_rows: Vec<_> = reader
+        .with_dictionary_columns(vec!["city".to_string()])
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(rows, read_rows);
+
+    // Rows with the same city share the decoded dictionary entry
+    match (&read_rows[0][0], &read_rows[4][0]) {
+        (ParquetValue::String(a), ParquetValue::String(b)) => assert!(Arc::ptr_eq(a, b)),
+        other => panic!("expected strings, got {:?}", other),
+    }
+
+    for column in ["id", "missing"] {
+        let result = Reader::new(bytes.clone())
+            .with_dictionary_columns(vec![column.to_string()])
+            .read_rows();
+        assert!(matches!(result, Err(ParquetError::InvalidArgument(_))));
+    }
+
+    let plain = write(Some(
+        parquet::file::properties::WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .build(),
+    ));
+    assert!(Reader::new(plain.clone())
+        .dictionary_encoded_columns()
+        .unwrap()
+        .is_empty());
+
+    // Plain encoded columns can still be read as dictionaries
+    let read_rows: Vec<_> = Reader::new(plain)
+        .with_dictionary_columns(vec!["city".to_string()])
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(rows, read_rows);
+}
+
+#[test]
+fn test_duration_and_interval_roundtrip() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                SchemaNode::Primitive {
+                    name: "elapsed".to_string(),
+                    primitive_type: PrimitiveType::DurationMicros,
+                    nullable: true,
+                    format: None,
+                },
+                SchemaNode::Primitive {
+                    name: "window".to_string(),
+                    primitive_type: PrimitiveType::Interval,
+                    nullable: true,
+                    format: None,
+                },
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let rows = vec![
+        vec![
+            ParquetValue::DurationMicros(1_500_000),
+            ParquetValue::Interval(1, 2, 500),
+        ],
+        vec![
+            ParquetValue::DurationMicros(-42),
+            ParquetValue::Interval(0, -7, i32::MAX),
+        ],
+        vec![ParquetValue::Null, ParquetValue::Null],
+    ];
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer.write_rows(rows.clone()).unwrap();
+        writer.close().unwrap();
+    }
+
+    let bytes = Bytes::from(buffer);
+    let mut reader = Reader::new(bytes);
+
+    let metadata = reader.metadata().unwrap();
+    assert_eq!(
+        metadata.schema_descr().column(0).physical_type(),
+        parquet::basic::Type::INT64
+    );
+    assert_eq!(
+        metadata.schema_descr().column(1).converted_type(),
+        parquet::basic::ConvertedType::INTERVAL
+    );
+
+    let read_rows: Vec<_> = reader
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(rows, read_rows);
+}
+
+#[test]
+fn test_read_parquet_interval_annotation() {
+    use parquet::data_type::{FixedLenByteArray, FixedLenByteArrayType};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+
+    // Months, days and milliseconds as little-endian u32s
+    let interval = |months: u32, days: u32, millis: u32| {
+        let bytes = [months, days, millis]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<_>>();
+        FixedLenByteArray::from(bytes)
+    };
+
+    // Written with no Arrow schema, so only the INTERVAL annotation is there
+    let schema =
+        parse_message_type("message root { optional fixed_len_byte_array(12) window (INTERVAL); }")
+            .unwrap();
+    let mut buffer = Vec::new();
+    {
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer = SerializedFileWriter::new(&mut buffer, Arc::new(schema), props).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut column = row_group.next_column().unwrap().unwrap();
+        column
+            .typed::<FixedLenByteArrayType>()
+            .write_batch(
+                &[interval(1, 2, 500), interval(14, 0, 0)],
+                Some(&[1, 0, 1]),
+                None,
+            )
+            .unwrap();
+        column.close().unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+    }
+
+    let reader = Reader::new(Bytes::from(buffer));
+    let read_rows: Vec<_> = reader
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    // The months survive, unlike the Arrow reader's day/time interval
+    assert_eq!(
+        read_rows,
+        vec![
+            vec![ParquetValue::Interval(1, 2, 500)],
+            vec![ParquetValue::Null],
+            vec![ParquetValue::Interval(14, 0, 0)],
+        ]
+    );
+}
+
 // =============================================================================
 // Numeric Type Tests
 // =============================================================================
@@ -0,0 +1,205 @@
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use parquet_core::salvage::salvage;
+use parquet_core::*;
+use std::sync::Arc;
+
+fn primitive(name: &str, primitive_type: PrimitiveType, nullable: bool) -> SchemaNode {
+    SchemaNode::Primitive {
+        name: name.to_string(),
+        primitive_type,
+        nullable,
+        format: None,
+    }
+}
+
+fn test_schema() -> Schema {
+    SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("id", PrimitiveType::Int64, false),
+                primitive("name", PrimitiveType::String, true),
+                primitive("active", PrimitiveType::Boolean, false),
+            ],
+        })
+        .build()
+        .unwrap()
+}
+
+fn row(id: i64) -> Vec<ParquetValue> {
+    vec![
+        ParquetValue::Int64(id),
+        if id % 5 == 0 {
+            ParquetValue::Null
+        } else {
+            ParquetValue::String(Arc::from(format!("name {}", id)))
+        },
+        ParquetValue::Boolean(id % 2 == 0),
+    ]
+}
+
+/// Three row groups of 100 rows each
+fn write_test_file(compression: Compression) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = WriterBuilder::new()
+            .with_compression(compression)
+            .build(&mut buffer, test_schema())
+            .unwrap();
+        for start in [0, 100, 200] {
+            writer
+                .write_rows((start..start + 100).map(row).collect())
+                .unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+    }
+    buffer
+}
+
+/// Offset where the footer of a complete file starts
+fn footer_start(file: &[u8]) -> usize {
+    let len = &file[file.len() - 8..file.len() - 4];
+    file.len() - 8 - u32::from_le_bytes(len.try_into().unwrap()) as usize
+}
+
+fn read_ids(rows: impl Iterator<Item = Result<Vec<ParquetValue>>>) -> Vec<i64> {
+    rows.map(|row| match row.unwrap()[0] {
+        ParquetValue::Int64(id) => id,
+        ref other => panic!("unexpected id {:?}", other),
+    })
+    .collect()
+}
+
+#[test]
+fn test_salvage_file_without_footer() {
+    for compression in [
+        Compression::UNCOMPRESSED,
+        Compression::SNAPPY,
+        Compression::ZSTD(Default::default()),
+    ] {
+        let mut file = write_test_file(compression);
+        file.truncate(footer_start(&file));
+
+        let schema = SalvageSchema::from_schema(&test_schema()).unwrap();
+        let salvaged = salvage(Bytes::from(file), &schema).unwrap();
+
+        let report = salvaged.report().clone();
+        assert_eq!(report.row_groups, 3, "{:?}", report.problems);
+        assert_eq!(report.num_rows, 300);
+
+        let rows = salvaged
+            .read_rows()
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rows.len(), 300);
+        assert_eq!(rows[7], row(7));
+        assert_eq!(rows[250], row(250));
+    }
+}
+
+#[test]
+fn test_salvage_file_without_dictionary_pages() {
+    // Nothing marks where the first column chunk of a row group ends
+    let props = WriterProperties::builder()
+        .set_dictionary_enabled(false)
+        .build();
+    let mut file = Vec::new();
+    {
+        let mut writer = Writer::new_with_properties(&mut file, test_schema(), props).unwrap();
+        for start in [0, 100, 200] {
+            writer
+                .write_rows((start..start + 100).map(row).collect())
+                .unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+    }
+    file.truncate(footer_start(&file));
+
+    let schema = SalvageSchema::from_schema(&test_schema()).unwrap();
+    let salvaged = salvage(Bytes::from(file), &schema).unwrap();
+
+    let report = salvaged.report().clone();
+    assert_eq!(report.row_groups, 3, "{:?}", report.problems);
+    assert_eq!(report.num_rows, 300);
+
+    let ids = read_ids(salvaged.read_rows().unwrap());
+    assert_eq!(ids, (0..300).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_salvage_drops_truncated_row_group() {
+    let original = write_test_file(Compression::SNAPPY);
+    let metadata =
+        ArrowReaderMetadata::load(&Bytes::from(original.clone()), ArrowReaderOptions::new())
+            .unwrap();
+    let (start, len) = metadata.metadata().row_group(2).column(1).byte_range();
+
+    let mut file = original.clone();
+    file.truncate((start + len / 2) as usize);
+
+    // The schema comes from a complete file written the same way
+    let schema = SalvageSchema::from_file(&Bytes::from(original)).unwrap();
+    let salvaged = salvage(Bytes::from(file), &schema).unwrap();
+
+    assert_eq!(salvaged.report().row_groups, 2);
+    assert!(!salvaged.report().problems.is_empty());
+
+    let mut repaired = Vec::new();
+    let rows = salvaged.write(&mut repaired, WriterBuilder::new()).unwrap();
+    assert_eq!(rows, 200);
+
+    let ids = read_ids(Reader::new(Bytes::from(repaired)).read_rows().unwrap());
+    assert_eq!(ids, (0..200).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_salvage_rejects_non_parquet_input() {
+    let schema = SalvageSchema::from_schema(&test_schema()).unwrap();
+    assert!(salvage(Bytes::from_static(b"id,name\n1,a\n"), &schema).is_err());
+}
+
+#[test]
+fn test_skip_corrupt_row_groups() {
+    let mut file = write_test_file(Compression::SNAPPY);
+    let metadata =
+        ArrowReaderMetadata::load(&Bytes::from(file.clone()), ArrowReaderOptions::new()).unwrap();
+    let start = metadata.metadata().row_group(1).column(0).byte_range().0 as usize;
+    for byte in &mut file[start..start + 4] {
+        *byte = 0xff;
+    }
+
+    // Without skipping the read fails at the damaged row group
+    let rows = Reader::new(Bytes::from(file.clone())).read_rows().unwrap();
+    assert!(rows.collect::<Result<Vec<_>>>().is_err());
+
+    let mut rows = Reader::new(Bytes::from(file.clone()))
+        .with_skip_corrupt_row_groups(true)
+        .read_rows()
+        .unwrap();
+    let ids = read_ids(rows.by_ref());
+    assert_eq!(ids, (0..100).chain(200..300).collect::<Vec<_>>());
+
+    let skipped = rows.take_skipped_row_groups();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(skipped[0].index, 1);
+    assert_eq!(skipped[0].num_rows, 100);
+    assert!(rows.take_skipped_row_groups().is_empty());
+
+    let mut columns = Reader::new(Bytes::from(file))
+        .with_skip_corrupt_row_groups(true)
+        .read_columns(None)
+        .unwrap();
+    let values: usize = columns
+        .by_ref()
+        .map(|batch| batch.unwrap().columns[0].1.len())
+        .sum();
+    assert_eq!(values, 200);
+    assert_eq!(columns.take_skipped_row_groups().len(), 1);
+}
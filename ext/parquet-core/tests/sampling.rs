@@ -0,0 +1,111 @@
+use bytes::Bytes;
+use parquet_core::*;
+use std::ops::Bound;
+
+fn test_schema() -> Schema {
+    SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![SchemaNode::Primitive {
+                name: "id".to_string(),
+                primitive_type: PrimitiveType::Int64,
+                nullable: false,
+                format: None,
+            }],
+        })
+        .build()
+        .unwrap()
+}
+
+/// Ids 0 to 999 in ten row groups
+fn write_ids() -> Bytes {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, test_schema()).unwrap();
+        for start in (0..1000).step_by(100) {
+            writer
+                .write_rows(
+                    (start..start + 100)
+                        .map(|id| vec![ParquetValue::Int64(id)])
+                        .collect(),
+                )
+                .unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+    }
+    Bytes::from(buffer)
+}
+
+fn ids(reader: Reader<Bytes>) -> Vec<i64> {
+    reader
+        .read_rows()
+        .unwrap()
+        .map(|row| match row.unwrap()[0] {
+            ParquetValue::Int64(id) => id,
+            ref other => panic!("unexpected id {:?}", other),
+        })
+        .collect()
+}
+
+#[test]
+fn test_sample_rows() {
+    let file = write_ids();
+
+    let sample = ids(Reader::new(file.clone()).with_sample(Sample::Rows(50), Some(42)));
+    assert_eq!(sample.len(), 50);
+    // Rows come back in file order, without repeats
+    assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // The same seed picks the same rows, through either read path
+    assert_eq!(
+        sample,
+        ids(Reader::new(file.clone()).with_sample(Sample::Rows(50), Some(42)))
+    );
+    assert_eq!(
+        sample,
+        ids(Reader::new(file.clone())
+            .with_sample(Sample::Rows(50), Some(42))
+            .with_skip_corrupt_row_groups(true))
+    );
+
+    let all = ids(Reader::new(file).with_sample(Sample::Rows(5000), None));
+    assert_eq!(all, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_sample_rate() {
+    let file = write_ids();
+
+    let sample = ids(Reader::new(file.clone()).with_sample(Sample::Rate(0.2), Some(3)));
+    assert!((120..280).contains(&sample.len()), "{}", sample.len());
+
+    let batches = Reader::new(file.clone())
+        .with_sample(Sample::Rate(0.2), Some(3))
+        .read_columns(None)
+        .unwrap()
+        .map(|batch| batch.unwrap().columns[0].1.len())
+        .sum::<usize>();
+    assert_eq!(batches, sample.len());
+
+    let result = Reader::new(file)
+        .with_sample(Sample::Rate(-0.5), None)
+        .read_rows();
+    assert!(matches!(result, Err(ParquetError::InvalidArgument(_))));
+}
+
+#[test]
+fn test_sample_with_range_filter() {
+    let sample = ids(Reader::new(write_ids())
+        .with_range_filter(RangeFilter::new(
+            "id",
+            Bound::Included(ParquetValue::Int64(250)),
+            Bound::Excluded(ParquetValue::Int64(500)),
+        ))
+        .with_sample(Sample::Rows(100), Some(9)));
+
+    // Drawn from the three row groups overlapping the range, then filtered
+    assert!(!sample.is_empty() && sample.len() <= 100);
+    assert!(sample.iter().all(|id| (250..500).contains(id)));
+}
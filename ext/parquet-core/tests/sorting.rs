@@ -0,0 +1,332 @@
+use bytes::Bytes;
+use ordered_float::OrderedFloat;
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet_core::*;
+use std::ops::Bound;
+use std::sync::Arc;
+
+fn primitive(name: &str, primitive_type: PrimitiveType, nullable: bool) -> SchemaNode {
+    SchemaNode::Primitive {
+        name: name.to_string(),
+        primitive_type,
+        nullable,
+        format: None,
+    }
+}
+
+fn test_schema() -> Schema {
+    SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("tenant_id", PrimitiveType::Int32, false),
+                primitive("ts", PrimitiveType::Int64, true),
+                primitive("name", PrimitiveType::String, true),
+            ],
+        })
+        .build()
+        .unwrap()
+}
+
+fn row(tenant_id: i32, ts: Option<i64>) -> Vec<ParquetValue> {
+    vec![
+        ParquetValue::Int32(tenant_id),
+        ts.map(ParquetValue::Int64).unwrap_or(ParquetValue::Null),
+        ParquetValue::String(Arc::from(format!("tenant {}", tenant_id))),
+    ]
+}
+
+/// Ten row groups of ten rows, one tenant per row group
+fn write_sorted(tenants: impl Iterator<Item = i32>, builder: WriterBuilder) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = builder.build(&mut buffer, test_schema()).unwrap();
+        for tenant_id in tenants {
+            writer
+                .write_rows((0..10).rev().map(|ts| row(tenant_id, Some(ts))).collect())
+                .unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+    }
+    buffer
+}
+
+fn sorted_builder() -> WriterBuilder {
+    WriterBuilder::new().with_sorted_by(vec![
+        SortColumn::new("tenant_id"),
+        SortColumn::new("ts")
+            .with_descending(true)
+            .with_nulls_first(true),
+    ])
+}
+
+fn tenants(rows: impl Iterator<Item = Result<Vec<ParquetValue>>>) -> Vec<i32> {
+    rows.map(|row| match row.unwrap()[0] {
+        ParquetValue::Int32(tenant_id) => tenant_id,
+        ref other => panic!("unexpected tenant {:?}", other),
+    })
+    .collect()
+}
+
+#[test]
+fn test_sorting_columns_written() {
+    let file = write_sorted(0..10, sorted_builder());
+    let metadata =
+        ArrowReaderMetadata::load(&Bytes::from(file), ArrowReaderOptions::new()).unwrap();
+
+    for row_group in metadata.metadata().row_groups() {
+        let sorting = row_group.sorting_columns().unwrap();
+        assert_eq!(sorting.len(), 2);
+        assert_eq!(
+            (
+                sorting[0].column_idx,
+                sorting[0].descending,
+                sorting[0].nulls_first
+            ),
+            (0, false, false)
+        );
+        assert_eq!(
+            (
+                sorting[1].column_idx,
+                sorting[1].descending,
+                sorting[1].nulls_first
+            ),
+            (1, true, true)
+        );
+    }
+}
+
+#[test]
+fn test_sorted_by_rejects_unknown_columns() {
+    let result = WriterBuilder::new()
+        .with_sorted_by(vec![SortColumn::new("missing")])
+        .build(Vec::new(), test_schema());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_verify_sorted() {
+    let builder = || sorted_builder().with_verify_sorted(true);
+
+    // Sorted input passes, across batches and row groups
+    write_sorted(0..10, builder());
+
+    // Nulls first in a descending column are in order too
+    let mut buffer = Vec::new();
+    let mut writer = builder().build(&mut buffer, test_schema()).unwrap();
+    writer
+        .write_rows(vec![
+            row(1, None),
+            row(1, Some(5)),
+            row(1, Some(2)),
+            row(2, None),
+        ])
+        .unwrap();
+    writer.close().unwrap();
+
+    // A tenant going backwards between flushed batches fails
+    let mut buffer = Vec::new();
+    let mut writer = builder().build(&mut buffer, test_schema()).unwrap();
+    writer.write_rows(vec![row(2, Some(1))]).unwrap();
+    writer.flush().unwrap();
+    writer.write_rows(vec![row(1, Some(1))]).unwrap();
+    let error = writer.flush().unwrap_err();
+    assert!(error.to_string().contains("out of order"), "{}", error);
+
+    // Within a batch the row is reported
+    let mut buffer = Vec::new();
+    let mut writer = builder().build(&mut buffer, test_schema()).unwrap();
+    writer
+        .write_rows(vec![row(1, Some(1)), row(1, Some(2))])
+        .unwrap();
+    let error = writer.close().unwrap_err();
+    assert!(error.to_string().contains("Row 1"), "{}", error);
+}
+
+#[test]
+fn test_range_filter_on_sort_column() {
+    let file = Bytes::from(write_sorted(0..10, sorted_builder()));
+
+    let read = |filter: RangeFilter| {
+        tenants(
+            Reader::new(file.clone())
+                .with_range_filter(filter)
+                .read_rows()
+                .unwrap(),
+        )
+    };
+
+    let ids = read(RangeFilter::new(
+        "tenant_id",
+        Bound::Included(ParquetValue::Int32(3)),
+        Bound::Excluded(ParquetValue::Int32(5)),
+    ));
+    assert_eq!(ids, [vec![3; 10], vec![4; 10]].concat());
+
+    // Literals of another type are cast to the column's
+    let ids = read(RangeFilter::eq("tenant_id", ParquetValue::Int64(7)));
+    assert_eq!(ids, vec![7; 10]);
+
+    let ids = read(RangeFilter::new(
+        "tenant_id",
+        Bound::Excluded(ParquetValue::Int32(8)),
+        Bound::Unbounded,
+    ));
+    assert_eq!(ids, vec![9; 10]);
+
+    assert!(read(RangeFilter::eq("tenant_id", ParquetValue::Int32(42))).is_empty());
+}
+
+#[test]
+fn test_range_filter_skips_row_groups_outside_range() {
+    let mut file = write_sorted(0..10, sorted_builder());
+
+    // Damage the first row group; a range in the last never reads it
+    let metadata =
+        ArrowReaderMetadata::load(&Bytes::from(file.clone()), ArrowReaderOptions::new()).unwrap();
+    let (start, len) = metadata.metadata().row_group(0).column(0).byte_range();
+    for byte in &mut file[start as usize..(start + len) as usize] {
+        *byte = 0xff;
+    }
+    let file = Bytes::from(file);
+
+    let rows = Reader::new(file.clone())
+        .with_range_filter(RangeFilter::eq("tenant_id", ParquetValue::Int32(9)))
+        .read_rows()
+        .unwrap();
+    assert_eq!(tenants(rows), vec![9; 10]);
+
+    let rows = Reader::new(file)
+        .with_range_filter(RangeFilter::eq("tenant_id", ParquetValue::Int32(0)))
+        .read_rows()
+        .unwrap();
+    assert!(rows.collect::<Result<Vec<_>>>().is_err());
+}
+
+#[test]
+fn test_range_filter_descending_and_unsorted_files() {
+    let descending = WriterBuilder::new()
+        .with_sorted_by(vec![SortColumn::new("tenant_id").with_descending(true)]);
+    let unsorted = WriterBuilder::new();
+
+    for (file, expected) in [
+        (write_sorted((0..10).rev(), descending), vec![6, 5, 4]),
+        (
+            write_sorted([4, 9, 0, 6, 5].into_iter(), unsorted),
+            vec![4, 6, 5],
+        ),
+    ] {
+        let ids = tenants(
+            Reader::new(Bytes::from(file))
+                .with_range_filter(RangeFilter::new(
+                    "tenant_id",
+                    Bound::Included(ParquetValue::Int32(4)),
+                    Bound::Included(ParquetValue::Int32(6)),
+                ))
+                .read_rows()
+                .unwrap(),
+        );
+        let expected = expected
+            .into_iter()
+            .flat_map(|id| vec![id; 10])
+            .collect::<Vec<_>>();
+        assert_eq!(ids, expected);
+    }
+}
+
+#[test]
+fn test_range_filter_row_groups_sorted_only_within() {
+    // Each row group is sorted as declared, but the file as a whole isn't
+    let file = write_sorted([4, 9, 0, 6, 5].into_iter(), sorted_builder());
+
+    let ids = tenants(
+        Reader::new(Bytes::from(file))
+            .with_range_filter(RangeFilter::new(
+                "tenant_id",
+                Bound::Included(ParquetValue::Int32(4)),
+                Bound::Included(ParquetValue::Int32(6)),
+            ))
+            .read_rows()
+            .unwrap(),
+    );
+    let expected = [4, 6, 5]
+        .into_iter()
+        .flat_map(|id| vec![id; 10])
+        .collect::<Vec<_>>();
+    assert_eq!(ids, expected);
+}
+
+#[test]
+fn test_range_filter_rows_within_row_group_and_projection() {
+    let file = Bytes::from(write_sorted(0..3, sorted_builder()));
+
+    let mut filtered = Reader::new(file)
+        .with_range_filter(RangeFilter::eq("tenant_id", ParquetValue::Int32(1)))
+        .with_range_filter(RangeFilter::new(
+            "ts",
+            Bound::Included(ParquetValue::Int64(3)),
+            Bound::Included(ParquetValue::Int64(4)),
+        ))
+        .read_columns_with_projection(&["name".to_string()], None)
+        .unwrap();
+
+    let batch = filtered.next().unwrap().unwrap();
+    assert_eq!(batch.columns.len(), 1);
+    assert_eq!(
+        batch.columns[0].1,
+        vec![ParquetValue::String(Arc::from("tenant 1")); 2]
+    );
+    assert!(filtered.next().is_none());
+}
+
+#[test]
+fn test_range_filter_never_matches_nan() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![primitive("score", PrimitiveType::Float64, false)],
+        })
+        .build()
+        .unwrap();
+    let score = |v: f64| vec![ParquetValue::Float64(OrderedFloat(v))];
+
+    // The first row group's max is 1, so `score >= 10` prunes it and its NaN
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer
+            .write_rows(vec![score(1.0), score(f64::NAN)])
+            .unwrap();
+        writer.flush().unwrap();
+        writer
+            .write_rows(vec![score(12.0), score(f64::NAN)])
+            .unwrap();
+        writer.close().unwrap();
+    }
+    let file = Bytes::from(buffer);
+
+    let read = |lower: Bound<f64>, upper: Bound<f64>| {
+        Reader::new(file.clone())
+            .with_range_filter(RangeFilter::new(
+                "score",
+                lower.map(|v| ParquetValue::Float64(OrderedFloat(v))),
+                upper.map(|v| ParquetValue::Float64(OrderedFloat(v))),
+            ))
+            .read_rows()
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    };
+
+    assert_eq!(
+        read(Bound::Included(10.0), Bound::Unbounded),
+        vec![score(12.0)]
+    );
+    assert_eq!(
+        read(Bound::Unbounded, Bound::Included(5.0)),
+        vec![score(1.0)]
+    );
+}
@@ -0,0 +1,261 @@
+use bytes::Bytes;
+use indexmap::IndexMap;
+use parquet_core::*;
+use std::sync::Arc;
+
+fn primitive(name: &str, primitive_type: PrimitiveType, nullable: bool) -> SchemaNode {
+    SchemaNode::Primitive {
+        name: name.to_string(),
+        primitive_type,
+        nullable,
+        format: None,
+    }
+}
+
+fn address(city: Option<&str>) -> ParquetValue {
+    let mut record = IndexMap::new();
+    record.insert(
+        Arc::from("city"),
+        city.map_or(ParquetValue::Null, |c| ParquetValue::String(Arc::from(c))),
+    );
+    ParquetValue::Record(record)
+}
+
+/// Two row groups: ids 1..=3 and 4..=5
+fn write_test_file() -> Bytes {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("id", PrimitiveType::Int32, false),
+                primitive("price", PrimitiveType::Decimal128(10, 2), true),
+                primitive("created_at", PrimitiveType::TimestampMillis(None), false),
+                SchemaNode::Struct {
+                    name: "address".to_string(),
+                    nullable: true,
+                    fields: vec![primitive("city", PrimitiveType::String, true)],
+                },
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let row = |id: i32, price: Option<i128>, created_at: i64, city: Option<&str>| {
+        vec![
+            ParquetValue::Int32(id),
+            price.map_or(ParquetValue::Null, |p| ParquetValue::Decimal128(p, 2)),
+            ParquetValue::TimestampMillis(created_at, None),
+            address(city),
+        ]
+    };
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer
+            .write_rows(vec![
+                row(1, Some(1999), 1_700_000_000_000, Some("Paris")),
+                row(2, None, 1_700_000_001_000, Some("Berlin")),
+                row(3, Some(-250), 1_700_000_002_000, None),
+            ])
+            .unwrap();
+        writer.flush().unwrap();
+        writer
+            .write_rows(vec![
+                row(4, Some(10_000), 1_600_000_000_000, Some("Oslo")),
+                row(5, None, 1_800_000_000_000, Some("Zurich")),
+            ])
+            .unwrap();
+        writer.close().unwrap();
+    }
+    Bytes::from(buffer)
+}
+
+fn column<'a>(columns: &'a [ColumnStatistics], path: &str) -> &'a ColumnStatistics {
+    columns
+        .iter()
+        .find(|c| c.path == path)
+        .unwrap_or_else(|| panic!("no statistics for {}", path))
+}
+
+#[test]
+fn test_statistics_are_decoded_and_aggregated() {
+    let stats = Reader::new(write_test_file()).statistics(None).unwrap();
+
+    assert_eq!(stats.num_rows, 5);
+    assert_eq!(stats.row_groups.len(), 2);
+    assert_eq!(stats.row_groups[0].num_rows, 3);
+    assert_eq!(stats.row_groups[1].num_rows, 2);
+
+    let id = column(&stats.columns, "id");
+    assert_eq!(id.min, Some(ParquetValue::Int32(1)));
+    assert_eq!(id.max, Some(ParquetValue::Int32(5)));
+    assert_eq!(id.null_count, Some(0));
+    assert_eq!(id.num_values, 5);
+    assert!(id.compressed_size > 0);
+    assert_eq!(
+        id.compressed_size,
+        stats
+            .row_groups
+            .iter()
+            .map(|rg| column(&rg.columns, "id").compressed_size)
+            .sum::<i64>()
+    );
+
+    let price = column(&stats.columns, "price");
+    assert_eq!(price.min, Some(ParquetValue::Decimal128(-250, 2)));
+    assert_eq!(price.max, Some(ParquetValue::Decimal128(10_000, 2)));
+    assert_eq!(price.null_count, Some(2));
+
+    let created_at = column(&stats.columns, "created_at");
+    assert!(matches!(
+        created_at.min,
+        Some(ParquetValue::TimestampMillis(1_600_000_000_000, _))
+    ));
+    assert!(matches!(
+        created_at.max,
+        Some(ParquetValue::TimestampMillis(1_800_000_000_000, _))
+    ));
+
+    // Per row group values are decoded the same way
+    let first_price = column(&stats.row_groups[0].columns, "price");
+    assert_eq!(first_price.min, Some(ParquetValue::Decimal128(-250, 2)));
+    assert_eq!(first_price.max, Some(ParquetValue::Decimal128(1999, 2)));
+    assert_eq!(first_price.null_count, Some(1));
+
+    // Nested leaves report counts and sizes but no decoded bounds
+    let city = column(&stats.columns, "address.city");
+    assert_eq!(city.null_count, Some(1));
+    assert_eq!(city.min, None);
+    assert_eq!(city.max, None);
+}
+
+#[test]
+fn test_file_bounds_unknown_when_a_row_group_has_none() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("ratio", PrimitiveType::Float64, false),
+                primitive("score", PrimitiveType::Int32, true),
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let row = |ratio: f64, score: Option<i32>| {
+        vec![
+            ParquetValue::Float64(ordered_float::OrderedFloat(ratio)),
+            score.map_or(ParquetValue::Null, ParquetValue::Int32),
+        ]
+    };
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer
+            .write_rows(vec![row(1.5, Some(3)), row(2.5, Some(7))])
+            .unwrap();
+        writer.flush().unwrap();
+        // NaN is left out of bounds, so this row group has values but no min/max
+        writer.write_rows(vec![row(f64::NAN, None)]).unwrap();
+        writer.close().unwrap();
+    }
+
+    let stats = Reader::new(Bytes::from(buffer)).statistics(None).unwrap();
+
+    let second_ratio = column(&stats.row_groups[1].columns, "ratio");
+    assert_eq!(second_ratio.min, None);
+    assert_eq!(second_ratio.max, None);
+    let ratio = column(&stats.columns, "ratio");
+    assert_eq!(ratio.min, None);
+    assert_eq!(ratio.max, None);
+
+    // A row group of nulls has no bounds either, but can't hold the extremes
+    let score = column(&stats.columns, "score");
+    assert_eq!(score.min, Some(ParquetValue::Int32(3)));
+    assert_eq!(score.max, Some(ParquetValue::Int32(7)));
+}
+
+#[test]
+fn test_statistics_column_selection() {
+    let columns = vec!["price".to_string(), "address".to_string()];
+    let stats = Reader::new(write_test_file())
+        .statistics(Some(&columns))
+        .unwrap();
+
+    let paths = stats
+        .columns
+        .iter()
+        .map(|c| c.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["price", "address.city"]);
+    assert_eq!(stats.row_groups[1].columns.len(), 2);
+}
+
+#[test]
+fn test_page_statistics_from_page_index() {
+    use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+    use parquet::file::properties::WriterProperties;
+    use parquet_core::statistics::page_statistics;
+
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![primitive("price", PrimitiveType::Decimal128(10, 2), true)],
+        })
+        .build()
+        .unwrap();
+
+    // Two rows per page
+    let props = WriterProperties::builder()
+        .set_data_page_row_count_limit(2)
+        .set_write_batch_size(1)
+        .build();
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new_with_properties(&mut buffer, schema, props).unwrap();
+        writer
+            .write_rows(
+                [Some(500), None, Some(-100), Some(250), Some(999)]
+                    .into_iter()
+                    .map(|p| vec![p.map_or(ParquetValue::Null, |p| ParquetValue::Decimal128(p, 2))])
+                    .collect(),
+            )
+            .unwrap();
+        writer.close().unwrap();
+    }
+    let bytes = Bytes::from(buffer);
+
+    // Without the page index there is nothing to report
+    let metadata = ArrowReaderMetadata::load(&bytes, ArrowReaderOptions::new()).unwrap();
+    assert_eq!(
+        page_statistics(metadata.metadata(), metadata.schema(), 0, 0).unwrap(),
+        None
+    );
+
+    let options = ArrowReaderOptions::new().with_page_index(true);
+    let metadata = ArrowReaderMetadata::load(&bytes, options).unwrap();
+    let pages = page_statistics(metadata.metadata(), metadata.schema(), 0, 0)
+        .unwrap()
+        .expect("page index should be loaded");
+
+    assert_eq!(pages.len(), 3);
+    assert_eq!(
+        pages.iter().map(|p| p.first_row_index).collect::<Vec<_>>(),
+        vec![0, 2, 4]
+    );
+    assert!(pages.windows(2).all(|w| w[0].offset < w[1].offset));
+    assert!(pages.iter().all(|p| p.compressed_size > 0));
+
+    assert_eq!(pages[0].min, Some(ParquetValue::Decimal128(500, 2)));
+    assert_eq!(pages[0].max, Some(ParquetValue::Decimal128(500, 2)));
+    assert_eq!(pages[0].null_count, Some(1));
+    assert_eq!(pages[1].min, Some(ParquetValue::Decimal128(-100, 2)));
+    assert_eq!(pages[1].max, Some(ParquetValue::Decimal128(250, 2)));
+    assert_eq!(pages[1].null_count, Some(0));
+    assert_eq!(pages[2].max, Some(ParquetValue::Decimal128(999, 2)));
+}
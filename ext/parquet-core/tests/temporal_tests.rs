@@ -165,11 +165,12 @@ fn test_timestamp_types() {
                         "Timestamp value mismatch at row {}, col {}",
                         row_idx, col_idx
                     );
-                    // For columns with timezone in schema (col 2 and 3), the schema timezone wins
+                    // For columns with timezone in schema (col 2 and 3), the schema timezone
+                    // wins and is kept as written
                     if col_idx >= 2 {
                         assert_eq!(
                             a_tz.as_deref(),
-                            Some("UTC"),
+                            Some("America/New_York"),
                             "Timezone mismatch at row {}, col {}",
                             row_idx,
                             col_idx
@@ -191,11 +192,12 @@ fn test_timestamp_types() {
                         "Timestamp value mismatch at row {}, col {}",
                         row_idx, col_idx
                     );
-                    // For columns with timezone in schema (col 2 and 3), the schema timezone wins
+                    // For columns with timezone in schema (col 2 and 3), the schema timezone
+                    // wins and is kept as written
                     if col_idx >= 2 {
                         assert_eq!(
                             a_tz.as_deref(),
-                            Some("UTC"),
+                            Some("America/New_York"),
                             "Timezone mismatch at row {}, col {}",
                             row_idx,
                             col_idx
@@ -0,0 +1,102 @@
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet_core::*;
+use std::sync::Arc;
+
+fn primitive(name: &str, primitive_type: PrimitiveType) -> SchemaNode {
+    SchemaNode::Primitive {
+        name: name.to_string(),
+        primitive_type,
+        nullable: false,
+        format: None,
+    }
+}
+
+/// Two row groups of three rows, with an integer and a string column
+fn write_test_file() -> Vec<u8> {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                primitive("id", PrimitiveType::Int64),
+                primitive("name", PrimitiveType::String),
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let rows = |ids: std::ops::Range<i64>| {
+        ids.map(|id| {
+            vec![
+                ParquetValue::Int64(id),
+                ParquetValue::String(Arc::from(format!("name {}", id))),
+            ]
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = Writer::new(&mut buffer, schema).unwrap();
+        writer.write_rows(rows(0..3)).unwrap();
+        writer.flush().unwrap();
+        writer.write_rows(rows(3..6)).unwrap();
+        writer.close().unwrap();
+    }
+    buffer
+}
+
+/// Offset of the first page of a column chunk
+fn chunk_start(file: &[u8], row_group: usize, column: usize) -> u64 {
+    let bytes = Bytes::from(file.to_vec());
+    let metadata = ArrowReaderMetadata::load(&bytes, ArrowReaderOptions::new()).unwrap();
+    metadata
+        .metadata()
+        .row_group(row_group)
+        .column(column)
+        .byte_range()
+        .0
+}
+
+#[test]
+fn test_verify_healthy_file() {
+    let report = Reader::new(Bytes::from(write_test_file()))
+        .verify(true)
+        .unwrap();
+
+    assert!(report.is_ok(), "{:?}", report.problems);
+    assert_eq!(report.num_rows, 6);
+    assert_eq!(report.row_groups, 2);
+    assert!(report.pages >= 4);
+}
+
+#[test]
+fn test_verify_reports_corrupt_page_header() {
+    let mut file = write_test_file();
+    let start = chunk_start(&file, 1, 1);
+    for byte in &mut file[start as usize..start as usize + 4] {
+        *byte = 0xff;
+    }
+
+    let report = Reader::new(Bytes::from(file)).verify(true).unwrap();
+
+    // Only the damaged chunk is reported; the other three check out
+    assert_eq!(report.problems.len(), 1, "{:?}", report.problems);
+    let problem = &report.problems[0];
+    assert_eq!(problem.row_group, Some(1));
+    assert_eq!(problem.column.as_deref(), Some("name"));
+    assert_eq!(problem.page_offset, Some(start));
+}
+
+#[test]
+fn test_verify_reports_unreadable_footer() {
+    let mut file = write_test_file();
+    file.truncate(file.len() - 10);
+
+    let report = Reader::new(Bytes::from(file)).verify(true).unwrap();
+
+    assert!(!report.is_ok());
+    assert_eq!(report.problems[0].row_group, None);
+    assert!(report.problems[0].message.contains("footer"));
+}
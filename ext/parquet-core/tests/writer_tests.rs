@@ -234,6 +234,43 @@ fn test_memory_threshold_configuration() {
     }
 }
 
+#[test]
+fn test_writer_max_row_group_size() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![SchemaNode::Primitive {
+                name: "id".to_string(),
+                primitive_type: PrimitiveType::Int32,
+                nullable: false,
+                format: None,
+            }],
+        })
+        .build()
+        .unwrap();
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = WriterBuilder::new()
+            .with_max_row_group_size(100)
+            .build(&mut buffer, schema)
+            .unwrap();
+        writer
+            .write_rows((0..250).map(|i| vec![ParquetValue::Int32(i)]).collect())
+            .unwrap();
+        writer.close().unwrap();
+    }
+
+    let stats = Reader::new(Bytes::from(buffer)).statistics(None).unwrap();
+    let sizes = stats
+        .row_groups
+        .iter()
+        .map(|rg| rg.num_rows)
+        .collect::<Vec<_>>();
+    assert_eq!(sizes, vec![100, 100, 50]);
+}
+
 #[test]
 fn test_writer_memory_flushing_with_binary() {
     let schema = SchemaBuilder::new()
@@ -543,3 +580,82 @@ fn test_complex_nested_data_memory() {
 
     assert_eq!(read_rows, rows);
 }
+
+#[test]
+fn test_writer_validate_value() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![
+                SchemaNode::Primitive {
+                    name: "id".to_string(),
+                    primitive_type: PrimitiveType::Int32,
+                    nullable: false,
+                    format: None,
+                },
+                SchemaNode::Primitive {
+                    name: "name".to_string(),
+                    primitive_type: PrimitiveType::String,
+                    nullable: true,
+                    format: None,
+                },
+            ],
+        })
+        .build()
+        .unwrap();
+
+    let mut buffer = Vec::new();
+    let mut writer = Writer::new(&mut buffer, schema).unwrap();
+
+    assert!(writer.validate_value(0, &ParquetValue::Int32(1)).is_ok());
+    assert!(writer.validate_value(1, &ParquetValue::Null).is_ok());
+    assert!(writer.validate_value(0, &ParquetValue::Null).is_err());
+    assert!(writer
+        .validate_value(0, &ParquetValue::String("one".into()))
+        .is_err());
+    assert!(writer.validate_value(2, &ParquetValue::Int32(1)).is_err());
+
+    writer
+        .write_row(vec![ParquetValue::Int32(1), ParquetValue::Null])
+        .unwrap();
+    writer.close().unwrap();
+
+    let rows: Vec<_> = Reader::new(Bytes::from(buffer))
+        .read_rows()
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(rows, vec![vec![ParquetValue::Int32(1), ParquetValue::Null]]);
+}
+
+#[test]
+fn test_writer_write_rows_unchecked() {
+    let schema = SchemaBuilder::new()
+        .with_root(SchemaNode::Struct {
+            name: "root".to_string(),
+            nullable: false,
+            fields: vec![SchemaNode::Primitive {
+                name: "id".to_string(),
+                primitive_type: PrimitiveType::Int32,
+                nullable: false,
+                format: None,
+            }],
+        })
+        .build()
+        .unwrap();
+
+    let mut buffer = Vec::new();
+    let mut writer = Writer::new(&mut buffer, schema).unwrap();
+
+    // Row length is still checked
+    assert!(writer
+        .write_rows_unchecked(vec![vec![ParquetValue::Int32(1), ParquetValue::Null]])
+        .is_err());
+
+    // A value that skipped validation fails when its batch is built
+    writer
+        .write_rows_unchecked(vec![vec![ParquetValue::Int32(1)], vec![ParquetValue::Null]])
+        .unwrap();
+    assert!(writer.flush().is_err());
+}
@@ -0,0 +1,83 @@
+//! `Parquet.count`, `Parquet.min`, `Parquet.max` and `Parquet.null_count`
+
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, IntoValue, Ruby, Value};
+use parquet_core::Reader;
+
+use crate::chunk_reader::CloneableChunkReader;
+use crate::converter::parquet_to_ruby;
+use crate::error::to_read_error;
+use crate::utils::{open_chunk_reader, parse_range_filters};
+
+/// Number of rows, within `filter` when given
+pub fn count(ruby: &Ruby, to_read: Value, filter: Option<Value>) -> Result<Value, MagnusError> {
+    let count = filtered_reader(ruby, to_read, filter)?
+        .count()
+        .map_err(to_read_error)?;
+    Ok(count.into_value_with(ruby))
+}
+
+/// Smallest non null value of `column`, or nil when there is none
+pub fn min(
+    ruby: &Ruby,
+    to_read: Value,
+    column: String,
+    filter: Option<Value>,
+) -> Result<Value, MagnusError> {
+    extreme(ruby, to_read, column, filter, false)
+}
+
+/// Largest non null value of `column`, or nil when there is none
+pub fn max(
+    ruby: &Ruby,
+    to_read: Value,
+    column: String,
+    filter: Option<Value>,
+) -> Result<Value, MagnusError> {
+    extreme(ruby, to_read, column, filter, true)
+}
+
+/// Number of nulls in `column`
+pub fn null_count(
+    ruby: &Ruby,
+    to_read: Value,
+    column: String,
+    filter: Option<Value>,
+) -> Result<Value, MagnusError> {
+    let count = filtered_reader(ruby, to_read, filter)?
+        .null_count(&column)
+        .map_err(to_read_error)?;
+    Ok(count.into_value_with(ruby))
+}
+
+fn extreme(
+    ruby: &Ruby,
+    to_read: Value,
+    column: String,
+    filter: Option<Value>,
+    largest: bool,
+) -> Result<Value, MagnusError> {
+    let reader = filtered_reader(ruby, to_read, filter)?;
+    let value = if largest {
+        reader.max(&column)
+    } else {
+        reader.min(&column)
+    }
+    .map_err(to_read_error)?;
+
+    match value {
+        Some(value) => parquet_to_ruby(value).map_err(to_read_error),
+        None => Ok(ruby.qnil().as_value()),
+    }
+}
+
+fn filtered_reader(
+    ruby: &Ruby,
+    to_read: Value,
+    filter: Option<Value>,
+) -> Result<Reader<CloneableChunkReader>, MagnusError> {
+    let chunk_reader = open_chunk_reader(ruby, to_read)?;
+    Ok(parse_range_filters(ruby, filter)?
+        .into_iter()
+        .fold(Reader::new(chunk_reader), Reader::with_range_filter))
+}
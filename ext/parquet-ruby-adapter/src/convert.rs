@@ -0,0 +1,314 @@
+//! Conversions between Parquet files and other formats
+//!
+//! The data never passes through Ruby: the core converts record batches
+//! directly, and this module only opens the paths or IO objects involved.
+
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, IntoValue, RString, Ruby, TryConvert, Value};
+use parquet_core::convert::{
+    arrow_c_array_to_parquet, arrow_c_stream_to_parquet, arrow_ipc_to_parquet, csv_to_parquet,
+    ndjson_to_parquet, parquet_to_arrow_ipc, parquet_to_csv, parquet_to_ndjson, CsvOptions,
+    IpcFormat,
+};
+use parquet_core::reader::Reader;
+use parquet_core::{Schema, WriterBuilder};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Cursor};
+
+use crate::error::{to_magnus_error, to_read_error};
+use crate::io::{RubyIOReader, RubyIOWriter};
+use crate::schema::{process_schema_value, ruby_schema_to_parquet};
+use crate::types::CsvArgs;
+use crate::utils::{open_chunk_reader, parse_compression};
+use crate::writer::{finish_output, open_output};
+
+/// Write a Parquet file as newline delimited JSON, returning the row count
+pub fn to_ndjson(
+    ruby: &Ruby,
+    to_read: Value,
+    write_to: Value,
+    columns: Option<Vec<String>>,
+) -> Result<Value, MagnusError> {
+    let reader = Reader::new(open_chunk_reader(ruby, to_read)?);
+    let columns = columns.as_deref();
+
+    let rows = if write_to.is_kind_of(ruby.class_string()) {
+        let path: String = TryConvert::try_convert(write_to)?;
+        let file = File::create(&path).map_err(to_magnus_error)?;
+        parquet_to_ndjson(reader, BufWriter::new(file), columns)
+    } else {
+        parquet_to_ndjson(reader, BufWriter::new(RubyIOWriter::new(write_to)), columns)
+    }
+    .map_err(to_read_error)?;
+
+    Ok(rows.into_value_with(ruby))
+}
+
+/// Write newline delimited JSON as a Parquet file, returning the row count
+///
+/// The schema is inferred from the records when `schema_value` is nil.
+pub fn from_ndjson(
+    ruby: &Ruby,
+    read_from: Value,
+    write_to: Value,
+    schema_value: Option<Value>,
+    compression: Option<String>,
+) -> Result<Value, MagnusError> {
+    let schema = parse_optional_schema(ruby, schema_value)?;
+    let builder = WriterBuilder::new().with_compression(parse_compression(compression)?);
+
+    let (file, pending_copy) = open_output(ruby, write_to)?;
+    let rows = if read_from.is_kind_of(ruby.class_string()) {
+        let path: String = TryConvert::try_convert(read_from)?;
+        let input = File::open(&path).map_err(to_magnus_error)?;
+        ndjson_to_parquet(BufReader::new(input), file, schema, builder)
+    } else {
+        let input = RubyIOReader::new(read_from).map_err(to_magnus_error)?;
+        ndjson_to_parquet(BufReader::new(input), file, schema, builder)
+    }
+    .map_err(to_magnus_error)?;
+    finish_output(pending_copy)?;
+
+    Ok(rows.into_value_with(ruby))
+}
+
+/// Write a Parquet file as CSV, returning the row count
+pub fn to_csv(
+    ruby: &Ruby,
+    to_read: Value,
+    write_to: Value,
+    columns: Option<Vec<String>>,
+    csv_args: CsvArgs,
+) -> Result<Value, MagnusError> {
+    let options = csv_options(ruby, csv_args)?;
+    let reader = Reader::new(open_chunk_reader(ruby, to_read)?);
+    let columns = columns.as_deref();
+
+    let rows = if write_to.is_kind_of(ruby.class_string()) {
+        let path: String = TryConvert::try_convert(write_to)?;
+        let file = File::create(&path).map_err(to_magnus_error)?;
+        parquet_to_csv(reader, BufWriter::new(file), columns, &options)
+    } else {
+        let output = BufWriter::new(RubyIOWriter::new(write_to));
+        parquet_to_csv(reader, output, columns, &options)
+    }
+    .map_err(to_read_error)?;
+
+    Ok(rows.into_value_with(ruby))
+}
+
+/// Write CSV as a Parquet file, returning the row count
+///
+/// The schema is inferred from the first rows when `schema_value` is nil.
+pub fn from_csv(
+    ruby: &Ruby,
+    read_from: Value,
+    write_to: Value,
+    schema_value: Option<Value>,
+    csv_args: CsvArgs,
+    compression: Option<String>,
+) -> Result<Value, MagnusError> {
+    let options = csv_options(ruby, csv_args)?;
+    let schema = parse_optional_schema(ruby, schema_value)?;
+    let builder = WriterBuilder::new().with_compression(parse_compression(compression)?);
+
+    let (file, pending_copy) = open_output(ruby, write_to)?;
+    let rows = if read_from.is_kind_of(ruby.class_string()) {
+        let path: String = TryConvert::try_convert(read_from)?;
+        let input = File::open(&path).map_err(to_magnus_error)?;
+        csv_to_parquet(BufReader::new(input), file, schema, &options, builder)
+    } else {
+        let input = RubyIOReader::new(read_from).map_err(to_magnus_error)?;
+        csv_to_parquet(BufReader::new(input), file, schema, &options, builder)
+    }
+    .map_err(to_magnus_error)?;
+    finish_output(pending_copy)?;
+
+    Ok(rows.into_value_with(ruby))
+}
+
+/// Write a Parquet file as Arrow IPC, returning the row count
+///
+/// `format` is `"file"` (the default) or `"stream"`.
+pub fn to_arrow_ipc(
+    ruby: &Ruby,
+    to_read: Value,
+    write_to: Value,
+    format: Option<String>,
+    columns: Option<Vec<String>>,
+) -> Result<Value, MagnusError> {
+    let format = match format.as_deref() {
+        Some("file") | None => IpcFormat::File,
+        Some("stream") => IpcFormat::Stream,
+        Some(other) => {
+            return Err(MagnusError::new(
+                ruby.exception_arg_error(),
+                format!("Invalid format {:?}, expected :file or :stream", other),
+            ))
+        }
+    };
+    let reader = Reader::new(open_chunk_reader(ruby, to_read)?);
+    let columns = columns.as_deref();
+
+    let rows = if write_to.is_kind_of(ruby.class_string()) {
+        let path: String = TryConvert::try_convert(write_to)?;
+        let file = File::create(&path).map_err(to_magnus_error)?;
+        parquet_to_arrow_ipc(reader, BufWriter::new(file), columns, format)
+    } else {
+        let output = BufWriter::new(RubyIOWriter::new(write_to));
+        parquet_to_arrow_ipc(reader, output, columns, format)
+    }
+    .map_err(to_read_error)?;
+
+    Ok(rows.into_value_with(ruby))
+}
+
+/// Write an Arrow IPC file or stream as a Parquet file, returning the row count
+pub fn from_arrow_ipc(
+    ruby: &Ruby,
+    read_from: Value,
+    write_to: Value,
+    compression: Option<String>,
+    flush_threshold: Option<usize>,
+) -> Result<Value, MagnusError> {
+    let mut builder = WriterBuilder::new().with_compression(parse_compression(compression)?);
+    if let Some(threshold) = flush_threshold {
+        builder = builder.with_memory_threshold(threshold);
+    }
+
+    let (file, pending_copy) = open_output(ruby, write_to)?;
+    let rows = if read_from.is_kind_of(ruby.class_string()) {
+        let path: String = TryConvert::try_convert(read_from)?;
+        let input = File::open(&path).map_err(to_magnus_error)?;
+        arrow_ipc_to_parquet(BufReader::new(input), file, None, builder)
+    } else {
+        let input = RubyIOReader::new(read_from).map_err(to_magnus_error)?;
+        arrow_ipc_to_parquet(BufReader::new(input), file, None, builder)
+    }
+    .map_err(to_magnus_error)?;
+    finish_output(pending_copy)?;
+
+    Ok(rows.into_value_with(ruby))
+}
+
+/// Write Arrow data as a Parquet file, returning the row count
+///
+/// `data` is the address of an Arrow C stream, an `[array, schema]` pair of
+/// C array addresses, Arrow IPC bytes in a String, or an IO of IPC data.
+/// Objects responding to `export`, such as red-arrow record batch readers
+/// and record batches, are exported to the C form first. The Parquet schema
+/// follows the Arrow schema unless `schema_value` is given.
+pub fn write_arrow(
+    ruby: &Ruby,
+    data: Value,
+    write_to: Value,
+    schema_value: Option<Value>,
+    compression: Option<String>,
+) -> Result<Value, MagnusError> {
+    let schema = parse_optional_schema(ruby, schema_value)?;
+    let builder = WriterBuilder::new().with_compression(parse_compression(compression)?);
+
+    let (file, pending_copy) = open_output(ruby, write_to)?;
+
+    // The structs red-arrow exports are allocated with g_new, which is
+    // malloc, and belong to us once exported
+    let exported = data.respond_to("export", false)?;
+    let data = if exported {
+        data.funcall::<_, _, Value>("export", ())?
+    } else {
+        data
+    };
+
+    let rows = if data.is_kind_of(ruby.class_integer()) {
+        let stream = c_address(ruby, data)?;
+        let rows = unsafe { arrow_c_stream_to_parquet(stream as *mut _, file, schema, builder) };
+        if exported {
+            unsafe { libc::free(stream as *mut libc::c_void) };
+        }
+        rows
+    } else if data.is_kind_of(ruby.class_array()) {
+        let (array, array_schema): (Value, Value) = TryConvert::try_convert(data)?;
+        let (array, array_schema) = (c_address(ruby, array)?, c_address(ruby, array_schema)?);
+        let rows = unsafe {
+            arrow_c_array_to_parquet(
+                array as *mut _,
+                array_schema as *mut _,
+                file,
+                schema,
+                builder,
+            )
+        };
+        if exported {
+            unsafe {
+                libc::free(array as *mut libc::c_void);
+                libc::free(array_schema as *mut libc::c_void);
+            }
+        }
+        rows
+    } else if data.is_kind_of(ruby.class_string()) {
+        let bytes: RString = TryConvert::try_convert(data)?;
+        let bytes = unsafe { bytes.as_slice() }.to_vec();
+        arrow_ipc_to_parquet(Cursor::new(bytes), file, schema, builder)
+    } else {
+        let input = RubyIOReader::new(data).map_err(to_magnus_error)?;
+        arrow_ipc_to_parquet(BufReader::new(input), file, schema, builder)
+    }
+    .map_err(to_magnus_error)?;
+    finish_output(pending_copy)?;
+
+    Ok(rows.into_value_with(ruby))
+}
+
+/// A non-null C data interface address
+fn c_address(ruby: &Ruby, value: Value) -> Result<usize, MagnusError> {
+    let address: usize = TryConvert::try_convert(value)?;
+    if address == 0 {
+        return Err(MagnusError::new(
+            ruby.exception_arg_error(),
+            "Arrow C data address must not be null",
+        ));
+    }
+    Ok(address)
+}
+
+fn csv_options(ruby: &Ruby, csv_args: CsvArgs) -> Result<CsvOptions, MagnusError> {
+    let mut options = CsvOptions::new();
+    if let Some(header) = csv_args.header {
+        options = options.with_header(header);
+    }
+    if let Some(delimiter) = csv_args.delimiter {
+        match delimiter.as_bytes() {
+            [byte] => options = options.with_delimiter(*byte),
+            _ => {
+                return Err(MagnusError::new(
+                    ruby.exception_arg_error(),
+                    format!("delimiter must be a single byte, got {:?}", delimiter),
+                ))
+            }
+        }
+    }
+    if let Some(null_values) = csv_args.null_values {
+        options = options.with_null_values(null_values);
+    }
+    if let Some(format) = csv_args.date_format {
+        options = options.with_date_format(format);
+    }
+    if let Some(format) = csv_args.timestamp_format {
+        options = options.with_timestamp_format(format);
+    }
+    Ok(options)
+}
+
+/// A DSL or hash schema, or `None` so the converter infers one
+pub(crate) fn parse_optional_schema(
+    ruby: &Ruby,
+    schema_value: Option<Value>,
+) -> Result<Option<Schema>, MagnusError> {
+    let Some(schema_value) = schema_value.filter(|v| !v.is_nil()) else {
+        return Ok(None);
+    };
+    let schema_hash = process_schema_value(ruby, schema_value, None).map_err(to_magnus_error)?;
+    ruby_schema_to_parquet(schema_hash)
+        .map(Some)
+        .map_err(to_magnus_error)
+}
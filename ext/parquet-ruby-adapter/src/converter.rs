@@ -1,15 +1,25 @@
+use crate::geometry::{decode_geometry, encode_geometry};
+use crate::json::{encode_json, parse_json};
 use crate::string_cache::StringCache;
+use crate::timezone::{resolve_time_zone, utc_offset};
+use crate::types::{
+    BinaryFormat, Conversions, DateFormat, DecimalFormat, GeometryFormat, KeyFormat,
+    TimestampFormat,
+};
 use bytes::Bytes;
 use indexmap::IndexMap;
+use jiff::tz::TimeZone;
+use magnus::encoding::EncodingCapable;
 use magnus::r_hash::ForEach;
-use magnus::value::ReprValue;
+use magnus::value::{BoxValue, ReprValue};
 use magnus::{
     kwargs, Error as MagnusError, IntoValue, Module, RArray, RHash, RString, Ruby, Symbol,
     TryConvert, Value,
 };
 use ordered_float::OrderedFloat;
-use parquet_core::{ParquetError, ParquetValue, Result};
+use parquet_core::{ParquetError, ParquetValue, Result, Variant};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -131,12 +141,29 @@ impl RubyValueConverter {
             Float32 => self.convert_to_float32(value),
             Float64 => self.convert_to_float64(value),
             String => self.convert_to_string(value),
+            Json => self.convert_to_json(value),
+            Enum(values) => self.convert_to_enum(value, values.as_deref()),
             Binary => self.convert_to_binary(value),
             Date32 => self.convert_to_date32(value, None),
             Date64 => self.convert_to_date64(value, None),
             TimeMillis => self.convert_to_time_millis(value),
             TimeMicros => self.convert_to_time_micros(value),
             TimeNanos => self.convert_to_time_nanos(value),
+            DurationSecond => Ok(self
+                .convert_to_duration(value, 1)?
+                .map_or(ParquetValue::Null, ParquetValue::DurationSecond)),
+            DurationMillis => Ok(self
+                .convert_to_duration(value, 1_000)?
+                .map_or(ParquetValue::Null, ParquetValue::DurationMillis)),
+            DurationMicros => Ok(self
+                .convert_to_duration(value, 1_000_000)?
+                .map_or(ParquetValue::Null, ParquetValue::DurationMicros)),
+            DurationNanos => Ok(self
+                .convert_to_duration(value, 1_000_000_000)?
+                .map_or(ParquetValue::Null, ParquetValue::DurationNanos)),
+            Interval => self.convert_to_interval(value),
+            Variant => self.convert_to_variant(value),
+            Geometry(_) | Geography(_) => self.convert_to_geometry(value),
             TimestampSecond(schema_tz) => {
                 self.convert_to_timestamp_second_with_tz(value, schema_tz.as_deref())
             }
@@ -257,7 +284,9 @@ impl RubyValueConverter {
     /// 1. UTC timestamps (when schema has ANY timezone)
     /// 2. Local/unzoned timestamps (when schema has NO timezone)
     ///
-    /// Non-UTC timezones like "+09:00" or "America/New_York" are NOT preserved.
+    /// The offset of each Time is not preserved. A schema zone like "+09:00"
+    /// or "America/New_York" is kept in the Arrow schema, and values are
+    /// localised into it when read.
     fn normalize_timestamp_for_parquet(
         &self,
         time_value: Value,
@@ -438,6 +467,50 @@ impl RubyValueConverter {
         }
     }
 
+    fn convert_to_json(&self, value: Value) -> Result<ParquetValue> {
+        if value.is_nil() {
+            return Ok(ParquetValue::Null);
+        }
+
+        Ok(ParquetValue::Json(encode_json(value)?))
+    }
+
+    fn convert_to_enum(&self, value: Value, allowed: Option<&[String]>) -> Result<ParquetValue> {
+        if value.is_nil() {
+            return Ok(ParquetValue::Null);
+        }
+
+        let s: String = if let Some(symbol) = Symbol::from_value(value) {
+            symbol
+                .name()
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?
+                .into_owned()
+        } else {
+            let ruby = Ruby::get()
+                .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
+            if !value.is_kind_of(ruby.class_string()) {
+                return Err(ParquetError::Conversion(format!(
+                    "Expected String or Symbol for enum value, got {}",
+                    value.class()
+                )));
+            }
+            TryConvert::try_convert(value)
+                .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?
+        };
+
+        if let Some(allowed) = allowed {
+            if !allowed.iter().any(|v| v == &s) {
+                return Err(ParquetError::Conversion(format!(
+                    "Invalid enum value {:?}, expected one of: {}",
+                    s,
+                    allowed.join(", ")
+                )));
+            }
+        }
+
+        Ok(ParquetValue::Enum(s.into()))
+    }
+
     fn convert_to_binary(&self, value: Value) -> Result<ParquetValue> {
         if value.is_nil() {
             return Ok(ParquetValue::Null);
@@ -692,6 +765,235 @@ impl RubyValueConverter {
         )))
     }
 
+    /// Convert numeric seconds (Integer, Float or Rational) into a count of
+    /// `units_per_second` ticks
+    fn convert_to_duration(&self, value: Value, units_per_second: i64) -> Result<Option<i64>> {
+        if value.is_nil() {
+            return Ok(None);
+        }
+
+        let ruby = Ruby::get()
+            .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
+
+        if value.is_kind_of(ruby.class_integer()) {
+            let seconds: i64 = TryConvert::try_convert(value)
+                .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+            return seconds
+                .checked_mul(units_per_second)
+                .map(Some)
+                .ok_or_else(|| {
+                    ParquetError::Conversion(format!(
+                        "Duration of {} seconds is out of range",
+                        seconds
+                    ))
+                });
+        }
+
+        if value.is_kind_of(ruby.class_numeric()) {
+            // Multiply in Ruby so Rationals stay exact before rounding
+            let scaled: Value = value
+                .funcall("*", (units_per_second,))
+                .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+            let ticks: i64 = scaled
+                .funcall("round", ())
+                .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+            return Ok(Some(ticks));
+        }
+
+        Err(ParquetError::Conversion(format!(
+            "Cannot convert {} to duration, expected numeric seconds",
+            value.class()
+        )))
+    }
+
+    fn convert_to_variant(&mut self, value: Value) -> Result<ParquetValue> {
+        if value.is_nil() {
+            return Ok(ParquetValue::Null);
+        }
+
+        let variant = Variant::from_value(&self.variant_value(value)?)?;
+        Ok(ParquetValue::Variant(Box::new(variant)))
+    }
+
+    /// Map an arbitrary Ruby object onto the values a variant can hold
+    fn variant_value(&mut self, value: Value) -> Result<ParquetValue> {
+        let ruby = Ruby::get()
+            .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
+
+        if value.is_nil() {
+            return Ok(ParquetValue::Null);
+        }
+
+        if let Some(symbol) = Symbol::from_value(value) {
+            let name = symbol
+                .name()
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            return Ok(ParquetValue::String(name.as_ref().into()));
+        }
+
+        if value.is_kind_of(ruby.class_string()) {
+            let s: RString = TryConvert::try_convert(value)
+                .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+            // Binary strings are kept as bytes, everything else as text
+            if s.enc_get() == ruby.ascii8bit_encindex() {
+                return Ok(ParquetValue::Bytes(Bytes::copy_from_slice(unsafe {
+                    s.as_slice()
+                })));
+            }
+            let s = s
+                .to_string()
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            return Ok(ParquetValue::String(s.into()));
+        }
+
+        if value.is_kind_of(ruby.class_array()) {
+            let array: RArray = TryConvert::try_convert(value)
+                .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+            let mut items = Vec::with_capacity(array.len());
+            for item in array.into_iter() {
+                items.push(self.variant_value(item)?);
+            }
+            return Ok(ParquetValue::List(items));
+        }
+
+        if value.is_kind_of(ruby.class_hash()) {
+            let hash: RHash = TryConvert::try_convert(value)
+                .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+            let mut fields = Vec::with_capacity(hash.len());
+            hash.foreach(|key: Value, val: Value| {
+                fields.push((key, val));
+                Ok(ForEach::Continue)
+            })
+            .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+
+            let mut record = IndexMap::with_capacity(fields.len());
+            for (key, val) in fields {
+                let key: Arc<str> = match Symbol::from_value(key) {
+                    Some(symbol) => symbol
+                        .name()
+                        .map_err(|e| ParquetError::Conversion(e.to_string()))?
+                        .as_ref()
+                        .into(),
+                    None => {
+                        let s: String = key
+                            .funcall("to_s", ())
+                            .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+                        s.into()
+                    }
+                };
+                record.insert(key, self.variant_value(val)?);
+            }
+            return Ok(ParquetValue::Record(record));
+        }
+
+        if value.is_kind_of(ruby.class_time()) {
+            let secs: i64 = value
+                .funcall("to_i", ())
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            let nsec: i64 = value
+                .funcall("nsec", ())
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            let micros = secs
+                .checked_mul(1_000_000)
+                .and_then(|micros| micros.checked_add(nsec / 1_000))
+                .ok_or_else(|| {
+                    ParquetError::Conversion(format!(
+                        "Time of {} seconds is out of range for a variant timestamp",
+                        secs
+                    ))
+                })?;
+            return Ok(ParquetValue::TimestampMicros(micros, Some("UTC".into())));
+        }
+
+        match value.class().to_string().as_str() {
+            "Date" => self.convert_to_date32(value, None),
+            "BigDecimal" => {
+                let s: String = value
+                    .funcall("to_s", ("F",))
+                    .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+                let scale = s.split_once('.').map_or(0, |(_, frac)| frac.len()).min(38);
+                self.parse_decimal128(&s, 38, scale as i8)
+            }
+            "Integer" => match i64::try_convert(value) {
+                Ok(i) => Ok(ParquetValue::Int64(i)),
+                Err(_) => {
+                    let s: String = value
+                        .funcall("to_s", ())
+                        .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+                    self.parse_decimal128(&s, 38, 0)
+                }
+            },
+            _ => self.infer_and_convert(value),
+        }
+    }
+
+    fn convert_to_geometry(&self, value: Value) -> Result<ParquetValue> {
+        if value.is_nil() {
+            return Ok(ParquetValue::Null);
+        }
+
+        let ruby = Ruby::get()
+            .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
+        Ok(ParquetValue::Geometry(encode_geometry(&ruby, value)?))
+    }
+
+    fn convert_to_interval(&self, value: Value) -> Result<ParquetValue> {
+        if value.is_nil() {
+            return Ok(ParquetValue::Null);
+        }
+
+        let hash: RHash = TryConvert::try_convert(value).map_err(|_| {
+            ParquetError::Conversion(format!(
+                "Cannot convert {} to interval, expected a Hash with :months, :days and :millis",
+                value.class()
+            ))
+        })?;
+
+        // A misspelt part would otherwise be written as a zero interval
+        let mut keys = Vec::with_capacity(hash.len());
+        hash.foreach(|key: Value, _: Value| {
+            keys.push(key);
+            Ok(ForEach::Continue)
+        })
+        .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+        if keys.is_empty() {
+            return Err(ParquetError::Conversion(
+                "Interval needs at least one of :months, :days and :millis".to_string(),
+            ));
+        }
+        for key in keys {
+            let name = match Symbol::from_value(key) {
+                Some(symbol) => symbol.name().ok().map(|name| name.to_string()),
+                None => RString::from_value(key).and_then(|s| s.to_string().ok()),
+            };
+            if !matches!(name.as_deref(), Some("months" | "days" | "millis")) {
+                return Err(ParquetError::Conversion(format!(
+                    "Unknown interval part {}, expected :months, :days or :millis",
+                    key.inspect()
+                )));
+            }
+        }
+
+        let part = |name: &str| -> Result<i32> {
+            let part = hash
+                .get(Symbol::new(name))
+                .or_else(|| hash.get(name))
+                .filter(|v| !v.is_nil());
+            match part {
+                Some(v) => TryConvert::try_convert(v).map_err(|e: MagnusError| {
+                    ParquetError::Conversion(format!("Invalid interval {}: {}", name, e))
+                }),
+                None => Ok(0),
+            }
+        };
+
+        Ok(ParquetValue::Interval(
+            part("months")?,
+            part("days")?,
+            part("millis")?,
+        ))
+    }
+
     // Timestamp conversion methods that respect schema timezone
     fn convert_to_timestamp_second_with_tz(
         &self,
@@ -713,14 +1015,10 @@ impl RubyValueConverter {
                 .map_err(|e| ParquetError::Conversion(e.to_string()))?;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampSecond(secs, tz));
         }
@@ -743,14 +1041,10 @@ impl RubyValueConverter {
                 .map_err(|e| ParquetError::Conversion(e.to_string()))?;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampSecond(secs, tz));
         }
@@ -786,14 +1080,10 @@ impl RubyValueConverter {
                     / 1_000_000;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampMillis(millis, tz));
         }
@@ -821,14 +1111,10 @@ impl RubyValueConverter {
                     / 1_000_000;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampMillis(millis, tz));
         }
@@ -864,14 +1150,10 @@ impl RubyValueConverter {
                     / 1000;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampMicros(micros, tz));
         }
@@ -899,14 +1181,10 @@ impl RubyValueConverter {
                     / 1000;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampMicros(micros, tz));
         }
@@ -941,14 +1219,10 @@ impl RubyValueConverter {
                     .map_err(|e| ParquetError::Conversion(e.to_string()))? as i64;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampNanos(nanos, tz));
         }
@@ -975,14 +1249,10 @@ impl RubyValueConverter {
                     .map_err(|e| ParquetError::Conversion(e.to_string()))? as i64;
 
             // PARQUET TIMESTAMP STORAGE:
-            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true)
+            // - Schema WITH timezone -> Store as UTC (isAdjustedToUTC = true), tagged
+            //   with the schema's zone so values are read back in that zone
             // - Schema WITHOUT timezone -> Store as unzoned (isAdjustedToUTC = false)
-            // NOTE: Original timezone like "+09:00" is converted to "UTC" for storage
-            let tz = if schema_tz.is_some() {
-                Some(Arc::from("UTC")) // Always UTC, never the original timezone
-            } else {
-                None // Unzoned/local timestamp
-            };
+            let tz = schema_tz.map(Arc::from);
 
             return Ok(ParquetValue::TimestampNanos(nanos, tz));
         }
@@ -1380,7 +1650,188 @@ pub fn ruby_to_parquet(value: Value) -> Result<ParquetValue> {
     converter.infer_and_convert(value)
 }
 
+/// Options controlling how Parquet values are converted to Ruby objects
+#[derive(Debug, Default)]
+pub struct ParquetToRubyOptions {
+    /// Parse JSON columns into Ruby structures instead of returning strings
+    pub parse_json: bool,
+    /// Return enum values as Symbols instead of Strings
+    pub symbolize_enums: bool,
+    /// How geometry columns are returned
+    pub geometry_format: GeometryFormat,
+    /// How Hash keys are built for column, field and map key names
+    pub keys: KeyFormat,
+    /// Symbols already created for enum values, so each distinct value is
+    /// only looked up once per read; they're dynamic Symbols, which Ruby can
+    /// collect once the read is done
+    enum_symbols: RefCell<HashMap<Arc<str>, BoxValue<Value>>>,
+    /// Keys already built for column and struct field names, which come from
+    /// the schema and so are resolved once per read rather than per row
+    field_keys: RefCell<HashMap<Arc<str>, BoxValue<Value>>>,
+    /// Top level columns whose String values are shared frozen Strings
+    intern_columns: Vec<bool>,
+    /// Frozen Strings already created for values of interned columns
+    interned_strings: RefCell<HashMap<Arc<str>, BoxValue<Value>>>,
+    /// Ruby types for decimal, timestamp, date and binary values
+    pub conversions: Conversions,
+    /// Conversions for each top level column, in place of `conversions`
+    column_conversions: Vec<Conversions>,
+    /// Zones of timestamp columns already looked up, `None` for UTC
+    time_zones: RefCell<HashMap<Arc<str>, Option<TimeZone>>>,
+}
+
+/// Interned values kept per read; later distinct values are still frozen
+/// but no longer cached, bounding memory for columns that turn out not to
+/// be low cardinality
+const MAX_INTERNED_STRINGS: usize = 1 << 16;
+
+impl ParquetToRubyOptions {
+    pub fn with_parse_json(mut self, parse_json: bool) -> Self {
+        self.parse_json = parse_json;
+        self
+    }
+
+    pub fn with_symbolize_enums(mut self, symbolize_enums: bool) -> Self {
+        self.symbolize_enums = symbolize_enums;
+        self
+    }
+
+    pub fn with_geometry_format(mut self, geometry_format: GeometryFormat) -> Self {
+        self.geometry_format = geometry_format;
+        self
+    }
+
+    pub fn with_keys(mut self, keys: KeyFormat) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Share one frozen String per distinct value of the flagged top level
+    /// columns, see [`Self::column_value`]
+    pub fn with_intern_columns(mut self, intern_columns: Vec<bool>) -> Self {
+        self.intern_columns = intern_columns;
+        self
+    }
+
+    pub fn with_conversions(mut self, conversions: Conversions) -> Self {
+        self.conversions = conversions;
+        self
+    }
+
+    /// Convert the values of each top level column as given, see
+    /// [`Self::column_value`]
+    pub fn with_column_conversions(mut self, column_conversions: Vec<Conversions>) -> Self {
+        self.column_conversions = column_conversions;
+        self
+    }
+
+    /// Conversions for the top level column at `column`
+    pub fn column_conversions(&self, column: usize) -> &Conversions {
+        self.column_conversions
+            .get(column)
+            .unwrap_or(&self.conversions)
+    }
+
+    /// Convert the value of the top level column at `column`
+    pub fn column_value(&self, column: usize, value: ParquetValue) -> Result<Value> {
+        match value {
+            ParquetValue::String(s) if self.intern_columns.get(column) == Some(&true) => {
+                self.interned_string(&s)
+            }
+            value => self.convert_with(value, self.column_conversions(column)),
+        }
+    }
+
+    /// Convert a value with `conversions` in place of the default ones
+    pub fn convert_with(&self, value: ParquetValue, conversions: &Conversions) -> Result<Value> {
+        convert_value(value, self, conversions)
+    }
+
+    fn interned_string(&self, value: &Arc<str>) -> Result<Value> {
+        if let Some(string) = self.interned_strings.borrow().get(value) {
+            return Ok(**string);
+        }
+        let ruby = Ruby::get()
+            .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
+        let string = ruby.str_new(value).to_interned_str().as_value();
+        let mut interned = self.interned_strings.borrow_mut();
+        if interned.len() < MAX_INTERNED_STRINGS {
+            interned.insert(value.clone(), BoxValue::new(string));
+        }
+        Ok(string)
+    }
+
+    /// Hash key for a column or struct field name
+    pub fn field_key(&self, name: &Arc<str>) -> Result<Value> {
+        if let Some(key) = self.field_keys.borrow().get(name) {
+            return Ok(**key);
+        }
+        let key = self.build_key(name)?;
+        self.field_keys
+            .borrow_mut()
+            .insert(name.clone(), BoxValue::new(key));
+        Ok(key)
+    }
+
+    /// Hash key for a map entry; only String keys are transformed
+    pub(crate) fn map_key(&self, key: ParquetValue) -> Result<Value> {
+        match (key, self.keys) {
+            (ParquetValue::String(s), KeyFormat::Symbol | KeyFormat::Proc(_)) => self.build_key(&s),
+            (key, _) => parquet_to_ruby_with_options(key, self),
+        }
+    }
+
+    fn build_key(&self, name: &str) -> Result<Value> {
+        let ruby = Ruby::get()
+            .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
+        match self.keys {
+            KeyFormat::String => Ok(ruby.str_new(name).to_interned_str().as_value()),
+            KeyFormat::Symbol => Ok(ruby.to_symbol(name).as_value()),
+            KeyFormat::Proc(callable) => callable
+                .funcall("call", (ruby.str_new(name),))
+                .map_err(|e| ParquetError::Conversion(format!("keys transform failed: {}", e))),
+        }
+    }
+
+    /// Zone to localise timestamps tagged `name` into; zones this build
+    /// doesn't know are read as UTC
+    fn time_zone(&self, name: &Arc<str>) -> Option<TimeZone> {
+        self.time_zones
+            .borrow_mut()
+            .entry(name.clone())
+            .or_insert_with(|| resolve_time_zone(name).ok().flatten())
+            .clone()
+    }
+
+    fn enum_symbol(&self, ruby: &Ruby, value: &Arc<str>) -> Value {
+        if let Some(symbol) = self.enum_symbols.borrow().get(value) {
+            return **symbol;
+        }
+        let symbol = ruby.to_symbol(value).as_value();
+        let mut symbols = self.enum_symbols.borrow_mut();
+        if symbols.len() < MAX_INTERNED_STRINGS {
+            symbols.insert(value.clone(), BoxValue::new(symbol));
+        }
+        symbol
+    }
+}
+
 pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
+    parquet_to_ruby_with_options(value, &ParquetToRubyOptions::default())
+}
+
+pub fn parquet_to_ruby_with_options(
+    value: ParquetValue,
+    options: &ParquetToRubyOptions,
+) -> Result<Value> {
+    convert_value(value, options, &options.conversions)
+}
+
+fn convert_value(
+    value: ParquetValue,
+    options: &ParquetToRubyOptions,
+    conversions: &Conversions,
+) -> Result<Value> {
     let ruby = Ruby::get()
         .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
 
@@ -1437,11 +1888,35 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
         }
         ParquetValue::Float64(OrderedFloat(f)) => Ok(f.into_value_with(&ruby)),
         ParquetValue::String(s) => Ok(s.into_value_with(&ruby)),
+        ParquetValue::Json(s) => {
+            if options.parse_json {
+                parse_json(&ruby, &s)
+            } else {
+                Ok(s.into_value_with(&ruby))
+            }
+        }
+        ParquetValue::Enum(s) => {
+            if options.symbolize_enums {
+                Ok(options.enum_symbol(&ruby, &s))
+            } else {
+                Ok(s.into_value_with(&ruby))
+            }
+        }
         ParquetValue::Uuid(u) => Ok(u
             .hyphenated()
             .encode_lower(&mut Uuid::encode_buffer())
             .into_value_with(&ruby)),
-        ParquetValue::Bytes(b) => Ok(ruby.enc_str_new(&b, ruby.ascii8bit_encoding()).as_value()),
+        ParquetValue::Bytes(b) => match conversions.binary {
+            BinaryFormat::Bytes => Ok(ruby.enc_str_new(&b, ruby.ascii8bit_encoding()).as_value()),
+            BinaryFormat::String => Ok(ruby.enc_str_new(&b, ruby.utf8_encoding()).as_value()),
+        },
+        ParquetValue::Geometry(wkb) => decode_geometry(&ruby, &wkb, options.geometry_format),
+        ParquetValue::Date32(days) if conversions.date == DateFormat::Integer => {
+            Ok((days as i64).into_value_with(&ruby))
+        }
+        ParquetValue::Date32(days) if conversions.date == DateFormat::String => {
+            Ok(format_date(days as i64).into_value_with(&ruby))
+        }
         ParquetValue::Date32(days) => {
             // Convert days since epoch to Date object
             let _ = ruby.require("date");
@@ -1469,6 +1944,12 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
                 .funcall("new", (year, month, day))
                 .map_err(|e| ParquetError::Conversion(e.to_string()))
         }
+        ParquetValue::Date64(millis) if conversions.date == DateFormat::Integer => {
+            Ok(millis.div_euclid(MILLIS_PER_DAY).into_value_with(&ruby))
+        }
+        ParquetValue::Date64(millis) if conversions.date == DateFormat::String => {
+            Ok(format_date(millis.div_euclid(MILLIS_PER_DAY)).into_value_with(&ruby))
+        }
         ParquetValue::Date64(millis) => {
             // Convert millis to Time object
             let time_class = ruby.class_time();
@@ -1547,12 +2028,54 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
                 )
                 .map_err(|e| ParquetError::Conversion(e.to_string()))
         }
+        ParquetValue::DurationSecond(secs) => Ok(secs.into_value_with(&ruby)),
+        ParquetValue::DurationMillis(millis) => duration_to_rational(&ruby, millis, 1_000),
+        ParquetValue::DurationMicros(micros) => duration_to_rational(&ruby, micros, 1_000_000),
+        ParquetValue::DurationNanos(nanos) => duration_to_rational(&ruby, nanos, 1_000_000_000),
+        ParquetValue::Interval(months, days, millis) => {
+            let hash = ruby.hash_new();
+            hash.aset(Symbol::new("months"), months)
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            hash.aset(Symbol::new("days"), days)
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            hash.aset(Symbol::new("millis"), millis)
+                .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            Ok(hash.as_value())
+        }
+        ParquetValue::TimestampSecond(value, _)
+        | ParquetValue::TimestampMillis(value, _)
+        | ParquetValue::TimestampMicros(value, _)
+        | ParquetValue::TimestampNanos(value, _)
+            if conversions.timestamp == TimestampFormat::Integer =>
+        {
+            Ok(value.into_value_with(&ruby))
+        }
+        ParquetValue::TimestampSecond(secs, tz)
+            if conversions.timestamp == TimestampFormat::Iso8601 =>
+        {
+            Ok(format_timestamp(secs, 1, tz.is_some()).into_value_with(&ruby))
+        }
+        ParquetValue::TimestampMillis(millis, tz)
+            if conversions.timestamp == TimestampFormat::Iso8601 =>
+        {
+            Ok(format_timestamp(millis, 1_000, tz.is_some()).into_value_with(&ruby))
+        }
+        ParquetValue::TimestampMicros(micros, tz)
+            if conversions.timestamp == TimestampFormat::Iso8601 =>
+        {
+            Ok(format_timestamp(micros, 1_000_000, tz.is_some()).into_value_with(&ruby))
+        }
+        ParquetValue::TimestampNanos(nanos, tz)
+            if conversions.timestamp == TimestampFormat::Iso8601 =>
+        {
+            Ok(format_timestamp(nanos, 1_000_000_000, tz.is_some()).into_value_with(&ruby))
+        }
         ParquetValue::TimestampSecond(secs, tz) => {
             let time_class = ruby.class_time();
             let time = time_class
                 .funcall::<_, _, Value>("at", (secs, kwargs!("in" => "UTC")))
                 .map_err(|e| ParquetError::Conversion(e.to_string()))?;
-            apply_timezone(time, &tz)
+            apply_timezone(time, &tz, secs, options)
         }
         ParquetValue::TimestampMillis(millis, tz) => {
             let time_class = ruby.class_time();
@@ -1561,7 +2084,7 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
             let time = time_class
                 .funcall::<_, _, Value>("at", (secs, usec, kwargs!("in" => "UTC")))
                 .map_err(|e| ParquetError::Conversion(e.to_string()))?;
-            apply_timezone(time, &tz)
+            apply_timezone(time, &tz, millis.div_euclid(1000), options)
         }
         ParquetValue::TimestampMicros(micros, tz) => {
             let time_class = ruby.class_time();
@@ -1570,7 +2093,7 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
             let time = time_class
                 .funcall::<_, _, Value>("at", (secs, usec, kwargs!("in" => "UTC")))
                 .map_err(|e| ParquetError::Conversion(e.to_string()))?;
-            apply_timezone(time, &tz)
+            apply_timezone(time, &tz, micros.div_euclid(1_000_000), options)
         }
         ParquetValue::TimestampNanos(nanos, tz) => {
             let time_class = ruby.class_time();
@@ -1588,7 +2111,15 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
                     ),
                 )
                 .map_err(|e| ParquetError::Conversion(e.to_string()))?;
-            apply_timezone(time, &tz)
+            apply_timezone(time, &tz, nanos.div_euclid(1_000_000_000), options)
+        }
+        ParquetValue::Decimal128(val, scale) if conversions.decimal == DecimalFormat::Float => {
+            Ok(format_decimal128(val, scale)
+                .parse::<f64>()?
+                .into_value_with(&ruby))
+        }
+        ParquetValue::Decimal128(val, scale) if conversions.decimal == DecimalFormat::String => {
+            Ok(format_decimal128(val, scale).into_value_with(&ruby))
         }
         ParquetValue::Decimal128(val, scale) => {
             // Load BigDecimal if needed
@@ -1601,6 +2132,14 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
                 .funcall("BigDecimal", (str_val,))
                 .map_err(|e| ParquetError::Conversion(e.to_string()))
         }
+        ParquetValue::Decimal256(val, scale) if conversions.decimal == DecimalFormat::Float => {
+            Ok(format_decimal256(&val, scale)
+                .parse::<f64>()?
+                .into_value_with(&ruby))
+        }
+        ParquetValue::Decimal256(val, scale) if conversions.decimal == DecimalFormat::String => {
+            Ok(format_decimal256(&val, scale).into_value_with(&ruby))
+        }
         ParquetValue::Decimal256(val, scale) => {
             // Load BigDecimal if needed
             let _ = ruby.require("bigdecimal");
@@ -1615,7 +2154,7 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
         ParquetValue::List(list) => {
             let array = ruby.ary_new_capa(list.len());
             for item in list {
-                let ruby_val = parquet_to_ruby(item)?;
+                let ruby_val = convert_value(item, options, conversions)?;
                 array
                     .push(ruby_val)
                     .map_err(|e| ParquetError::Conversion(e.to_string()))?;
@@ -1625,19 +2164,20 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
         ParquetValue::Map(map) => {
             let hash = ruby.hash_new();
             for (k, v) in map {
-                let ruby_key = parquet_to_ruby(k)?;
-                let ruby_val = parquet_to_ruby(v)?;
+                let ruby_key = options.map_key(k)?;
+                let ruby_val = convert_value(v, options, conversions)?;
                 hash.aset(ruby_key, ruby_val)
                     .map_err(|e| ParquetError::Conversion(e.to_string()))?;
             }
             Ok(hash.as_value())
         }
+        ParquetValue::Variant(variant) => convert_value(variant.decode()?, options, conversions),
         ParquetValue::Record(record) => {
             // Convert Record to Ruby Hash
             let hash = ruby.hash_new();
             for (field_name, field_value) in record {
-                let ruby_key = ruby.str_new(&field_name);
-                let ruby_val = parquet_to_ruby(field_value)?;
+                let ruby_key = options.field_key(&field_name)?;
+                let ruby_val = convert_value(field_value, options, conversions)?;
                 hash.aset(ruby_key, ruby_val)
                     .map_err(|e| ParquetError::Conversion(e.to_string()))?;
             }
@@ -1646,6 +2186,64 @@ pub fn parquet_to_ruby(value: ParquetValue) -> Result<Value> {
     }
 }
 
+/// Convert a sub-second duration to a Rational number of seconds
+fn duration_to_rational(ruby: &Ruby, value: i64, units_per_second: i64) -> Result<Value> {
+    ruby.module_kernel()
+        .funcall("Rational", (value, units_per_second))
+        .map_err(|e| ParquetError::Conversion(e.to_string()))
+}
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// `"YYYY-MM-DD"` for the date `days` after the epoch
+fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// ISO 8601 for a timestamp of `value` units since the epoch, with as many
+/// fractional digits as the unit has and a `Z` when adjusted to UTC
+fn format_timestamp(value: i64, units_per_second: i64, utc: bool) -> String {
+    let secs = value.div_euclid(units_per_second);
+    let fraction = value.rem_euclid(units_per_second);
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let secs_of_day = secs.rem_euclid(86_400);
+
+    let mut formatted = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    );
+    if units_per_second > 1 {
+        let digits = units_per_second.ilog10() as usize;
+        formatted.push_str(&format!(".{:0width$}", fraction, width = digits));
+    }
+    if utc {
+        formatted.push('Z');
+    }
+    formatted
+}
+
+/// Proleptic Gregorian year, month and day of the date `days` after the
+/// epoch, after Howard Hinnant's `civil_from_days`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 // Helper functions for decimal formatting
 
 fn format_decimal128(value: i128, scale: i8) -> String {
@@ -1710,20 +2308,24 @@ fn format_decimal256(value: &num::BigInt, scale: i8) -> String {
 /// - If schema has ANY timezone -> values are UTC (isAdjustedToUTC = true)
 /// - If schema has NO timezone -> values are local/unzoned (isAdjustedToUTC = false)
 ///
-/// NOTE: The actual timezone string in the schema is irrelevant for reading.
-/// Whether it's "UTC", "+09:00", or "America/New_York", the stored values
-/// are ALWAYS UTC-normalized. We return them as UTC Time objects.
-fn apply_timezone(time: Value, tz: &Option<std::sync::Arc<str>>) -> Result<Value> {
-    let _ruby = Ruby::get()
-        .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
-
+/// Stored values are always UTC-normalized. A zone other than UTC from the
+/// Arrow schema, like "+09:00" or "America/New_York", gives the Time the
+/// offset that zone had at `secs` seconds since the epoch.
+fn apply_timezone(
+    time: Value,
+    tz: &Option<std::sync::Arc<str>>,
+    secs: i64,
+    options: &ParquetToRubyOptions,
+) -> Result<Value> {
     match tz {
-        Some(_) => {
-            // ANY timezone = UTC storage (Parquet spec requirement)
-            // Original timezone like "+09:00" is NOT preserved
-            time.funcall("utc", ())
-                .map_err(|e| ParquetError::Conversion(e.to_string()))
-        }
+        Some(tz) => match options.time_zone(tz) {
+            Some(zone) => time
+                .funcall("localtime", (utc_offset(&zone, secs)?,))
+                .map_err(|e| ParquetError::Conversion(e.to_string())),
+            None => time
+                .funcall("utc", ())
+                .map_err(|e| ParquetError::Conversion(e.to_string())),
+        },
         None => {
             // No timezone = local/unzoned timestamp
             // This is a "wall clock" time without timezone context
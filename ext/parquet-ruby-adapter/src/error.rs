@@ -1,6 +1,11 @@
-use magnus::{Error as MagnusError, Ruby};
+use arrow_schema::ArrowError;
+use magnus::value::ReprValue;
+use magnus::{
+    Class, Error as MagnusError, ExceptionClass, IntoValue, Module, RModule, Ruby, Value,
+};
+use parquet::errors::ParquetError as FormatError;
 use parquet_core::ParquetError as CoreParquetError;
-use std::fmt::Display;
+use std::fmt::{self, Display};
 use thiserror::Error;
 
 /// Error type for parquet-ruby-adapter
@@ -14,6 +19,14 @@ pub enum RubyAdapterError {
     #[error("Ruby error: {0}")]
     Ruby(String),
 
+    /// A Ruby exception raised during the operation, re-raised as is
+    #[error("{0}")]
+    Raised(MagnusError),
+
+    /// A value that couldn't be converted, with where it was found
+    #[error("{0}")]
+    Conversion(Box<ConversionError>),
+
     /// IO errors
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -41,6 +54,78 @@ pub enum RubyAdapterError {
 
 pub type Result<T> = std::result::Result<T, RubyAdapterError>;
 
+/// A value that couldn't be converted, raised as `Parquet::ConversionError`
+///
+/// Each location field becomes the exception attribute of the same name and
+/// is nil when unknown.
+#[derive(Debug, Default)]
+pub struct ConversionError {
+    pub message: String,
+    /// Index of the row within the data being read or written
+    pub row_index: Option<u64>,
+    /// Name of the top level column
+    pub column_path: Option<String>,
+    /// Parquet type the value was converted to
+    pub expected_type: Option<String>,
+    /// Ruby class of the value being written
+    pub value_class: Option<String>,
+}
+
+impl ConversionError {
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_row_index(mut self, row_index: u64) -> Self {
+        self.row_index = Some(row_index);
+        self
+    }
+
+    pub fn with_column_path<S: Into<String>>(mut self, column_path: S) -> Self {
+        self.column_path = Some(column_path.into());
+        self
+    }
+
+    pub fn with_expected_type<S: Into<String>>(mut self, expected_type: S) -> Self {
+        self.expected_type = Some(expected_type.into());
+        self
+    }
+
+    pub fn with_value_class<S: Into<String>>(mut self, value_class: S) -> Self {
+        self.value_class = Some(value_class.into());
+        self
+    }
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(column) = &self.column_path {
+            write!(f, " (column {:?}", column)?;
+            if let Some(row) = self.row_index {
+                write!(f, ", row {}", row)?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ConversionError> for RubyAdapterError {
+    fn from(err: ConversionError) -> Self {
+        RubyAdapterError::Conversion(Box::new(err))
+    }
+}
+
+impl From<ConversionError> for MagnusError {
+    fn from(err: ConversionError) -> Self {
+        RubyAdapterError::from(err).into()
+    }
+}
+
 impl RubyAdapterError {
     /// Create a new Ruby error
     pub fn ruby<S: Into<String>>(msg: S) -> Self {
@@ -74,30 +159,140 @@ impl RubyAdapterError {
 }
 
 /// Convert RubyAdapterError to MagnusError
+///
+/// Errors are raised as subclasses of `Parquet::Error`, defined in
+/// lib/parquet/errors.rb; invalid input from the adapter's own argument
+/// parsing stays an `ArgumentError`.
 impl From<RubyAdapterError> for MagnusError {
     fn from(err: RubyAdapterError) -> Self {
-        match Ruby::get() {
-            Ok(ruby) => match &err {
-                RubyAdapterError::Io(_) => {
-                    MagnusError::new(ruby.exception_io_error(), err.to_string())
-                }
-                RubyAdapterError::TypeConversion(_) => {
-                    MagnusError::new(ruby.exception_type_error(), err.to_string())
-                }
-                RubyAdapterError::InvalidInput(_) => {
-                    MagnusError::new(ruby.exception_arg_error(), err.to_string())
-                }
-                _ => MagnusError::new(ruby.exception_runtime_error(), err.to_string()),
-            },
-            Err(_) => {
-                // Fallback if we can't get Ruby runtime
-                MagnusError::new(
-                    magnus::exception::runtime_error(),
-                    format!("Failed to get Ruby runtime: {}", err),
-                )
+        exception_for(err, false)
+    }
+}
+
+/// Build the Ruby exception for `err`
+///
+/// Parquet format errors only mean a damaged file while `reading` one;
+/// anywhere else they're raised as plain `Parquet::Error`.
+fn exception_for(err: RubyAdapterError, reading: bool) -> MagnusError {
+    let ruby = match Ruby::get() {
+        Ok(ruby) => ruby,
+        Err(_) => {
+            // Fallback if we can't get Ruby runtime
+            return MagnusError::new(
+                magnus::exception::runtime_error(),
+                format!("Failed to get Ruby runtime: {}", err),
+            );
+        }
+    };
+    match err {
+        RubyAdapterError::Raised(err) => err,
+        RubyAdapterError::Parquet(err) => {
+            MagnusError::new(core_error_class(&ruby, &err, reading), err.to_string())
+        }
+        RubyAdapterError::Conversion(err) => conversion_exception(&ruby, &err),
+        RubyAdapterError::Io(_) => {
+            MagnusError::new(parquet_exception(&ruby, "IOError"), err.to_string())
+        }
+        RubyAdapterError::TypeConversion(_) => {
+            conversion_exception(&ruby, &ConversionError::new(err.to_string()))
+        }
+        RubyAdapterError::SchemaConversion(_) => {
+            MagnusError::new(parquet_exception(&ruby, "SchemaError"), err.to_string())
+        }
+        RubyAdapterError::InvalidInput(_) => {
+            MagnusError::new(ruby.exception_arg_error(), err.to_string())
+        }
+        _ => MagnusError::new(parquet_exception(&ruby, "Error"), err.to_string()),
+    }
+}
+
+/// Raise `err` as the matching `Parquet::Error` subclass
+pub fn to_magnus_error<E: Into<RubyAdapterError>>(err: E) -> MagnusError {
+    MagnusError::from(err.into())
+}
+
+/// Raise an error from opening or decoding a Parquet file
+///
+/// Like [`to_magnus_error`], but format and EOF failures become
+/// `Parquet::CorruptFileError`.
+pub fn to_read_error<E: Into<RubyAdapterError>>(err: E) -> MagnusError {
+    exception_for(err.into(), true)
+}
+
+/// `Parquet::<name>`, or RuntimeError if lib/parquet/errors.rb isn't loaded
+fn parquet_exception(ruby: &Ruby, name: &str) -> ExceptionClass {
+    ruby.class_object()
+        .const_get::<_, RModule>("Parquet")
+        .and_then(|module| module.const_get::<_, ExceptionClass>(name))
+        .unwrap_or_else(|_| ruby.exception_runtime_error())
+}
+
+fn core_error_class(ruby: &Ruby, err: &CoreParquetError, reading: bool) -> ExceptionClass {
+    let name = match err {
+        CoreParquetError::Io(_) => "IOError",
+        CoreParquetError::Schema(_) => "SchemaError",
+        CoreParquetError::Conversion(_)
+        | CoreParquetError::Utf8(_)
+        | CoreParquetError::ParseInt(_)
+        | CoreParquetError::ParseFloat(_) => "ConversionError",
+        CoreParquetError::Unsupported(_) => "UnsupportedTypeError",
+        CoreParquetError::Parquet(err) => match err {
+            FormatError::NYI(_) => "UnsupportedTypeError",
+            FormatError::External(_) => "Error",
+            FormatError::General(_)
+            | FormatError::EOF(_)
+            | FormatError::NeedMoreData(_)
+            | FormatError::IndexOutOfBound(_, _)
+            | FormatError::ArrowError(_)
+                if reading =>
+            {
+                "CorruptFileError"
             }
+            _ => "Error",
+        },
+        CoreParquetError::Arrow(err) => match err {
+            ArrowError::NotYetImplemented(_) => "UnsupportedTypeError",
+            ArrowError::SchemaError(_) => "SchemaError",
+            ArrowError::CastError(_) | ArrowError::ParseError(_) => "ConversionError",
+            ArrowError::IoError(_, _) => "IOError",
+            ArrowError::ParquetError(_) if reading => "CorruptFileError",
+            _ => "Error",
+        },
+        CoreParquetError::InvalidArgument(_)
+        | CoreParquetError::DataValidation(_)
+        | CoreParquetError::Internal(_) => "Error",
+    };
+    parquet_exception(ruby, name)
+}
+
+/// `Parquet::ConversionError` with its location attributes set
+fn conversion_exception(ruby: &Ruby, err: &ConversionError) -> MagnusError {
+    let class = parquet_exception(ruby, "ConversionError");
+    let exception = match class.new_instance((err.to_string(),)) {
+        Ok(exception) => exception,
+        Err(e) => return e,
+    };
+    let attributes: [(&str, Value); 4] = [
+        ("@row_index", err.row_index.into_value_with(ruby)),
+        (
+            "@column_path",
+            err.column_path.clone().into_value_with(ruby),
+        ),
+        (
+            "@expected_type",
+            err.expected_type.clone().into_value_with(ruby),
+        ),
+        (
+            "@value_class",
+            err.value_class.clone().into_value_with(ruby),
+        ),
+    ];
+    for (name, value) in attributes {
+        if let Err(e) = exception.funcall::<_, _, Value>("instance_variable_set", (name, value)) {
+            return e;
         }
     }
+    MagnusError::from(exception)
 }
 
 /// Extension trait to convert errors to MagnusError at the boundary
@@ -140,9 +335,9 @@ where
     }
 }
 
-/// Convert from MagnusError to RubyAdapterError
+/// Convert from MagnusError to RubyAdapterError, keeping the exception
 impl From<MagnusError> for RubyAdapterError {
     fn from(err: MagnusError) -> Self {
-        RubyAdapterError::Ruby(err.to_string())
+        RubyAdapterError::Raised(err)
     }
 }
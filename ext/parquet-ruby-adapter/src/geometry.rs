@@ -0,0 +1,186 @@
+//! Conversion between Ruby geometry representations and WKB
+//!
+//! Geometry columns accept WKT strings, binary WKB strings, bare coordinate
+//! arrays and GeoJSON style hashes. Reads return WKT by default, or the same
+//! GeoJSON style hashes when `geometry_format: :coordinates` is given.
+
+use crate::json::{json_to_ruby, ruby_to_json};
+use crate::types::GeometryFormat;
+use bytes::Bytes;
+use magnus::encoding::EncodingCapable;
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, IntoValue, RString, Ruby, TryConvert, Value};
+use parquet_core::geometry::Coord;
+use parquet_core::{Geometry, ParquetError, Result};
+use serde_json::{json, Value as JsonValue};
+
+/// Encode a Ruby geometry as WKB
+pub fn encode_geometry(ruby: &Ruby, value: Value) -> Result<Bytes> {
+    if value.is_kind_of(ruby.class_string()) {
+        let s: RString = TryConvert::try_convert(value)
+            .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+
+        // Binary strings are taken to be WKB already
+        if s.enc_get() == ruby.ascii8bit_encindex() {
+            let wkb = unsafe { Bytes::copy_from_slice(s.as_slice()) };
+            Geometry::from_wkb(&wkb)?;
+            return Ok(wkb);
+        }
+
+        let wkt = s
+            .to_string()
+            .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+        return Ok(Geometry::from_wkt(&wkt)?.to_wkb().into());
+    }
+
+    if value.is_kind_of(ruby.class_array()) || value.is_kind_of(ruby.class_hash()) {
+        let json = ruby_to_json(ruby, value)?;
+        let geometry = match json {
+            JsonValue::Array(_) => geometry_from_coordinates(&json)?,
+            _ => geometry_from_geojson(&json)?,
+        };
+        return Ok(geometry.to_wkb().into());
+    }
+
+    Err(ParquetError::Conversion(format!(
+        "Cannot convert {} to geometry, expected WKT, WKB, coordinates or a GeoJSON style Hash",
+        value.class()
+    )))
+}
+
+/// Decode WKB into the requested Ruby representation
+pub fn decode_geometry(ruby: &Ruby, wkb: &[u8], format: GeometryFormat) -> Result<Value> {
+    match format {
+        GeometryFormat::Wkb => Ok(ruby.enc_str_new(wkb, ruby.ascii8bit_encoding()).as_value()),
+        GeometryFormat::Wkt => Ok(Geometry::from_wkb(wkb)?.to_wkt().into_value_with(ruby)),
+        GeometryFormat::Coordinates => {
+            json_to_ruby(ruby, geometry_to_geojson(&Geometry::from_wkb(wkb)?))
+        }
+    }
+}
+
+/// Infer the geometry type from how deeply coordinates are nested:
+/// a point, a line string, a polygon or a multi polygon
+fn geometry_from_coordinates(coordinates: &JsonValue) -> Result<Geometry> {
+    let mut depth = 0;
+    let mut current = coordinates;
+    while let JsonValue::Array(items) = current {
+        depth += 1;
+        match items.first() {
+            Some(first) => current = first,
+            None => break,
+        }
+    }
+
+    match depth {
+        1 => Ok(Geometry::Point(Some(coord(coordinates)?))),
+        2 => Ok(Geometry::LineString(coords(coordinates)?)),
+        3 => Ok(Geometry::Polygon(rings(coordinates)?)),
+        4 => Ok(Geometry::MultiPolygon(
+            list(coordinates)?
+                .iter()
+                .map(rings)
+                .collect::<Result<_>>()?,
+        )),
+        _ => Err(ParquetError::Conversion(
+            "Geometry coordinates must be nested 1 to 4 arrays deep".to_string(),
+        )),
+    }
+}
+
+fn geometry_from_geojson(json: &JsonValue) -> Result<Geometry> {
+    let kind = json
+        .get("type")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| ParquetError::Conversion("Geometry Hash needs a type".to_string()))?;
+
+    if kind.eq_ignore_ascii_case("GeometryCollection") {
+        let geometries = match json.get("geometries") {
+            Some(geometries) => list(geometries)?.as_slice(),
+            None => &[],
+        };
+        return Ok(Geometry::GeometryCollection(
+            geometries
+                .iter()
+                .map(geometry_from_geojson)
+                .collect::<Result<_>>()?,
+        ));
+    }
+
+    // Missing coordinates describe an empty geometry
+    let empty = JsonValue::Array(Vec::new());
+    let coordinates = match json.get("coordinates") {
+        None | Some(JsonValue::Null) => &empty,
+        Some(coordinates) => coordinates,
+    };
+
+    match kind.to_ascii_lowercase().as_str() {
+        "point" if coordinates == &empty => Ok(Geometry::Point(None)),
+        "point" => Ok(Geometry::Point(Some(coord(coordinates)?))),
+        "linestring" => Ok(Geometry::LineString(coords(coordinates)?)),
+        "polygon" => Ok(Geometry::Polygon(rings(coordinates)?)),
+        "multipoint" => Ok(Geometry::MultiPoint(coords(coordinates)?)),
+        "multilinestring" => Ok(Geometry::MultiLineString(rings(coordinates)?)),
+        "multipolygon" => Ok(Geometry::MultiPolygon(
+            list(coordinates)?
+                .iter()
+                .map(rings)
+                .collect::<Result<_>>()?,
+        )),
+        _ => Err(unknown_type(kind)),
+    }
+}
+
+fn geometry_to_geojson(geometry: &Geometry) -> JsonValue {
+    let coordinates = match geometry {
+        Geometry::Point(coord) => json!(coord.clone().unwrap_or_default()),
+        Geometry::LineString(coords) | Geometry::MultiPoint(coords) => json!(coords),
+        Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => json!(rings),
+        Geometry::MultiPolygon(polygons) => json!(polygons),
+        Geometry::GeometryCollection(geometries) => {
+            let geometries = geometries
+                .iter()
+                .map(geometry_to_geojson)
+                .collect::<Vec<_>>();
+            return json!({ "type": geometry.type_name(), "geometries": geometries });
+        }
+    };
+    json!({ "type": geometry.type_name(), "coordinates": coordinates })
+}
+
+fn list(json: &JsonValue) -> Result<&Vec<JsonValue>> {
+    json.as_array().ok_or_else(|| {
+        ParquetError::Conversion(format!("Expected an Array of coordinates, got {}", json))
+    })
+}
+
+fn rings(json: &JsonValue) -> Result<Vec<Vec<Coord>>> {
+    list(json)?.iter().map(coords).collect()
+}
+
+fn coords(json: &JsonValue) -> Result<Vec<Coord>> {
+    list(json)?.iter().map(coord).collect()
+}
+
+fn coord(json: &JsonValue) -> Result<Coord> {
+    let values = list(json)?
+        .iter()
+        .map(|v| {
+            v.as_f64().ok_or_else(|| {
+                ParquetError::Conversion(format!("Geometry coordinates must be numbers, got {}", v))
+            })
+        })
+        .collect::<Result<Coord>>()?;
+
+    if !(2..=4).contains(&values.len()) {
+        return Err(ParquetError::Conversion(format!(
+            "A coordinate needs 2 to 4 values, got {}",
+            values.len()
+        )));
+    }
+    Ok(values)
+}
+
+fn unknown_type(kind: &str) -> ParquetError {
+    ParquetError::Conversion(format!("Unknown geometry type {:?}", kind))
+}
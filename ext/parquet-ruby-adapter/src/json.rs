@@ -0,0 +1,166 @@
+//! JSON encoding and decoding for columns annotated with the JSON logical type
+//!
+//! Values are serialized in Rust rather than through Ruby's `JSON` library so
+//! that writing a JSON column doesn't require the `json` gem to be loaded.
+
+use magnus::r_hash::ForEach;
+use magnus::value::ReprValue;
+use magnus::{
+    Error as MagnusError, IntoValue, RArray, RHash, RString, Ruby, Symbol, TryConvert, Value,
+};
+use parquet_core::{ParquetError, Result};
+use serde_json::{Map, Number, Value as JsonValue};
+use std::sync::Arc;
+
+/// Encode a Ruby value for storage in a JSON column
+///
+/// Strings are treated as already encoded JSON and are validated; any other
+/// value (Hash, Array, scalars) is serialized.
+pub fn encode_json(value: Value) -> Result<Arc<str>> {
+    let ruby = Ruby::get()
+        .map_err(|_| ParquetError::Conversion("Failed to get Ruby runtime".to_string()))?;
+
+    if value.is_kind_of(ruby.class_string()) {
+        let s: String = TryConvert::try_convert(value)
+            .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+        serde_json::from_str::<JsonValue>(&s)
+            .map_err(|e| ParquetError::Conversion(format!("Invalid JSON string: {}", e)))?;
+        return Ok(s.into());
+    }
+
+    let json = ruby_to_json(&ruby, value)?;
+    Ok(json.to_string().into())
+}
+
+/// Parse JSON text into Ruby Hash, Array and scalar values
+pub fn parse_json(ruby: &Ruby, text: &str) -> Result<Value> {
+    let json: JsonValue = serde_json::from_str(text)
+        .map_err(|e| ParquetError::Conversion(format!("Invalid JSON value: {}", e)))?;
+    json_to_ruby(ruby, json)
+}
+
+pub(crate) fn ruby_to_json(ruby: &Ruby, value: Value) -> Result<JsonValue> {
+    if value.is_nil() {
+        return Ok(JsonValue::Null);
+    }
+
+    if value.is_kind_of(ruby.class_true_class()) {
+        return Ok(JsonValue::Bool(true));
+    }
+
+    if value.is_kind_of(ruby.class_false_class()) {
+        return Ok(JsonValue::Bool(false));
+    }
+
+    if value.is_kind_of(ruby.class_integer()) {
+        if let Ok(i) = i64::try_convert(value) {
+            return Ok(JsonValue::Number(i.into()));
+        }
+        let u = u64::try_convert(value).map_err(|_| {
+            ParquetError::Conversion("Integer is too large to encode as JSON".to_string())
+        })?;
+        return Ok(JsonValue::Number(u.into()));
+    }
+
+    if value.is_kind_of(ruby.class_float()) {
+        let f = f64::try_convert(value)
+            .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+        return Number::from_f64(f)
+            .map(JsonValue::Number)
+            .ok_or_else(|| ParquetError::Conversion(format!("Cannot encode {} as JSON", f)));
+    }
+
+    if value.is_kind_of(ruby.class_string()) || value.is_kind_of(ruby.class_symbol()) {
+        return Ok(JsonValue::String(value_to_string(value)?));
+    }
+
+    if value.is_kind_of(ruby.class_array()) {
+        let array: RArray = TryConvert::try_convert(value)
+            .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+        let mut items = Vec::with_capacity(array.len());
+        for item in array.into_iter() {
+            items.push(ruby_to_json(ruby, item)?);
+        }
+        return Ok(JsonValue::Array(items));
+    }
+
+    if value.is_kind_of(ruby.class_hash()) {
+        let hash: RHash = TryConvert::try_convert(value)
+            .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+        let mut object = Map::with_capacity(hash.len());
+        let mut conversion_error = None;
+
+        hash.foreach(|key: Value, val: Value| {
+            let entry =
+                value_to_string(key).and_then(|key| ruby_to_json(ruby, val).map(|val| (key, val)));
+            match entry {
+                Ok((key, val)) => {
+                    object.insert(key, val);
+                    Ok(ForEach::Continue)
+                }
+                Err(e) => {
+                    conversion_error = Some(e);
+                    Ok(ForEach::Stop)
+                }
+            }
+        })
+        .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+
+        if let Some(e) = conversion_error {
+            return Err(e);
+        }
+        return Ok(JsonValue::Object(object));
+    }
+
+    // Mirror Ruby's JSON library, which falls back to #to_s for other objects
+    Ok(JsonValue::String(value_to_string(value)?))
+}
+
+pub(crate) fn json_to_ruby(ruby: &Ruby, json: JsonValue) -> Result<Value> {
+    match json {
+        JsonValue::Null => Ok(ruby.qnil().as_value()),
+        JsonValue::Bool(b) => Ok(b.into_value_with(ruby)),
+        JsonValue::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Ok(i.into_value_with(ruby))
+            } else if let Some(u) = n.as_u64() {
+                Ok(u.into_value_with(ruby))
+            } else {
+                Ok(n.as_f64().unwrap_or(f64::NAN).into_value_with(ruby))
+            }
+        }
+        JsonValue::String(s) => Ok(ruby.str_new(&s).as_value()),
+        JsonValue::Array(items) => {
+            let array = ruby.ary_new_capa(items.len());
+            for item in items {
+                array
+                    .push(json_to_ruby(ruby, item)?)
+                    .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            }
+            Ok(array.as_value())
+        }
+        JsonValue::Object(object) => {
+            let hash = ruby.hash_new_capa(object.len());
+            for (key, val) in object {
+                hash.aset(ruby.str_new(&key), json_to_ruby(ruby, val)?)
+                    .map_err(|e| ParquetError::Conversion(e.to_string()))?;
+            }
+            Ok(hash.as_value())
+        }
+    }
+}
+
+fn value_to_string(value: Value) -> Result<String> {
+    if let Some(symbol) = Symbol::from_value(value) {
+        return symbol
+            .name()
+            .map(|name| name.into_owned())
+            .map_err(|e| ParquetError::Conversion(e.to_string()));
+    }
+
+    let s: RString = value
+        .funcall("to_s", ())
+        .map_err(|e: MagnusError| ParquetError::Conversion(e.to_string()))?;
+    s.to_string()
+        .map_err(|e| ParquetError::Conversion(e.to_string()))
+}
@@ -38,13 +38,30 @@
 //! - Automatic type inference from data
 
 pub mod error;
-pub use error::{ErrorContext, IntoMagnusError, Result, RubyAdapterError};
+pub use error::{
+    to_magnus_error, to_read_error, ConversionError, ErrorContext, IntoMagnusError, Result,
+    RubyAdapterError,
+};
+
+pub mod aggregate;
+pub use aggregate::{count, max, min, null_count};
 
 pub mod chunk_reader;
 pub use chunk_reader::CloneableChunkReader;
 
+pub mod convert;
+pub use convert::{
+    from_arrow_ipc, from_csv, from_ndjson, to_arrow_ipc, to_csv, to_ndjson, write_arrow,
+};
+
 pub mod converter;
-pub use converter::RubyValueConverter;
+pub use converter::{ParquetToRubyOptions, RubyValueConverter};
+
+pub mod json;
+pub use json::{encode_json, parse_json};
+
+pub mod geometry;
+pub use geometry::{decode_geometry, encode_geometry};
 
 pub mod io;
 pub use io::{create_reader, is_io_like, RubyIO, RubyIOReader, RubyIOWriter};
@@ -58,6 +75,12 @@ pub use schema::{
     process_schema_value, ruby_schema_to_parquet, RubySchemaBuilder,
 };
 
+pub mod struct_row;
+pub use struct_row::StructRowBuilder;
+
+pub mod statistics;
+pub use statistics::statistics;
+
 pub mod string_cache;
 pub use string_cache::StringCache;
 
@@ -71,20 +94,37 @@ pub use metadata::{parse_metadata, RubyParquetMetaData};
 
 pub mod types;
 pub use types::{
-    ColumnEnumeratorArgs, ParquetWriteArgs, ParserResultType, RowEnumeratorArgs, WriterOutput,
+    BinaryFormat, ColumnEnumeratorArgs, Conversions, ConvertOptions, CsvArgs, DateFormat,
+    DecimalFormat, GeometryFormat, KeyFormat, OnError, ParquetWriteArgs, ParserResultType,
+    RowEnumeratorArgs, TimestampFormat, WriterOutput,
 };
 
 pub mod utils;
 pub use utils::{
     create_column_enumerator, create_row_enumerator, estimate_parquet_value_size,
-    estimate_row_size, handle_block_or_enum, parse_compression, parse_parquet_write_args,
+    estimate_row_size, handle_block_or_enum, open_chunk_reader, parse_compression,
+    parse_parquet_write_args,
 };
 
 pub mod reader;
 pub use reader::{each_column, each_row};
 
+pub mod salvage;
+pub use salvage::salvage;
+
+pub mod sample;
+pub use sample::sample;
+
+pub mod verify;
+pub use verify::verify;
+
 pub mod writer;
-pub use writer::{create_writer, finalize_writer, write_columns, write_rows};
+pub use writer::{
+    create_writer, finalize_writer, finish_output, open_output, write_columns, write_rows,
+};
+
+pub mod timezone;
+pub use timezone::resolve_time_zone;
 
 pub mod try_into_value;
 pub use try_into_value::TryIntoValue;
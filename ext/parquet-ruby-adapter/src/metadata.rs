@@ -1,9 +1,15 @@
 use magnus::value::ReprValue;
 use magnus::{Error as MagnusError, IntoValue, Ruby, Value};
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet::arrow::parquet_to_arrow_schema;
 use parquet::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+use parquet::file::reader::ChunkReader;
+use parquet_core::statistics::page_statistics;
+use parquet_core::ParquetValue;
 use std::fs::File;
 
-use crate::error::{IntoMagnusError, Result, RubyAdapterError};
+use crate::converter::parquet_to_ruby;
+use crate::error::{to_read_error, Result, RubyAdapterError};
 use crate::io::{RubyIOReader, ThreadSafeRubyIOReader};
 use crate::TryIntoValue;
 
@@ -236,9 +242,23 @@ impl TryIntoValue for RubyParquetMetaData {
         hash.aset("schema", schema_hash)
             .map_err(|e| RubyAdapterError::metadata(format!("Failed to set schema: {}", e)))?;
 
+        // Page index values are decoded through the Arrow types, like when reading rows
+        let page_index_schema = match metadata.offset_index() {
+            Some(_) => Some(
+                parquet_to_arrow_schema(
+                    file_metadata.schema_descr(),
+                    file_metadata.key_value_metadata(),
+                )
+                .map_err(|e| {
+                    RubyAdapterError::metadata(format!("Failed to read Arrow schema: {}", e))
+                })?,
+            ),
+            None => None,
+        };
+
         // Convert row_groups to a Ruby array since &[RowGroupMetaData] doesn't implement IntoValue
         let row_groups_array = handle.ary_new();
-        for row_group in row_groups.iter() {
+        for (rg_idx, row_group) in row_groups.iter().enumerate() {
             let rg_hash = handle.hash_new();
             rg_hash
                 .aset("num_columns", row_group.num_columns())
@@ -269,6 +289,56 @@ impl TryIntoValue for RubyParquetMetaData {
                     RubyAdapterError::metadata(format!("Failed to set compressed_size: {}", e))
                 })?;
 
+            // The order the writer declared the rows to be sorted in
+            let sorting_columns = match row_group.sorting_columns() {
+                Some(sorting_columns) => {
+                    let sorting_array = handle.ary_new();
+                    for sorting_column in sorting_columns {
+                        let sort_hash = handle.hash_new();
+                        let column_idx = sorting_column.column_idx as usize;
+                        let path = (column_idx < row_group.num_columns())
+                            .then(|| row_group.column(column_idx).column_path().string());
+                        sort_hash.aset("column_path", path).map_err(|e| {
+                            RubyAdapterError::metadata(format!("Failed to set column_path: {}", e))
+                        })?;
+                        sort_hash
+                            .aset("column_idx", sorting_column.column_idx)
+                            .map_err(|e| {
+                                RubyAdapterError::metadata(format!(
+                                    "Failed to set column_idx: {}",
+                                    e
+                                ))
+                            })?;
+                        sort_hash
+                            .aset("descending", sorting_column.descending)
+                            .map_err(|e| {
+                                RubyAdapterError::metadata(format!(
+                                    "Failed to set descending: {}",
+                                    e
+                                ))
+                            })?;
+                        sort_hash
+                            .aset("nulls_first", sorting_column.nulls_first)
+                            .map_err(|e| {
+                                RubyAdapterError::metadata(format!(
+                                    "Failed to set nulls_first: {}",
+                                    e
+                                ))
+                            })?;
+                        sorting_array.push(sort_hash).map_err(|e| {
+                            RubyAdapterError::metadata(format!("Failed to push sort_hash: {}", e))
+                        })?;
+                    }
+                    Some(sorting_array)
+                }
+                None => None,
+            };
+            rg_hash
+                .aset("sorting_columns", sorting_columns)
+                .map_err(|e| {
+                    RubyAdapterError::metadata(format!("Failed to set sorting_columns: {}", e))
+                })?;
+
             // Add column chunks metadata
             let columns_array = handle.ary_new();
             for col_idx in 0..row_group.num_columns() {
@@ -454,6 +524,68 @@ impl TryIntoValue for RubyParquetMetaData {
                         })?;
                 }
 
+                // Add pages from the page index if it was loaded
+                if let Some(arrow_schema) = &page_index_schema {
+                    let pages = page_statistics(metadata, arrow_schema, rg_idx, col_idx)
+                        .map_err(RubyAdapterError::Parquet)?;
+                    if let Some(pages) = pages {
+                        let pages_array = handle.ary_new();
+                        for page in pages {
+                            let page_hash = handle.hash_new();
+                            page_hash
+                                .aset("first_row_index", page.first_row_index)
+                                .map_err(|e| {
+                                    RubyAdapterError::metadata(format!(
+                                        "Failed to set first_row_index: {}",
+                                        e
+                                    ))
+                                })?;
+                            page_hash.aset("offset", page.offset).map_err(|e| {
+                                RubyAdapterError::metadata(format!("Failed to set offset: {}", e))
+                            })?;
+                            page_hash
+                                .aset("compressed_size", page.compressed_size)
+                                .map_err(|e| {
+                                    RubyAdapterError::metadata(format!(
+                                        "Failed to set compressed_size: {}",
+                                        e
+                                    ))
+                                })?;
+                            page_hash
+                                .aset(
+                                    "min",
+                                    parquet_to_ruby(page.min.unwrap_or(ParquetValue::Null))?,
+                                )
+                                .map_err(|e| {
+                                    RubyAdapterError::metadata(format!("Failed to set min: {}", e))
+                                })?;
+                            page_hash
+                                .aset(
+                                    "max",
+                                    parquet_to_ruby(page.max.unwrap_or(ParquetValue::Null))?,
+                                )
+                                .map_err(|e| {
+                                    RubyAdapterError::metadata(format!("Failed to set max: {}", e))
+                                })?;
+                            page_hash.aset("null_count", page.null_count).map_err(|e| {
+                                RubyAdapterError::metadata(format!(
+                                    "Failed to set null_count: {}",
+                                    e
+                                ))
+                            })?;
+                            pages_array.push(page_hash).map_err(|e| {
+                                RubyAdapterError::metadata(format!(
+                                    "Failed to push page_hash: {}",
+                                    e
+                                ))
+                            })?;
+                        }
+                        col_hash.aset("pages", pages_array).map_err(|e| {
+                            RubyAdapterError::metadata(format!("Failed to set pages: {}", e))
+                        })?;
+                    }
+                }
+
                 columns_array.push(col_hash).map_err(|e| {
                     RubyAdapterError::metadata(format!("Failed to push col_hash: {}", e))
                 })?;
@@ -491,25 +623,30 @@ impl IntoValue for RubyParquetMetaData {
 }
 
 /// Parse metadata from a file path or Ruby IO object
-pub fn parse_metadata(arg: Value) -> std::result::Result<Value, MagnusError> {
-    parse_metadata_impl(arg).into_magnus_error()
+///
+/// With `page_index` set the column and offset indexes are loaded too, and
+/// every column chunk lists its pages.
+pub fn parse_metadata(arg: Value, page_index: bool) -> std::result::Result<Value, MagnusError> {
+    parse_metadata_impl(arg, page_index).map_err(to_read_error)
 }
 
-fn parse_metadata_impl(arg: Value) -> Result<Value> {
+fn parse_metadata_impl(arg: Value, page_index: bool) -> Result<Value> {
     let ruby = Ruby::get().map_err(|_| RubyAdapterError::runtime("Failed to get Ruby runtime"))?;
 
+    if page_index {
+        let metadata = if arg.is_kind_of(ruby.class_string()) {
+            let file = File::open(path_from_value(arg)?).map_err(RubyAdapterError::Io)?;
+            load_with_page_index(&file)?
+        } else {
+            let file = RubyIOReader::new(arg).map_err(RubyAdapterError::Io)?;
+            load_with_page_index(&ThreadSafeRubyIOReader::new(file))?
+        };
+        return RubyParquetMetaData(metadata).try_into_value(&ruby);
+    }
+
     let mut reader = ParquetMetaDataReader::new();
     if arg.is_kind_of(ruby.class_string()) {
-        let path = arg
-            .to_r_string()
-            .map_err(|e| {
-                RubyAdapterError::invalid_input(format!("Failed to convert to string: {}", e))
-            })?
-            .to_string()
-            .map_err(|e| {
-                RubyAdapterError::invalid_input(format!("Failed to convert to Rust string: {}", e))
-            })?;
-        let file = File::open(path).map_err(RubyAdapterError::Io)?;
+        let file = File::open(path_from_value(arg)?).map_err(RubyAdapterError::Io)?;
         reader
             .try_parse(&file)
             .map_err(|e| RubyAdapterError::Parquet(parquet_core::ParquetError::Parquet(e)))?;
@@ -527,3 +664,21 @@ fn parse_metadata_impl(arg: Value) -> Result<Value> {
     // Use TryIntoValue instead of IntoValue
     RubyParquetMetaData(metadata).try_into_value(&ruby)
 }
+
+fn path_from_value(arg: Value) -> Result<String> {
+    arg.to_r_string()
+        .map_err(|e| {
+            RubyAdapterError::invalid_input(format!("Failed to convert to string: {}", e))
+        })?
+        .to_string()
+        .map_err(|e| {
+            RubyAdapterError::invalid_input(format!("Failed to convert to Rust string: {}", e))
+        })
+}
+
+fn load_with_page_index<R: ChunkReader>(reader: &R) -> Result<ParquetMetaData> {
+    let options = ArrowReaderOptions::new().with_page_index(true);
+    let metadata = ArrowReaderMetadata::load(reader, options)
+        .map_err(|e| RubyAdapterError::Parquet(parquet_core::ParquetError::Parquet(e)))?;
+    Ok(metadata.metadata().as_ref().clone())
+}
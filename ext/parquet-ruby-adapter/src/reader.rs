@@ -1,41 +1,49 @@
 use magnus::value::ReprValue;
 use magnus::{Error as MagnusError, IntoValue, RArray, RHash, Ruby, TryConvert, Value};
-use parquet_core::reader::Reader;
+use parquet_core::reader::{Reader, SkippedRowGroup};
+use parquet_core::ParquetError;
 
 use crate::StringCache;
 use crate::{
-    converter::parquet_to_ruby,
+    converter::ParquetToRubyOptions,
+    error::{to_read_error, ConversionError},
     io::{RubyIOReader, ThreadSafeRubyIOReader},
     logger::RubyLogger,
-    types::{ColumnEnumeratorArgs, ParserResultType, RowEnumeratorArgs},
-    utils::{create_column_enumerator, create_row_enumerator, handle_block_or_enum},
+    struct_row::StructRowBuilder,
+    types::{ColumnEnumeratorArgs, InternStrings, ParserResultType, RowEnumeratorArgs},
+    utils::{
+        create_column_enumerator, create_row_enumerator, handle_block_or_enum, parse_convert,
+        parse_range_filters,
+    },
     CloneableChunkReader,
 };
 
 /// Read parquet file row by row
-pub fn each_row(
-    ruby: &Ruby,
-    rb_self: Value,
-    to_read: Value,
-    result_type: ParserResultType,
-    columns: Option<Vec<String>>,
-    strict: bool,
-    logger: RubyLogger,
-) -> Result<Value, MagnusError> {
+pub fn each_row(ruby: &Ruby, args: RowEnumeratorArgs) -> Result<Value, MagnusError> {
+    let logger = RubyLogger::new(args.logger)?;
+
     if let Some(enum_value) = handle_block_or_enum(ruby.block_given(), || {
-        create_row_enumerator(RowEnumeratorArgs {
-            rb_self,
-            to_read,
-            result_type,
-            columns: columns.clone(),
-            strict,
-            logger: logger.inner(),
-        })
-        .map(|yield_enum| yield_enum.into_value_with(ruby))
+        create_row_enumerator(args.clone()).map(|yield_enum| yield_enum.into_value_with(ruby))
     })? {
         return Ok(enum_value);
     }
 
+    let RowEnumeratorArgs {
+        to_read,
+        result_type,
+        row_class,
+        columns,
+        parse_json,
+        symbolize_enums,
+        geometry_format,
+        keys,
+        skip_corrupt_row_groups,
+        filter,
+        intern_strings,
+        convert,
+        ..
+    } = args;
+
     // Log start of processing
     let _ = logger.info(|| "Starting to read parquet file".to_string());
 
@@ -43,17 +51,14 @@ pub fn each_row(
     let chunk_reader = if to_read.is_kind_of(ruby.class_string()) {
         let path_str: String = TryConvert::try_convert(to_read)?;
         let _ = logger.debug(|| format!("Reading from file: {}", path_str));
-        CloneableChunkReader::from_path(&path_str)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?
+        CloneableChunkReader::from_path(&path_str).map_err(to_read_error)?
     } else if to_read.respond_to("read", false)? {
         // Handle IO objects with streaming
         let _ = logger.debug(|| "Reading from IO object".to_string());
-        let ruby_reader = RubyIOReader::new(to_read)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+        let ruby_reader = RubyIOReader::new(to_read).map_err(to_read_error)?;
         let thread_safe_reader = ThreadSafeRubyIOReader::new(ruby_reader);
 
-        CloneableChunkReader::from_ruby_io(thread_safe_reader)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?
+        CloneableChunkReader::from_ruby_io(thread_safe_reader).map_err(to_read_error)?
     } else {
         return Err(MagnusError::new(
             ruby.exception_runtime_error(),
@@ -64,13 +69,27 @@ pub fn each_row(
         ));
     };
 
-    let reader = Reader::new(chunk_reader.clone());
+    // Interned columns are read as dictionaries, so each distinct value is
+    // decoded once and its frozen String is found again for every row
+    let dictionary_columns = match intern_strings {
+        InternStrings::None => Vec::new(),
+        InternStrings::DictionaryEncoded => Reader::new(chunk_reader.clone())
+            .dictionary_encoded_columns()
+            .map_err(to_read_error)?,
+        InternStrings::Columns(columns) => columns,
+    };
+
+    let convert = parse_convert(ruby, convert)?;
+    let reader = parse_range_filters(ruby, filter)?.into_iter().fold(
+        Reader::new(chunk_reader.clone())
+            .with_skip_corrupt_row_groups(skip_corrupt_row_groups)
+            .with_dictionary_columns(dictionary_columns.clone()),
+        Reader::with_range_filter,
+    );
     let mut reader_for_metadata = Reader::new(chunk_reader);
 
     // Get metadata to extract column names
-    let metadata = reader_for_metadata
-        .metadata()
-        .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+    let metadata = reader_for_metadata.metadata().map_err(to_read_error)?;
     let schema = metadata.schema();
     let all_column_names: Vec<String> = schema
         .get_fields()
@@ -81,15 +100,13 @@ pub fn each_row(
     let _ = logger.info(|| format!("Processing {} columns", all_column_names.len()));
 
     // Get the row iterator
-    let (row_iter, column_names) = if let Some(ref cols) = columns {
+    let (mut row_iter, column_names) = if let Some(ref cols) = columns {
         let iter = reader
             .read_rows_with_projection(cols)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+            .map_err(to_read_error)?;
         (iter, cols.clone())
     } else {
-        let iter = reader
-            .read_rows()
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+        let iter = reader.read_rows().map_err(to_read_error)?;
         (iter, all_column_names)
     };
 
@@ -102,24 +119,50 @@ pub fn each_row(
     })?;
     let mut row_count = 0u64;
 
+    let options = ParquetToRubyOptions::default()
+        .with_parse_json(parse_json)
+        .with_symbolize_enums(symbolize_enums)
+        .with_geometry_format(geometry_format)
+        .with_keys(keys)
+        .with_intern_columns(
+            column_names
+                .iter()
+                .map(|name| dictionary_columns.contains(name))
+                .collect(),
+        )
+        .with_conversions(convert.types)
+        .with_column_conversions(
+            column_names
+                .iter()
+                .map(|name| convert.for_column(name))
+                .collect(),
+        );
+
     let mut cache = StringCache::new(true);
-    let interned_column_names = column_names
+    let column_keys = column_names
         .iter()
-        .map(|name| cache.intern(name.clone()))
-        .collect::<Vec<_>>();
+        .map(|name| options.field_key(&cache.intern(name.clone())))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(to_read_error)?;
 
-    for row_result in row_iter {
-        let row = row_result
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+    let struct_rows = match result_type {
+        ParserResultType::Struct => Some(StructRowBuilder::new(ruby, &column_names, row_class)?),
+        _ => None,
+    };
+
+    // Not a `for` loop: skipped row groups are collected from the iterator
+    while let Some(row_result) = row_iter.next() {
+        log_skipped(&logger, row_iter.take_skipped_row_groups());
+        let row = row_result.map_err(to_read_error)?;
 
         // Convert row to Ruby value based on result_type
         let ruby_row = match result_type {
             ParserResultType::Array => {
                 let array: RArray = ruby.ary_new_capa(row.len());
-                for value in row {
-                    let ruby_value = parquet_to_ruby(value).map_err(|e| {
-                        MagnusError::new(ruby.exception_runtime_error(), e.to_string())
-                    })?;
+                for (idx, value) in row.into_iter().enumerate() {
+                    let ruby_value = options
+                        .column_value(idx, value)
+                        .map_err(|e| value_error(e, Some(row_count), &column_names[idx]))?;
                     array.push(ruby_value)?;
                 }
                 array.as_value()
@@ -127,15 +170,19 @@ pub fn each_row(
             ParserResultType::Hash => {
                 let hash: RHash = ruby.hash_new();
                 for (idx, value) in row.into_iter().enumerate() {
-                    if idx < interned_column_names.len() {
-                        let ruby_value = parquet_to_ruby(value).map_err(|e| {
-                            MagnusError::new(ruby.exception_runtime_error(), e.to_string())
-                        })?;
-                        hash.aset(interned_column_names[idx].as_ref(), ruby_value)?;
+                    if idx < column_keys.len() {
+                        let ruby_value = options
+                            .column_value(idx, value)
+                            .map_err(|e| value_error(e, Some(row_count), &column_names[idx]))?;
+                        hash.aset(column_keys[idx], ruby_value)?;
                     }
                 }
                 hash.as_value()
             }
+            ParserResultType::Struct => match &struct_rows {
+                Some(builder) => builder.build_row(ruby, row, &options)?,
+                None => unreachable!("struct rows are built for result_type :struct"),
+            },
         };
 
         proc.call::<_, Value>((ruby_row,))?;
@@ -146,86 +193,54 @@ pub fn each_row(
         }
     }
 
+    log_skipped(&logger, row_iter.take_skipped_row_groups());
     let _ = logger.info(|| format!("Finished processing {} rows", row_count));
 
     Ok(ruby.qnil().as_value())
 }
 
-/// Arguments for each_column function
-struct EachColumnArgs {
-    rb_self: Value,
-    to_read: Value,
-    result_type: ParserResultType,
-    columns: Option<Vec<String>>,
-    batch_size: Option<usize>,
-    strict: bool,
-    logger: RubyLogger,
+/// `Parquet::ConversionError` for a value that couldn't be converted to Ruby
+fn value_error(err: ParquetError, row_index: Option<u64>, column: &str) -> MagnusError {
+    let error = ConversionError::new(err.to_string()).with_column_path(column);
+    match row_index {
+        Some(row_index) => error.with_row_index(row_index),
+        None => error,
+    }
+    .into()
 }
 
 /// Read parquet file column by column
-#[allow(clippy::too_many_arguments)]
-pub fn each_column(
-    ruby: &Ruby,
-    rb_self: Value,
-    to_read: Value,
-    result_type: ParserResultType,
-    columns: Option<Vec<String>>,
-    batch_size: Option<usize>,
-    strict: bool,
-    logger: RubyLogger,
-) -> Result<Value, MagnusError> {
-    let args = EachColumnArgs {
-        rb_self,
-        to_read,
-        result_type,
-        columns,
-        batch_size,
-        strict,
-        logger,
-    };
-    each_column_impl(ruby, args)
-}
+pub fn each_column(ruby: &Ruby, args: ColumnEnumeratorArgs) -> Result<Value, MagnusError> {
+    if args.result_type == ParserResultType::Struct {
+        return Err(MagnusError::new(
+            ruby.exception_arg_error(),
+            "result_type :struct is only supported by each_row",
+        ));
+    }
+
+    let logger = RubyLogger::new(args.logger)?;
 
-fn each_column_impl(ruby: &Ruby, args: EachColumnArgs) -> Result<Value, MagnusError> {
     if let Some(enum_value) = handle_block_or_enum(ruby.block_given(), || {
-        create_column_enumerator(ColumnEnumeratorArgs {
-            rb_self: args.rb_self,
-            to_read: args.to_read,
-            result_type: args.result_type,
-            columns: args.columns.clone(),
-            batch_size: args.batch_size,
-            strict: args.strict,
-            logger: args.logger.inner(),
-        })
-        .map(|yield_enum| yield_enum.into_value_with(ruby))
+        create_column_enumerator(args.clone()).map(|yield_enum| yield_enum.into_value_with(ruby))
     })? {
         return Ok(enum_value);
     }
 
     // Log start of processing
-    let _ = args
-        .logger
-        .info(|| "Starting to read parquet file columns".to_string());
+    let _ = logger.info(|| "Starting to read parquet file columns".to_string());
 
     // Create a streaming reader based on input type
     let chunk_reader = if args.to_read.is_kind_of(ruby.class_string()) {
         let path_str: String = TryConvert::try_convert(args.to_read)?;
-        let _ = args
-            .logger
-            .debug(|| format!("Reading columns from file: {}", path_str));
-        CloneableChunkReader::from_path(&path_str)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?
+        let _ = logger.debug(|| format!("Reading columns from file: {}", path_str));
+        CloneableChunkReader::from_path(&path_str).map_err(to_read_error)?
     } else if args.to_read.respond_to("read", false)? {
         // Handle IO objects with streaming
-        let _ = args
-            .logger
-            .debug(|| "Reading columns from IO object".to_string());
-        let ruby_reader = RubyIOReader::new(args.to_read)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+        let _ = logger.debug(|| "Reading columns from IO object".to_string());
+        let ruby_reader = RubyIOReader::new(args.to_read).map_err(to_read_error)?;
         let thread_safe_reader = ThreadSafeRubyIOReader::new(ruby_reader);
 
-        CloneableChunkReader::from_ruby_io(thread_safe_reader)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?
+        CloneableChunkReader::from_ruby_io(thread_safe_reader).map_err(to_read_error)?
     } else {
         return Err(MagnusError::new(
             ruby.exception_runtime_error(),
@@ -236,13 +251,17 @@ fn each_column_impl(ruby: &Ruby, args: EachColumnArgs) -> Result<Value, MagnusEr
         ));
     };
 
-    let reader = Reader::new(chunk_reader.clone());
+    let convert = parse_convert(ruby, args.convert)?;
+    let options = ParquetToRubyOptions::default().with_conversions(convert.types);
+    let reader = parse_range_filters(ruby, args.filter)?.into_iter().fold(
+        Reader::new(chunk_reader.clone())
+            .with_skip_corrupt_row_groups(args.skip_corrupt_row_groups),
+        Reader::with_range_filter,
+    );
     let mut reader_for_metadata = Reader::new(chunk_reader);
 
     // Get metadata to extract column names
-    let metadata = reader_for_metadata
-        .metadata()
-        .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+    let metadata = reader_for_metadata.metadata().map_err(to_read_error)?;
     let schema = metadata.schema();
     let all_column_names: Vec<String> = schema
         .get_fields()
@@ -251,15 +270,15 @@ fn each_column_impl(ruby: &Ruby, args: EachColumnArgs) -> Result<Value, MagnusEr
         .collect();
 
     // Get the column iterator
-    let (col_iter, _column_names) = if let Some(ref cols) = args.columns {
+    let (mut col_iter, _column_names) = if let Some(ref cols) = args.columns {
         let iter = reader
             .read_columns_with_projection(cols, args.batch_size)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+            .map_err(to_read_error)?;
         (iter, cols.clone())
     } else {
         let iter = reader
             .read_columns(args.batch_size)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+            .map_err(to_read_error)?;
         (iter, all_column_names)
     };
 
@@ -272,20 +291,21 @@ fn each_column_impl(ruby: &Ruby, args: EachColumnArgs) -> Result<Value, MagnusEr
     })?;
     let mut batch_count = 0u64;
 
-    for batch_result in col_iter {
-        let batch = batch_result
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+    while let Some(batch_result) = col_iter.next() {
+        log_skipped(&logger, col_iter.take_skipped_row_groups());
+        let batch = batch_result.map_err(to_read_error)?;
 
         // Convert batch to Ruby value based on result_type
         let ruby_batch = match args.result_type {
             ParserResultType::Array => {
                 let array: RArray = ruby.ary_new_capa(batch.columns.len());
-                for (_name, values) in batch.columns {
+                for (name, values) in batch.columns {
+                    let conversions = convert.for_column(&name);
                     let col_array: RArray = ruby.ary_new_capa(values.len());
                     for value in values {
-                        let ruby_value = parquet_to_ruby(value).map_err(|e| {
-                            MagnusError::new(ruby.exception_runtime_error(), e.to_string())
-                        })?;
+                        let ruby_value = options
+                            .convert_with(value, &conversions)
+                            .map_err(|e| value_error(e, None, &name))?;
                         col_array.push(ruby_value)?;
                     }
                     array.push(col_array)?;
@@ -295,30 +315,41 @@ fn each_column_impl(ruby: &Ruby, args: EachColumnArgs) -> Result<Value, MagnusEr
             ParserResultType::Hash => {
                 let hash: RHash = ruby.hash_new();
                 for (name, values) in batch.columns {
+                    let conversions = convert.for_column(&name);
                     let col_array: RArray = ruby.ary_new_capa(values.len());
                     for value in values {
-                        let ruby_value = parquet_to_ruby(value).map_err(|e| {
-                            MagnusError::new(ruby.exception_runtime_error(), e.to_string())
-                        })?;
+                        let ruby_value = options
+                            .convert_with(value, &conversions)
+                            .map_err(|e| value_error(e, None, &name))?;
                         col_array.push(ruby_value)?;
                     }
                     hash.aset(name, col_array)?;
                 }
                 hash.as_value()
             }
+            ParserResultType::Struct => unreachable!("rejected before reading"),
         };
 
         proc.call::<_, Value>((ruby_batch,))?;
 
         batch_count += 1;
-        let _ = args
-            .logger
-            .debug(|| format!("Processed batch {}", batch_count));
+        let _ = logger.debug(|| format!("Processed batch {}", batch_count));
     }
 
-    let _ = args
-        .logger
-        .info(|| format!("Finished processing {} batches", batch_count));
+    log_skipped(&logger, col_iter.take_skipped_row_groups());
+    let _ = logger.info(|| format!("Finished processing {} batches", batch_count));
 
     Ok(ruby.qnil().as_value())
 }
+
+/// Warn about row groups left out by `skip_corrupt_row_groups`
+fn log_skipped(logger: &RubyLogger, skipped: Vec<SkippedRowGroup>) {
+    for row_group in skipped {
+        let _ = logger.warn(|| {
+            format!(
+                "Skipped corrupt row group {} ({} rows): {}",
+                row_group.index, row_group.num_rows, row_group.message
+            )
+        });
+    }
+}
@@ -0,0 +1,104 @@
+//! `Parquet.salvage`: recovering the rows of a file whose footer is missing
+
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, Ruby, Value};
+use parquet_core::salvage::{salvage as salvage_file, SalvageSchema};
+use parquet_core::WriterBuilder;
+
+use crate::convert::parse_optional_schema;
+use crate::converter::parquet_to_ruby;
+use crate::error::{to_magnus_error, to_read_error};
+use crate::logger::RubyLogger;
+use crate::utils::{open_chunk_reader, parse_compression};
+use crate::verify::problems_to_ruby;
+use crate::writer::{finish_output, open_output};
+
+/// Salvage a file path or IO object
+///
+/// The schema is either given as `schema_value` or read from the footer of
+/// `schema_from`, a complete file written the same way. Recovered rows are
+/// yielded to the block as hashes or written to `write_to`; with neither,
+/// only the report is returned. Problems found are also logged as warnings.
+pub fn salvage(
+    ruby: &Ruby,
+    to_read: Value,
+    schema_value: Option<Value>,
+    schema_from: Option<Value>,
+    write_to: Option<Value>,
+    compression: Option<String>,
+    logger: RubyLogger,
+) -> Result<Value, MagnusError> {
+    let schema_from = schema_from.filter(|v| !v.is_nil());
+    let schema = match (parse_optional_schema(ruby, schema_value)?, schema_from) {
+        (Some(schema), None) => SalvageSchema::from_schema(&schema),
+        (None, Some(sibling)) => SalvageSchema::from_file(&open_chunk_reader(ruby, sibling)?),
+        _ => {
+            return Err(MagnusError::new(
+                ruby.exception_arg_error(),
+                "Pass exactly one of schema: or schema_from:",
+            ))
+        }
+    }
+    .map_err(to_magnus_error)?;
+
+    let write_to = write_to.filter(|v| !v.is_nil());
+    if write_to.is_some() && ruby.block_given() {
+        return Err(MagnusError::new(
+            ruby.exception_arg_error(),
+            "Pass a block or write_to:, not both",
+        ));
+    }
+
+    let salvaged =
+        salvage_file(open_chunk_reader(ruby, to_read)?, &schema).map_err(to_read_error)?;
+    let report = salvaged.report().clone();
+    for problem in &report.problems {
+        let _ = logger.warn(|| match problem.page_offset {
+            Some(offset) => format!("Salvage at offset {}: {}", offset, problem.message),
+            None => format!("Salvage: {}", problem.message),
+        });
+    }
+    let _ = logger.info(|| {
+        format!(
+            "Recovered {} rows in {} row groups",
+            report.num_rows, report.row_groups
+        )
+    });
+
+    if let Some(write_to) = write_to {
+        let builder = WriterBuilder::new().with_compression(parse_compression(compression)?);
+        let (file, pending_copy) = open_output(ruby, write_to)?;
+        salvaged.write(file, builder).map_err(to_magnus_error)?;
+        finish_output(pending_copy)?;
+    } else if ruby.block_given() {
+        let names: Vec<String> = salvaged
+            .metadata()
+            .file_metadata()
+            .schema_descr()
+            .root_schema()
+            .get_fields()
+            .iter()
+            .map(|field| field.name().to_string())
+            .collect();
+
+        let proc = ruby.block_proc()?;
+        for row in salvaged.read_rows().map_err(to_read_error)? {
+            let row = row.map_err(to_read_error)?;
+            let hash = ruby.hash_new();
+            for (name, value) in names.iter().zip(row) {
+                let value = parquet_to_ruby(value).map_err(to_magnus_error)?;
+                hash.aset(name.as_str(), value)?;
+            }
+            proc.call::<_, Value>((hash,))?;
+        }
+    }
+
+    let hash = ruby.hash_new();
+    hash.aset("num_rows", report.num_rows)?;
+    hash.aset("row_groups", report.row_groups)?;
+    hash.aset("pages", report.pages)?;
+    hash.aset("file_size", report.file_size)?;
+    hash.aset("scanned_bytes", report.scanned_bytes)?;
+    hash.aset("problems", problems_to_ruby(ruby, &report.problems)?)?;
+    Ok(hash.as_value())
+}
@@ -0,0 +1,88 @@
+//! `Parquet.sample`: random rows for spot checks without a full scan
+
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, Ruby, Value};
+use parquet_core::{Reader, Sample};
+
+use crate::converter::parquet_to_ruby;
+use crate::error::to_read_error;
+use crate::utils::open_chunk_reader;
+
+/// Sample the rows of a file path or IO object as hashes
+///
+/// Exactly one of `n` (a number of rows) or `sample_rate` (a fraction of
+/// rows) is given. Rows are yielded to the block as they are read, or
+/// returned as an Array without one.
+pub fn sample(
+    ruby: &Ruby,
+    to_read: Value,
+    n: Option<usize>,
+    sample_rate: Option<f64>,
+    seed: Option<u64>,
+    columns: Option<Vec<String>>,
+) -> Result<Value, MagnusError> {
+    let sample = match (n, sample_rate) {
+        (Some(n), None) => Sample::Rows(n),
+        (None, Some(rate)) => Sample::Rate(rate),
+        _ => {
+            return Err(MagnusError::new(
+                ruby.exception_arg_error(),
+                "Pass exactly one of n: or sample_rate:",
+            ))
+        }
+    };
+    sample
+        .validate()
+        .map_err(|e| MagnusError::new(ruby.exception_arg_error(), e.to_string()))?;
+
+    let chunk_reader = open_chunk_reader(ruby, to_read)?;
+    // Projected rows keep the file's column order
+    let names: Vec<String> = Reader::new(chunk_reader.clone())
+        .metadata()
+        .map_err(to_read_error)?
+        .schema_descr()
+        .root_schema()
+        .get_fields()
+        .iter()
+        .map(|field| field.name().to_string())
+        .filter(|name| match &columns {
+            Some(columns) => columns.contains(name),
+            None => true,
+        })
+        .collect();
+
+    let reader = Reader::new(chunk_reader).with_sample(sample, seed);
+    let rows = match columns {
+        Some(ref columns) => reader.read_rows_with_projection(columns),
+        None => reader.read_rows(),
+    }
+    .map_err(to_read_error)?;
+
+    let block = if ruby.block_given() {
+        Some(ruby.block_proc()?)
+    } else {
+        None
+    };
+    let sampled = ruby.ary_new();
+    for row in rows {
+        let row = row.map_err(to_read_error)?;
+        let hash = ruby.hash_new();
+        for (name, value) in names.iter().zip(row) {
+            hash.aset(
+                name.as_str(),
+                parquet_to_ruby(value).map_err(to_read_error)?,
+            )?;
+        }
+        match block {
+            Some(proc) => {
+                proc.call::<_, Value>((hash,))?;
+            }
+            None => sampled.push(hash)?,
+        }
+    }
+
+    match block {
+        Some(_) => Ok(ruby.qnil().as_value()),
+        None => Ok(sampled.as_value()),
+    }
+}
@@ -1,7 +1,9 @@
 use magnus::value::ReprValue;
 use magnus::{Error as MagnusError, RArray, RHash, Ruby, Symbol, TryConvert, Value};
 use parquet_core::{ParquetError, PrimitiveType, Schema, SchemaNode};
+use std::sync::Arc;
 
+use crate::timezone::resolve_time_zone;
 use crate::utils::parse_string_or_symbol;
 use crate::RubyAdapterError;
 
@@ -180,17 +182,53 @@ impl RubySchemaBuilder {
                             None
                         }
                     } else {
-                        hash.fetch::<_, Value>(Symbol::new("timezone"))
-                            .ok()
-                            .map(|_| "UTC".to_string()) // Any value -> UTC
+                        match hash.fetch::<_, Value>(Symbol::new("timezone")) {
+                            // Stored as UTC either way, the zone is kept for reading
+                            Ok(tz) if !tz.is_nil() => {
+                                let name = tz.to_r_string()?.to_string()?;
+                                resolve_time_zone(&name)?;
+                                Some(name)
+                            }
+                            _ => None,
+                        }
                     };
 
-                let primitive = self.parse_primitive_type(
-                    primitive_type.to_string(),
-                    precision,
-                    scale,
-                    timezone,
-                )?;
+                // Durations may give their unit separately, e.g. { type: :duration, unit: :nanos }
+                let unit = hash
+                    .fetch::<_, Value>(Symbol::new("unit"))
+                    .ok()
+                    .and_then(|v| v.to_r_string().ok())
+                    .and_then(|s| s.to_string().ok());
+                let primitive_type = match unit {
+                    Some(unit) if primitive_type == "duration" => duration_type(&unit)?.to_string(),
+                    _ => primitive_type.to_string(),
+                };
+
+                let mut primitive =
+                    self.parse_primitive_type(primitive_type, precision, scale, timezone)?;
+
+                // Optional whitelist of allowed enum values
+                if let PrimitiveType::Enum(ref mut allowed) = primitive {
+                    if let Ok(values) = hash.fetch::<_, RArray>(Symbol::new("values")) {
+                        let values = values
+                            .into_iter()
+                            .map(|v| v.to_r_string()?.to_string())
+                            .collect::<Result<Vec<_>, MagnusError>>()
+                            .map_err(|e| {
+                                ParquetError::Schema(format!("Invalid enum values: {}", e))
+                            })?;
+                        *allowed = Some(values);
+                    }
+                }
+
+                // Coordinate reference system for geometry columns
+                if let PrimitiveType::Geometry(ref mut crs)
+                | PrimitiveType::Geography(ref mut crs) = primitive
+                {
+                    if let Ok(value) = hash.fetch::<_, Value>(Symbol::new("crs")) {
+                        *crs = parse_crs(value)?;
+                    }
+                }
 
                 Ok(SchemaNode::Primitive {
                     name,
@@ -357,6 +395,8 @@ impl RubySchemaBuilder {
             "float" | "float32" => Ok(PrimitiveType::Float32),
             "double" | "float64" => Ok(PrimitiveType::Float64),
             "string" => Ok(PrimitiveType::String),
+            "json" => Ok(PrimitiveType::Json),
+            "enum" => Ok(PrimitiveType::Enum(None)),
             "binary" => Ok(PrimitiveType::Binary),
             "date32" | "date" => Ok(PrimitiveType::Date32),
             "date64" => Ok(PrimitiveType::Date64),
@@ -379,6 +419,14 @@ impl RubySchemaBuilder {
             "time_millis" => Ok(PrimitiveType::TimeMillis),
             "time_micros" => Ok(PrimitiveType::TimeMicros),
             "time_nanos" => Ok(PrimitiveType::TimeNanos),
+            "duration" | "duration_millis" => Ok(PrimitiveType::DurationMillis),
+            "duration_second" => Ok(PrimitiveType::DurationSecond),
+            "duration_micros" => Ok(PrimitiveType::DurationMicros),
+            "duration_nanos" => Ok(PrimitiveType::DurationNanos),
+            "interval" => Ok(PrimitiveType::Interval),
+            "variant" => Ok(PrimitiveType::Variant),
+            "geometry" => Ok(PrimitiveType::Geometry(None)),
+            "geography" => Ok(PrimitiveType::Geography(None)),
             "decimal" => {
                 // Use provided precision/scale or defaults
                 let p = precision.unwrap_or(38);
@@ -564,6 +612,8 @@ fn schema_node_to_ruby(node: &SchemaNode, _ruby: &Ruby) -> Result<Value, RubyAda
                 PrimitiveType::Float32 => Symbol::new("float32"),
                 PrimitiveType::Float64 => Symbol::new("float64"),
                 PrimitiveType::String => Symbol::new("string"),
+                PrimitiveType::Json => Symbol::new("json"),
+                PrimitiveType::Enum(_) => Symbol::new("enum"),
                 PrimitiveType::Binary => Symbol::new("binary"),
                 PrimitiveType::Date32 => Symbol::new("date32"),
                 PrimitiveType::Date64 => Symbol::new("date64"),
@@ -574,6 +624,14 @@ fn schema_node_to_ruby(node: &SchemaNode, _ruby: &Ruby) -> Result<Value, RubyAda
                 PrimitiveType::TimeMillis => Symbol::new("time_millis"),
                 PrimitiveType::TimeMicros => Symbol::new("time_micros"),
                 PrimitiveType::TimeNanos => Symbol::new("time_nanos"),
+                PrimitiveType::DurationSecond => Symbol::new("duration_second"),
+                PrimitiveType::DurationMillis => Symbol::new("duration_millis"),
+                PrimitiveType::DurationMicros => Symbol::new("duration_micros"),
+                PrimitiveType::DurationNanos => Symbol::new("duration_nanos"),
+                PrimitiveType::Interval => Symbol::new("interval"),
+                PrimitiveType::Variant => Symbol::new("variant"),
+                PrimitiveType::Geometry(_) => Symbol::new("geometry"),
+                PrimitiveType::Geography(_) => Symbol::new("geography"),
                 PrimitiveType::Decimal128(_, _) => Symbol::new("decimal128"),
                 PrimitiveType::Decimal256(_, _) => Symbol::new("decimal256"),
                 PrimitiveType::FixedLenByteArray(_) => Symbol::new("fixed_len_byte_array"),
@@ -608,6 +666,17 @@ fn schema_node_to_ruby(node: &SchemaNode, _ruby: &Ruby) -> Result<Value, RubyAda
                         ParquetError::Conversion(format!("Failed to set length: {}", e))
                     })?;
                 }
+                PrimitiveType::Enum(Some(values)) => {
+                    hash.aset(Symbol::new("values"), RArray::from_vec(values.clone()))
+                        .map_err(|e| {
+                            ParquetError::Conversion(format!("Failed to set values: {}", e))
+                        })?;
+                }
+                PrimitiveType::Geometry(Some(crs)) | PrimitiveType::Geography(Some(crs)) => {
+                    hash.aset(Symbol::new("crs"), crs.as_ref()).map_err(|e| {
+                        ParquetError::Conversion(format!("Failed to set crs: {}", e))
+                    })?;
+                }
                 _ => {}
             }
         }
@@ -616,6 +685,56 @@ fn schema_node_to_ruby(node: &SchemaNode, _ruby: &Ruby) -> Result<Value, RubyAda
     Ok(hash.as_value())
 }
 
+/// Map the `unit:` of a duration field to its type name
+fn duration_type(unit: &str) -> Result<&'static str, RubyAdapterError> {
+    match unit {
+        "second" | "seconds" => Ok("duration_second"),
+        "millis" | "millisecond" | "milliseconds" => Ok("duration_millis"),
+        "micros" | "microsecond" | "microseconds" => Ok("duration_micros"),
+        "nanos" | "nanosecond" | "nanoseconds" => Ok("duration_nanos"),
+        other => Err(RubyAdapterError::InvalidInput(format!(
+            "unknown duration unit :{}, valid units are :second, :millis, :micros and :nanos",
+            other
+        ))),
+    }
+}
+
+/// Normalize a geometry CRS to PROJJSON text
+///
+/// Accepts PROJJSON as a String or Hash, or an `"AUTHORITY:CODE"` identifier
+/// such as `"EPSG:4326"`, which becomes a PROJJSON `id` object.
+fn parse_crs(value: Value) -> Result<Option<Arc<str>>, RubyAdapterError> {
+    if value.is_nil() {
+        return Ok(None);
+    }
+
+    let crs: serde_json::Value = if let Ok(text) = String::try_convert(value) {
+        let text = text.trim();
+        if text.starts_with('{') {
+            serde_json::from_str(text)
+                .map_err(|e| ParquetError::Schema(format!("Invalid PROJJSON crs: {}", e)))?
+        } else {
+            let (authority, code) = text.split_once(':').ok_or_else(|| {
+                ParquetError::Schema(format!(
+                    "Invalid crs {:?}, expected PROJJSON or \"AUTHORITY:CODE\"",
+                    text
+                ))
+            })?;
+            let code = match code.parse::<i64>() {
+                Ok(n) => serde_json::Value::from(n),
+                Err(_) => serde_json::Value::from(code),
+            };
+            serde_json::json!({ "id": { "authority": authority, "code": code } })
+        }
+    } else {
+        let text = crate::json::encode_json(value)?;
+        serde_json::from_str(&text)
+            .map_err(|e| ParquetError::Schema(format!("Invalid PROJJSON crs: {}", e)))?
+    };
+
+    Ok(Some(crs.to_string().into()))
+}
+
 /// Convert old schema format to new format
 /// Old: [{ "column_name" => "type" }, ...]
 /// New: [{ name: "column_name", type: :type }, ...]
@@ -0,0 +1,62 @@
+//! `Parquet.statistics`: decoded column statistics as Ruby hashes
+
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, RHash, Ruby, Value};
+use parquet_core::reader::Reader;
+use parquet_core::{ColumnStatistics, ParquetValue};
+
+use crate::{converter::parquet_to_ruby, error::to_read_error, utils::open_chunk_reader};
+
+/// Read the statistics of a file path or IO object
+///
+/// Columns are keyed by their dotted leaf path. With `row_groups` set the
+/// result also holds a `"row_groups"` array with the same per column hashes.
+pub fn statistics(
+    ruby: &Ruby,
+    to_read: Value,
+    columns: Option<Vec<String>>,
+    row_groups: bool,
+) -> Result<Value, MagnusError> {
+    let chunk_reader = open_chunk_reader(ruby, to_read)?;
+
+    let stats = Reader::new(chunk_reader)
+        .statistics(columns.as_deref())
+        .map_err(to_read_error)?;
+
+    let hash = ruby.hash_new();
+    hash.aset("num_rows", stats.num_rows)?;
+    hash.aset("columns", columns_hash(ruby, stats.columns)?)?;
+
+    if row_groups {
+        let array = ruby.ary_new_capa(stats.row_groups.len());
+        for row_group in stats.row_groups {
+            let rg_hash = ruby.hash_new();
+            rg_hash.aset("num_rows", row_group.num_rows)?;
+            rg_hash.aset("columns", columns_hash(ruby, row_group.columns)?)?;
+            array.push(rg_hash)?;
+        }
+        hash.aset("row_groups", array)?;
+    }
+
+    Ok(hash.as_value())
+}
+
+fn columns_hash(ruby: &Ruby, columns: Vec<ColumnStatistics>) -> Result<RHash, MagnusError> {
+    let hash = ruby.hash_new();
+    for column in columns {
+        let col_hash = ruby.hash_new();
+        col_hash.aset("min", value_to_ruby(column.min)?)?;
+        col_hash.aset("max", value_to_ruby(column.max)?)?;
+        col_hash.aset("null_count", column.null_count)?;
+        col_hash.aset("distinct_count", column.distinct_count)?;
+        col_hash.aset("num_values", column.num_values)?;
+        col_hash.aset("total_compressed_size", column.compressed_size)?;
+        col_hash.aset("total_uncompressed_size", column.uncompressed_size)?;
+        hash.aset(column.path, col_hash)?;
+    }
+    Ok(hash)
+}
+
+fn value_to_ruby(value: Option<ParquetValue>) -> Result<Value, MagnusError> {
+    parquet_to_ruby(value.unwrap_or(ParquetValue::Null)).map_err(to_read_error)
+}
@@ -0,0 +1,180 @@
+//! Building rows as `Data` (or `Struct` on Ruby < 3.2) instances
+//!
+//! One class is generated per file schema, and another for every nested
+//! struct the first time a record is seen at that path. Rows are instantiated
+//! positionally, so no per-row hash is built.
+
+use crate::converter::ParquetToRubyOptions;
+use crate::error::to_magnus_error;
+use crate::types::Conversions;
+use magnus::value::{BoxValue, ReprValue};
+use magnus::{Error as MagnusError, Module, RArray, Ruby, Symbol, Value};
+use parquet_core::ParquetValue;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Converts rows into instances of a generated or user supplied class
+pub struct StructRowBuilder {
+    row_class: BoxValue<Value>,
+    /// Index of the row value for each member of the row class
+    member_columns: Vec<usize>,
+    column_names: Vec<String>,
+    nested_classes: RefCell<HashMap<String, BoxValue<Value>>>,
+}
+
+impl StructRowBuilder {
+    /// Create a builder for rows with the given columns
+    ///
+    /// With `row_class` given, its members are matched to columns by name,
+    /// otherwise a class with one member per column is defined.
+    pub fn new(
+        ruby: &Ruby,
+        column_names: &[String],
+        row_class: Option<Value>,
+    ) -> Result<Self, MagnusError> {
+        let (row_class, member_columns) = match row_class {
+            Some(class) => {
+                let members: RArray = class.funcall("members", ())?;
+                let member_columns = members
+                    .into_iter()
+                    .map(|member| {
+                        let member = member.to_r_string()?.to_string()?;
+                        column_names
+                            .iter()
+                            .position(|name| name == &member)
+                            .ok_or_else(|| {
+                                MagnusError::new(
+                                    ruby.exception_arg_error(),
+                                    format!(
+                                        "{} has member {:?} which is not a column",
+                                        class, member
+                                    ),
+                                )
+                            })
+                    })
+                    .collect::<Result<Vec<_>, MagnusError>>()?;
+                (class, member_columns)
+            }
+            None => (
+                define_class(ruby, column_names.iter().map(String::as_str))?,
+                (0..column_names.len()).collect(),
+            ),
+        };
+
+        Ok(Self {
+            row_class: BoxValue::new(row_class),
+            member_columns,
+            column_names: column_names.to_vec(),
+            nested_classes: RefCell::new(HashMap::new()),
+        })
+    }
+
+    /// Instantiate the row class for one row
+    pub fn build_row(
+        &self,
+        ruby: &Ruby,
+        row: Vec<ParquetValue>,
+        options: &ParquetToRubyOptions,
+    ) -> Result<Value, MagnusError> {
+        let mut row = row.into_iter().map(Some).collect::<Vec<_>>();
+        let mut args = Vec::with_capacity(self.member_columns.len());
+        for &idx in &self.member_columns {
+            let value = row
+                .get_mut(idx)
+                .and_then(Option::take)
+                .unwrap_or(ParquetValue::Null);
+            let arg = match value {
+                ParquetValue::String(_) => {
+                    options.column_value(idx, value).map_err(to_magnus_error)?
+                }
+                value => self.convert(
+                    ruby,
+                    value,
+                    &self.column_names[idx],
+                    options,
+                    options.column_conversions(idx),
+                )?,
+            };
+            args.push(arg);
+        }
+        self.row_class.funcall("new", args.as_slice())
+    }
+
+    fn convert(
+        &self,
+        ruby: &Ruby,
+        value: ParquetValue,
+        path: &str,
+        options: &ParquetToRubyOptions,
+        conversions: &Conversions,
+    ) -> Result<Value, MagnusError> {
+        match value {
+            ParquetValue::Record(record) => {
+                let class = self.nested_class(ruby, path, record.keys().map(|k| k.as_ref()))?;
+                let mut args = Vec::with_capacity(record.len());
+                for (name, field) in record {
+                    let field_path = format!("{}.{}", path, name);
+                    args.push(self.convert(ruby, field, &field_path, options, conversions)?);
+                }
+                class.funcall("new", args.as_slice())
+            }
+            ParquetValue::List(items) => {
+                let item_path = format!("{}[]", path);
+                let array = ruby.ary_new_capa(items.len());
+                for item in items {
+                    array.push(self.convert(ruby, item, &item_path, options, conversions)?)?;
+                }
+                Ok(array.as_value())
+            }
+            ParquetValue::Map(entries) => {
+                let value_path = format!("{}{{value}}", path);
+                let hash = ruby.hash_new();
+                for (key, value) in entries {
+                    let key = options.map_key(key).map_err(to_magnus_error)?;
+                    hash.aset(
+                        key,
+                        self.convert(ruby, value, &value_path, options, conversions)?,
+                    )?;
+                }
+                Ok(hash.as_value())
+            }
+            other => options
+                .convert_with(other, conversions)
+                .map_err(to_magnus_error),
+        }
+    }
+
+    /// Class for the records at `path`, defined from the first record's fields
+    fn nested_class<'a>(
+        &self,
+        ruby: &Ruby,
+        path: &str,
+        fields: impl Iterator<Item = &'a str>,
+    ) -> Result<Value, MagnusError> {
+        if let Some(class) = self.nested_classes.borrow().get(path) {
+            return Ok(**class);
+        }
+        let class = define_class(ruby, fields)?;
+        self.nested_classes
+            .borrow_mut()
+            .insert(path.to_string(), BoxValue::new(class));
+        Ok(class)
+    }
+}
+
+/// `Data.define(*members)`, or `Struct.new(*members)` where `Data` is unavailable
+fn define_class<'a>(
+    ruby: &Ruby,
+    members: impl Iterator<Item = &'a str>,
+) -> Result<Value, MagnusError> {
+    let members = members.map(Symbol::new).collect::<Vec<_>>();
+    let object = ruby.class_object();
+    if object.const_defined("Data")? {
+        let data: Value = object.const_get("Data")?;
+        if data.respond_to("define", false)? {
+            return data.funcall("define", members.as_slice());
+        }
+    }
+    let struct_class: Value = object.const_get("Struct")?;
+    struct_class.funcall("new", members.as_slice())
+}
@@ -0,0 +1,57 @@
+//! Time zones of timestamp columns
+//!
+//! Timestamps with a zone are always stored adjusted to UTC. The zone itself
+//! is kept in the Arrow schema, either an IANA name like "America/New_York",
+//! looked up in jiff's tz database, or a fixed offset like "+09:00". Values
+//! are localised into it when read.
+
+use jiff::tz::{Offset, TimeZone};
+use jiff::Timestamp;
+use parquet_core::{ParquetError, Result};
+
+/// The zone named by a timestamp column, or `None` for UTC
+pub fn resolve_time_zone(name: &str) -> Result<Option<TimeZone>> {
+    if name.eq_ignore_ascii_case("UTC") || name == "Etc/UTC" || name == "Z" {
+        return Ok(None);
+    }
+    if let Some(seconds) = parse_offset(name) {
+        if seconds == 0 {
+            return Ok(None);
+        }
+        let offset = Offset::from_seconds(seconds)
+            .map_err(|e| ParquetError::Schema(format!("Invalid time zone {:?}: {}", name, e)))?;
+        return Ok(Some(TimeZone::fixed(offset)));
+    }
+    TimeZone::get(name)
+        .map(Some)
+        .map_err(|e| ParquetError::Schema(format!("Unknown time zone {:?}: {}", name, e)))
+}
+
+/// UTC offset in seconds of `zone` at `secs` seconds since the epoch
+pub fn utc_offset(zone: &TimeZone, secs: i64) -> Result<i32> {
+    let timestamp = Timestamp::from_second(secs)
+        .map_err(|e| ParquetError::Conversion(format!("Timestamp out of range: {}", e)))?;
+    Ok(zone.to_offset(timestamp).seconds())
+}
+
+/// Seconds east of UTC for offsets like "+09:00", "-0530" or "+01"
+fn parse_offset(name: &str) -> Option<i32> {
+    let sign = match name.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits = name[1..].replace(':', "");
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    Some(sign * (hours * 3600 + minutes * 60))
+}
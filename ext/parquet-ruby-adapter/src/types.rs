@@ -1,4 +1,6 @@
 use magnus::Value;
+use parquet_core::SortColumn;
+use std::collections::HashMap;
 use std::fs::File;
 use std::str::FromStr;
 use tempfile::NamedTempFile;
@@ -15,19 +17,70 @@ pub struct ParquetWriteArgs {
     pub sample_size: Option<usize>,
     pub logger: Option<Value>,
     pub string_cache: Option<bool>,
+    /// Declared as the `sorting_columns` of every row group
+    pub sorted_by: Vec<SortColumn>,
+    /// Fail at the first row out of the `sorted_by` order
+    pub verify_sorted: bool,
+    /// What to do with rows that can't be converted or don't fit the schema
+    pub on_error: OnError,
+    /// IO that rows left out by `on_error` are written to as NDJSON
+    pub dead_letter: Option<Value>,
 }
 
-/// Arguments for creating row enumerators
+/// What `write_rows` does with a row that fails conversion or validation
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum OnError {
+    /// Raise at the first bad row, the default
+    #[default]
+    Raise,
+    /// Leave bad rows out of the file
+    Skip,
+    /// Leave bad rows out and return their errors
+    Collect,
+}
+
+impl FromStr for OnError {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "raise" => Ok(OnError::Raise),
+            "skip" => Ok(OnError::Skip),
+            "collect" => Ok(OnError::Collect),
+            _ => Err(format!(
+                "Invalid on_error {:?}, expected :raise, :skip or :collect",
+                s
+            )),
+        }
+    }
+}
+
+/// Arguments of `each_row`, also passed on to the enumerator it returns
+/// without a block
+#[derive(Clone)]
 pub struct RowEnumeratorArgs {
     pub rb_self: Value,
     pub to_read: Value,
     pub result_type: ParserResultType,
+    /// Class to instantiate for each row when `result_type` is `Struct`
+    pub row_class: Option<Value>,
     pub columns: Option<Vec<String>>,
     pub strict: bool,
     pub logger: Option<Value>,
+    pub parse_json: bool,
+    pub symbolize_enums: bool,
+    pub geometry_format: GeometryFormat,
+    pub keys: KeyFormat,
+    pub skip_corrupt_row_groups: bool,
+    /// The `filter:` Hash, passed on as given
+    pub filter: Option<Value>,
+    pub intern_strings: InternStrings,
+    /// The `convert:` Hash, passed on as given
+    pub convert: Option<Value>,
 }
 
-/// Arguments for creating column enumerators
+/// Arguments of `each_column`, also passed on to the enumerator it returns
+/// without a block
+#[derive(Clone)]
 pub struct ColumnEnumeratorArgs {
     pub rb_self: Value,
     pub to_read: Value,
@@ -36,6 +89,21 @@ pub struct ColumnEnumeratorArgs {
     pub batch_size: Option<usize>,
     pub strict: bool,
     pub logger: Option<Value>,
+    pub skip_corrupt_row_groups: bool,
+    /// The `filter:` Hash, passed on as given
+    pub filter: Option<Value>,
+    /// The `convert:` Hash, passed on as given
+    pub convert: Option<Value>,
+}
+
+/// CSV options shared by `Parquet.from_csv` and `Parquet.to_csv`
+#[derive(Debug, Default)]
+pub struct CsvArgs {
+    pub header: Option<bool>,
+    pub delimiter: Option<String>,
+    pub null_values: Option<Vec<String>>,
+    pub date_format: Option<String>,
+    pub timestamp_format: Option<String>,
 }
 
 /// Enum to handle different writer outputs
@@ -49,11 +117,13 @@ pub enum WriterOutput {
 pub enum ParserResultType {
     Hash,
     Array,
+    /// One `Data` instance per row
+    Struct,
 }
 
 impl ParserResultType {
     pub fn iter() -> impl Iterator<Item = Self> {
-        [Self::Hash, Self::Array].into_iter()
+        [Self::Hash, Self::Array, Self::Struct].into_iter()
     }
 }
 
@@ -71,6 +141,7 @@ impl TryFrom<&str> for ParserResultType {
         match value {
             "hash" => Ok(ParserResultType::Hash),
             "array" => Ok(ParserResultType::Array),
+            "struct" => Ok(ParserResultType::Struct),
             _ => Err(format!("Invalid parser result type: {}", value)),
         }
     }
@@ -89,6 +160,196 @@ impl std::fmt::Display for ParserResultType {
         match self {
             ParserResultType::Hash => write!(f, "hash"),
             ParserResultType::Array => write!(f, "array"),
+            ParserResultType::Struct => write!(f, "struct"),
+        }
+    }
+}
+
+/// How geometry columns are returned when reading
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GeometryFormat {
+    /// Well-known text, e.g. `"POINT (1 2)"`
+    #[default]
+    Wkt,
+    /// GeoJSON style `{"type" => ..., "coordinates" => ...}` hashes
+    Coordinates,
+    /// Raw WKB as a binary string
+    Wkb,
+}
+
+impl FromStr for GeometryFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wkt" => Ok(GeometryFormat::Wkt),
+            "coordinates" => Ok(GeometryFormat::Coordinates),
+            "wkb" => Ok(GeometryFormat::Wkb),
+            _ => Err(format!("Invalid geometry format: {}", s)),
         }
     }
 }
+
+impl std::fmt::Display for GeometryFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeometryFormat::Wkt => write!(f, "wkt"),
+            GeometryFormat::Coordinates => write!(f, "coordinates"),
+            GeometryFormat::Wkb => write!(f, "wkb"),
+        }
+    }
+}
+
+/// Which string columns `each_row` returns as shared frozen Strings
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InternStrings {
+    /// A new String for every value, the default
+    #[default]
+    None,
+    /// Every string column that is dictionary encoded in the file
+    DictionaryEncoded,
+    /// The named columns, whatever their encoding
+    Columns(Vec<String>),
+}
+
+/// How Hash row keys, struct field names and map keys are returned
+#[derive(Copy, Clone, Debug, Default)]
+pub enum KeyFormat {
+    /// Frozen Strings, the default
+    #[default]
+    String,
+    /// Symbols
+    Symbol,
+    /// The result of calling a Proc (or any callable) with the String name
+    Proc(Value),
+}
+
+/// How decimal values are returned when reading
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DecimalFormat {
+    /// BigDecimal, the default
+    #[default]
+    BigDecimal,
+    /// Float, rounded to the nearest double
+    Float,
+    /// The exact decimal as a String, e.g. `"12.50"`
+    String,
+}
+
+impl FromStr for DecimalFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "big_decimal" => Ok(DecimalFormat::BigDecimal),
+            "float" => Ok(DecimalFormat::Float),
+            "string" => Ok(DecimalFormat::String),
+            _ => Err(format!(
+                "Invalid decimal conversion {:?}, expected :big_decimal, :float or :string",
+                s
+            )),
+        }
+    }
+}
+
+/// How timestamp values are returned when reading
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// Time, the default
+    #[default]
+    Time,
+    /// The stored Integer since the epoch, in the column's unit
+    Integer,
+    /// An ISO 8601 String, ending in `Z` for timestamps adjusted to UTC
+    Iso8601,
+}
+
+impl FromStr for TimestampFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "time" => Ok(TimestampFormat::Time),
+            "integer" => Ok(TimestampFormat::Integer),
+            "iso8601" => Ok(TimestampFormat::Iso8601),
+            _ => Err(format!(
+                "Invalid timestamp conversion {:?}, expected :time, :integer or :iso8601",
+                s
+            )),
+        }
+    }
+}
+
+/// How date values are returned when reading
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DateFormat {
+    /// Date, the default
+    #[default]
+    Date,
+    /// Integer days since the epoch
+    Integer,
+    /// A `"YYYY-MM-DD"` String
+    String,
+}
+
+impl FromStr for DateFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "date" => Ok(DateFormat::Date),
+            "integer" => Ok(DateFormat::Integer),
+            "string" => Ok(DateFormat::String),
+            _ => Err(format!(
+                "Invalid date conversion {:?}, expected :date, :integer or :string",
+                s
+            )),
+        }
+    }
+}
+
+/// How binary values are returned when reading
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BinaryFormat {
+    /// ASCII-8BIT Strings, the default
+    #[default]
+    Bytes,
+    /// UTF-8 Strings
+    String,
+}
+
+impl FromStr for BinaryFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bytes" => Ok(BinaryFormat::Bytes),
+            "string" => Ok(BinaryFormat::String),
+            _ => Err(format!(
+                "Invalid binary conversion {:?}, expected :bytes or :string",
+                s
+            )),
+        }
+    }
+}
+
+/// Ruby types that decimal, timestamp, date and binary values are read as
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Conversions {
+    pub decimal: DecimalFormat,
+    pub timestamp: TimestampFormat,
+    pub date: DateFormat,
+    pub binary: BinaryFormat,
+}
+
+/// The `convert:` option: conversions by type, overridden per column
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConvertOptions {
+    /// Applied to every column without its own entry
+    pub types: Conversions,
+    /// Conversions for top level columns by name, with any type not given
+    /// for the column taken from `types`
+    pub columns: HashMap<String, Conversions>,
+}
+
+impl ConvertOptions {
+    /// Conversions for the top level column `name`
+    pub fn for_column(&self, name: &str) -> Conversions {
+        self.columns.get(name).copied().unwrap_or(self.types)
+    }
+}
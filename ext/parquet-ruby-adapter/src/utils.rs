@@ -1,12 +1,21 @@
+use magnus::r_hash::ForEach;
 use magnus::value::ReprValue;
 use magnus::{
     scan_args::{get_kwargs, scan_args},
-    Error as MagnusError, KwArgs, RArray, RHash, Ruby, Symbol, Value,
+    Error as MagnusError, KwArgs, RArray, RHash, Ruby, Symbol, TryConvert, Value,
 };
 use parquet::basic::Compression;
-use parquet_core::ParquetValue;
+use parquet_core::{ParquetValue, RangeFilter, SortColumn};
+use std::ops::Bound;
 
-use crate::types::{ColumnEnumeratorArgs, ParquetWriteArgs, RowEnumeratorArgs};
+use crate::converter::ruby_to_parquet;
+use crate::error::to_read_error;
+use crate::io::{RubyIOReader, ThreadSafeRubyIOReader};
+use crate::types::{
+    ColumnEnumeratorArgs, Conversions, ConvertOptions, GeometryFormat, InternStrings, KeyFormat,
+    OnError, ParquetWriteArgs, RowEnumeratorArgs,
+};
+use crate::CloneableChunkReader;
 
 /// Estimate the memory size of a ParquetValue
 pub fn estimate_parquet_value_size(value: &ParquetValue) -> usize {
@@ -25,7 +34,10 @@ pub fn estimate_parquet_value_size(value: &ParquetValue) -> usize {
         ParquetValue::Float32(_) => 4,
         ParquetValue::Float64(_) => 8,
         ParquetValue::String(s) => s.len() + 24, // String overhead
-        ParquetValue::Bytes(b) => b.len() + 24,  // Vec overhead
+        ParquetValue::Json(s) => s.len() + 24,
+        ParquetValue::Enum(s) => s.len() + 24,
+        ParquetValue::Bytes(b) => b.len() + 24, // Vec overhead
+        ParquetValue::Geometry(b) => b.len() + 24,
         ParquetValue::Uuid(_) => 16,
         ParquetValue::Date32(_) => 4,
         ParquetValue::Date64(_) => 8,
@@ -38,6 +50,11 @@ pub fn estimate_parquet_value_size(value: &ParquetValue) -> usize {
         ParquetValue::TimeMillis(_) => 4,
         ParquetValue::TimeMicros(_) => 8,
         ParquetValue::TimeNanos(_) => 8,
+        ParquetValue::DurationSecond(_) => 8,
+        ParquetValue::DurationMillis(_) => 8,
+        ParquetValue::DurationMicros(_) => 8,
+        ParquetValue::DurationNanos(_) => 8,
+        ParquetValue::Interval(_, _, _) => 12,
         ParquetValue::List(items) => {
             24 + items.iter().map(estimate_parquet_value_size).sum::<usize>()
         }
@@ -53,6 +70,7 @@ pub fn estimate_parquet_value_size(value: &ParquetValue) -> usize {
                 .map(|(k, v)| k.len() + 24 + estimate_parquet_value_size(v))
                 .sum::<usize>()
         }
+        ParquetValue::Variant(v) => v.metadata.len() + v.value.as_ref().map_or(0, |b| b.len()) + 48,
     }
 }
 
@@ -78,9 +96,29 @@ pub fn parse_compression(compression: Option<String>) -> Result<Compression, Mag
     }
 }
 
+/// Open a Parquet source given as a file path or IO object
+pub fn open_chunk_reader(ruby: &Ruby, to_read: Value) -> Result<CloneableChunkReader, MagnusError> {
+    if to_read.is_kind_of(ruby.class_string()) {
+        let path_str: String = TryConvert::try_convert(to_read)?;
+        CloneableChunkReader::from_path(&path_str).map_err(to_read_error)
+    } else if to_read.respond_to("read", false)? {
+        let ruby_reader = RubyIOReader::new(to_read).map_err(to_read_error)?;
+        CloneableChunkReader::from_ruby_io(ThreadSafeRubyIOReader::new(ruby_reader))
+            .map_err(to_read_error)
+    } else {
+        Err(MagnusError::new(
+            ruby.exception_runtime_error(),
+            format!(
+                "Invalid input type: expected String or IO object with read method, got {}",
+                to_read.class()
+            ),
+        ))
+    }
+}
+
 /// Parse arguments for Parquet writing
 pub fn parse_parquet_write_args(
-    _ruby: &Ruby,
+    ruby: &Ruby,
     args: &[Value],
 ) -> Result<ParquetWriteArgs, MagnusError> {
     let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
@@ -96,6 +134,10 @@ pub fn parse_parquet_write_args(
             Option<Option<usize>>,
             Option<Option<Value>>,
             Option<Option<bool>>,
+            Option<Option<Value>>,
+            Option<Option<bool>>,
+            Option<Option<Value>>,
+            Option<Option<Value>>,
         ),
         (),
     >(
@@ -108,9 +150,30 @@ pub fn parse_parquet_write_args(
             "sample_size",
             "logger",
             "string_cache",
+            "sorted_by",
+            "verify_sorted",
+            "on_error",
+            "dead_letter",
         ],
     )?;
 
+    let on_error = match kwargs.optional.8.flatten() {
+        Some(value) => match parse_string_or_symbol(ruby, value)? {
+            Some(name) => name
+                .parse()
+                .map_err(|e: String| MagnusError::new(ruby.exception_arg_error(), e))?,
+            None => OnError::Raise,
+        },
+        None => OnError::Raise,
+    };
+    let dead_letter = kwargs.optional.9.flatten().filter(|v| !v.is_nil());
+    if dead_letter.is_some() && on_error == OnError::Raise {
+        return Err(MagnusError::new(
+            ruby.exception_arg_error(),
+            "dead_letter: requires on_error: :skip or :collect",
+        ));
+    }
+
     Ok(ParquetWriteArgs {
         read_from,
         write_to: kwargs.required.1,
@@ -121,6 +184,10 @@ pub fn parse_parquet_write_args(
         sample_size: kwargs.optional.3.flatten(),
         logger: kwargs.optional.4.flatten(),
         string_cache: kwargs.optional.5.flatten(),
+        sorted_by: parse_sorted_by(ruby, kwargs.optional.6.flatten())?,
+        verify_sorted: kwargs.optional.7.flatten().unwrap_or(false),
+        on_error,
+        dead_letter,
     })
 }
 
@@ -139,6 +206,175 @@ pub fn parse_string_or_symbol(ruby: &Ruby, value: Value) -> Result<Option<String
     }
 }
 
+/// Parse `sorted_by:`, a list of column names or `[column, direction, options]`
+/// arrays such as `[[:tenant_id, :asc], [:ts, :desc, nulls_first: true]]`
+pub fn parse_sorted_by(ruby: &Ruby, value: Option<Value>) -> Result<Vec<SortColumn>, MagnusError> {
+    let Some(value) = value.filter(|v| !v.is_nil()) else {
+        return Ok(Vec::new());
+    };
+    let arg_error = |message: String| MagnusError::new(ruby.exception_arg_error(), message);
+
+    let entries: RArray = TryConvert::try_convert(value)?;
+    let mut sort_columns = Vec::with_capacity(entries.len());
+    for entry in entries.into_iter() {
+        let parts: Vec<Value> = if entry.is_kind_of(ruby.class_array()) {
+            TryConvert::try_convert(entry)?
+        } else {
+            vec![entry]
+        };
+        let mut parts = parts.into_iter();
+
+        let column = match parts.next() {
+            Some(column) => parse_string_or_symbol(ruby, column)?,
+            None => None,
+        }
+        .ok_or_else(|| arg_error("sorted_by entries must start with a column name".to_string()))?;
+        let mut sort_column = SortColumn::new(column);
+
+        for part in parts {
+            if part.is_kind_of(ruby.class_hash()) {
+                let options: RHash = TryConvert::try_convert(part)?;
+                if let Some(nulls_first) = options
+                    .get(Symbol::new("nulls_first"))
+                    .or_else(|| options.get("nulls_first"))
+                {
+                    sort_column = sort_column.with_nulls_first(nulls_first.to_bool());
+                }
+                continue;
+            }
+            match parse_string_or_symbol(ruby, part)?.as_deref() {
+                Some("asc") => sort_column = sort_column.with_descending(false),
+                Some("desc") => sort_column = sort_column.with_descending(true),
+                other => {
+                    return Err(arg_error(format!(
+                        "Invalid sort direction {:?}, expected :asc or :desc",
+                        other
+                    )))
+                }
+            }
+        }
+        sort_columns.push(sort_column);
+    }
+    Ok(sort_columns)
+}
+
+/// Parse `filter:`, a Hash of column names to Ranges or single values
+///
+/// Ranges may be open ended, e.g. `(10..)`, and may exclude their end.
+pub fn parse_range_filters(
+    ruby: &Ruby,
+    value: Option<Value>,
+) -> Result<Vec<RangeFilter>, MagnusError> {
+    let Some(value) = value.filter(|v| !v.is_nil()) else {
+        return Ok(Vec::new());
+    };
+    let to_parquet = |value: Value| {
+        ruby_to_parquet(value)
+            .map_err(|e| MagnusError::new(ruby.exception_arg_error(), e.to_string()))
+    };
+    let bound = |value: Value, excluded: bool| -> Result<Bound<ParquetValue>, MagnusError> {
+        if value.is_nil() {
+            Ok(Bound::Unbounded)
+        } else if excluded {
+            Ok(Bound::Excluded(to_parquet(value)?))
+        } else {
+            Ok(Bound::Included(to_parquet(value)?))
+        }
+    };
+
+    let conditions: RHash = TryConvert::try_convert(value)?;
+    let mut filters = Vec::with_capacity(conditions.len());
+    conditions.foreach(|column: Value, condition: Value| {
+        let column = parse_string_or_symbol(ruby, column)?.ok_or_else(|| {
+            MagnusError::new(ruby.exception_arg_error(), "filter column cannot be nil")
+        })?;
+
+        let filter = if condition.is_kind_of(ruby.class_range()) {
+            let excludes_end: bool = condition.funcall("exclude_end?", ())?;
+            RangeFilter::new(
+                column,
+                bound(condition.funcall("begin", ())?, false)?,
+                bound(condition.funcall("end", ())?, excludes_end)?,
+            )
+        } else if condition.is_nil() {
+            return Err(MagnusError::new(
+                ruby.exception_arg_error(),
+                format!("filter for {} cannot be nil", column),
+            ));
+        } else {
+            RangeFilter::eq(column, to_parquet(condition)?)
+        };
+        filters.push(filter);
+        Ok(ForEach::Continue)
+    })?;
+    Ok(filters)
+}
+
+/// Parse `convert:`, a Hash of `decimal:`, `timestamp:`, `date:` and
+/// `binary:` conversions, with a `columns:` Hash of the same per column name
+///
+/// Column entries only replace the conversions they name.
+pub fn parse_convert(ruby: &Ruby, value: Option<Value>) -> Result<ConvertOptions, MagnusError> {
+    let Some(value) = value.filter(|v| !v.is_nil()) else {
+        return Ok(ConvertOptions::default());
+    };
+    let options: RHash = TryConvert::try_convert(value)?;
+
+    let mut convert = ConvertOptions::default();
+    let mut columns = None;
+    options.foreach(|key: Value, value: Value| {
+        if parse_string_or_symbol(ruby, key)?.as_deref() == Some("columns") {
+            columns = Some(value);
+        } else {
+            apply_conversion(ruby, &mut convert.types, key, value)?;
+        }
+        Ok(ForEach::Continue)
+    })?;
+
+    if let Some(columns) = columns {
+        let columns: RHash = TryConvert::try_convert(columns)?;
+        columns.foreach(|column: Value, conversions: Value| {
+            let column = parse_string_or_symbol(ruby, column)?.ok_or_else(|| {
+                MagnusError::new(ruby.exception_arg_error(), "convert column cannot be nil")
+            })?;
+            let mut column_conversions = convert.types;
+            let conversions: RHash = TryConvert::try_convert(conversions)?;
+            conversions.foreach(|key: Value, value: Value| {
+                apply_conversion(ruby, &mut column_conversions, key, value)?;
+                Ok(ForEach::Continue)
+            })?;
+            convert.columns.insert(column, column_conversions);
+            Ok(ForEach::Continue)
+        })?;
+    }
+    Ok(convert)
+}
+
+/// Set the conversion for the type named by `key`, e.g. `decimal: :float`
+fn apply_conversion(
+    ruby: &Ruby,
+    conversions: &mut Conversions,
+    key: Value,
+    value: Value,
+) -> Result<(), MagnusError> {
+    let arg_error = |message: String| MagnusError::new(ruby.exception_arg_error(), message);
+    let key = parse_string_or_symbol(ruby, key)?.unwrap_or_default();
+    let value = parse_string_or_symbol(ruby, value)?.unwrap_or_default();
+    match key.as_str() {
+        "decimal" => conversions.decimal = value.parse().map_err(arg_error)?,
+        "timestamp" => conversions.timestamp = value.parse().map_err(arg_error)?,
+        "date" => conversions.date = value.parse().map_err(arg_error)?,
+        "binary" => conversions.binary = value.parse().map_err(arg_error)?,
+        other => {
+            return Err(arg_error(format!(
+                "Invalid convert key {:?}, expected decimal, timestamp, date, binary or columns",
+                other
+            )))
+        }
+    }
+    Ok(())
+}
+
 /// Handle block or enumerator creation
 pub fn handle_block_or_enum<F, T>(
     block_given: bool,
@@ -157,10 +393,13 @@ where
 /// Create a row enumerator
 pub fn create_row_enumerator(args: RowEnumeratorArgs) -> Result<magnus::Enumerator, MagnusError> {
     let kwargs = RHash::new();
-    kwargs.aset(
-        Symbol::new("result_type"),
-        Symbol::new(args.result_type.to_string()),
-    )?;
+    match args.row_class {
+        Some(row_class) => kwargs.aset(Symbol::new("result_type"), row_class)?,
+        None => kwargs.aset(
+            Symbol::new("result_type"),
+            Symbol::new(args.result_type.to_string()),
+        )?,
+    }
     if let Some(columns) = args.columns {
         kwargs.aset(Symbol::new("columns"), RArray::from_vec(columns))?;
     }
@@ -170,6 +409,39 @@ pub fn create_row_enumerator(args: RowEnumeratorArgs) -> Result<magnus::Enumerat
     if let Some(logger) = args.logger {
         kwargs.aset(Symbol::new("logger"), logger)?;
     }
+    if args.parse_json {
+        kwargs.aset(Symbol::new("parse_json"), true)?;
+    }
+    if args.symbolize_enums {
+        kwargs.aset(Symbol::new("symbolize_enums"), true)?;
+    }
+    if args.geometry_format != GeometryFormat::default() {
+        kwargs.aset(
+            Symbol::new("geometry_format"),
+            Symbol::new(args.geometry_format.to_string()),
+        )?;
+    }
+    match args.keys {
+        KeyFormat::String => {}
+        KeyFormat::Symbol => kwargs.aset(Symbol::new("keys"), Symbol::new("symbol"))?,
+        KeyFormat::Proc(callable) => kwargs.aset(Symbol::new("keys"), callable)?,
+    }
+    if args.skip_corrupt_row_groups {
+        kwargs.aset(Symbol::new("skip_corrupt_row_groups"), true)?;
+    }
+    if let Some(filter) = args.filter {
+        kwargs.aset(Symbol::new("filter"), filter)?;
+    }
+    match args.intern_strings {
+        InternStrings::None => {}
+        InternStrings::DictionaryEncoded => kwargs.aset(Symbol::new("intern_strings"), true)?,
+        InternStrings::Columns(columns) => {
+            kwargs.aset(Symbol::new("intern_strings"), RArray::from_vec(columns))?
+        }
+    }
+    if let Some(convert) = args.convert {
+        kwargs.aset(Symbol::new("convert"), convert)?;
+    }
     Ok(args
         .rb_self
         .enumeratorize("each_row", (args.to_read, KwArgs(kwargs))))
@@ -197,6 +469,15 @@ pub fn create_column_enumerator(
     if let Some(logger) = args.logger {
         kwargs.aset(Symbol::new("logger"), logger)?;
     }
+    if args.skip_corrupt_row_groups {
+        kwargs.aset(Symbol::new("skip_corrupt_row_groups"), true)?;
+    }
+    if let Some(filter) = args.filter {
+        kwargs.aset(Symbol::new("filter"), filter)?;
+    }
+    if let Some(convert) = args.convert {
+        kwargs.aset(Symbol::new("convert"), convert)?;
+    }
     Ok(args
         .rb_self
         .enumeratorize("each_column", (args.to_read, KwArgs(kwargs))))
@@ -0,0 +1,48 @@
+//! `Parquet.verify`: integrity report for a file as a Ruby hash
+
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, RArray, Ruby, Value};
+use parquet_core::reader::Reader;
+use parquet_core::VerifyProblem;
+
+use crate::error::to_read_error;
+use crate::utils::open_chunk_reader;
+
+/// Verify a file path or IO object
+///
+/// Problems are returned under `"problems"`, each with the row group, leaf
+/// column and page offset it was found at (nil when not specific to one)
+/// and an `"error"` message.
+pub fn verify(ruby: &Ruby, to_read: Value, checksums: bool) -> Result<Value, MagnusError> {
+    let chunk_reader = open_chunk_reader(ruby, to_read)?;
+
+    let report = Reader::new(chunk_reader)
+        .verify(checksums)
+        .map_err(to_read_error)?;
+
+    let hash = ruby.hash_new();
+    hash.aset("ok", report.is_ok())?;
+    hash.aset("num_rows", report.num_rows)?;
+    hash.aset("row_groups", report.row_groups)?;
+    hash.aset("pages", report.pages)?;
+    hash.aset("checksums_verified", report.checksums_verified)?;
+    hash.aset("problems", problems_to_ruby(ruby, &report.problems)?)?;
+    Ok(hash.as_value())
+}
+
+/// Problems as an array of hashes, shared with `Parquet.salvage`
+pub(crate) fn problems_to_ruby(
+    ruby: &Ruby,
+    problems: &[VerifyProblem],
+) -> Result<RArray, MagnusError> {
+    let array = ruby.ary_new_capa(problems.len());
+    for problem in problems {
+        let hash = ruby.hash_new();
+        hash.aset("row_group", problem.row_group)?;
+        hash.aset("column", problem.column.clone())?;
+        hash.aset("page_offset", problem.page_offset)?;
+        hash.aset("error", problem.message.clone())?;
+        array.push(hash)?;
+    }
+    Ok(array)
+}
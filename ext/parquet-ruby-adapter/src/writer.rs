@@ -1,34 +1,55 @@
 use magnus::value::ReprValue;
-use magnus::{Error as MagnusError, Ruby, TryConvert, Value};
-use parquet::file::properties::WriterProperties;
-use parquet_core::Schema;
+use magnus::{Error as MagnusError, RArray, Ruby, TryConvert, Value};
+use parquet_core::{ParquetError, ParquetValue, Schema, SchemaNode, SortColumn, WriterBuilder};
+use serde_json::{json, Value as JsonValue};
+use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
 use tempfile::NamedTempFile;
 
+use crate::converter::RubyValueConverter;
+use crate::error::{to_magnus_error, ConversionError};
 use crate::io::RubyIOWriter;
-use crate::types::WriterOutput;
+use crate::json::ruby_to_json;
+use crate::types::{OnError, WriterOutput};
 use crate::utils::parse_compression;
 
 /// Create a writer based on the output type (file path or IO object)
+///
+/// `sorted_by` is declared as the sort order of every row group and, with
+/// `verify_sorted`, checked as rows are written.
 pub fn create_writer(
     ruby: &Ruby,
     write_to: Value,
     schema: Schema,
     compression: Option<String>,
+    sorted_by: Vec<SortColumn>,
+    verify_sorted: bool,
 ) -> Result<WriterOutput, MagnusError> {
-    let compression_setting = parse_compression(compression)?;
-    let props = WriterProperties::builder()
-        .set_compression(compression_setting)
-        .build();
+    let builder = WriterBuilder::new()
+        .with_compression(parse_compression(compression)?)
+        .with_sorted_by(sorted_by)
+        .with_verify_sorted(verify_sorted);
+
+    let (file, pending_copy) = open_output(ruby, write_to)?;
+    let writer = builder.build(file, schema).map_err(to_magnus_error)?;
+
+    Ok(match pending_copy {
+        None => WriterOutput::File(writer),
+        Some((temp_file, io_object)) => WriterOutput::TempFile(writer, temp_file, io_object),
+    })
+}
 
+/// Open the file to write to: the path itself, or for IO objects a temporary
+/// file that [`finish_output`] copies into the IO once it is complete
+pub fn open_output(
+    ruby: &Ruby,
+    write_to: Value,
+) -> Result<(File, Option<(NamedTempFile, Value)>), MagnusError> {
     if write_to.is_kind_of(ruby.class_string()) {
         // Direct file path
         let path_str: String = TryConvert::try_convert(write_to)?;
-        let file = std::fs::File::create(&path_str)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
-        let writer = parquet_core::writer::Writer::new_with_properties(file, schema, props)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
-        Ok(WriterOutput::File(writer))
+        let file = File::create(&path_str).map_err(to_magnus_error)?;
+        Ok((file, None))
     } else {
         // IO-like object - create temporary file
         let temp_file = NamedTempFile::new().map_err(|e| {
@@ -46,10 +67,15 @@ pub fn create_writer(
             )
         })?;
 
-        let writer = parquet_core::writer::Writer::new_with_properties(file, schema, props)
-            .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+        Ok((file, Some((temp_file, write_to))))
+    }
+}
 
-        Ok(WriterOutput::TempFile(writer, temp_file, write_to))
+/// Copy a completed temporary file from [`open_output`] to its IO object
+pub fn finish_output(pending_copy: Option<(NamedTempFile, Value)>) -> Result<(), MagnusError> {
+    match pending_copy {
+        Some((temp_file, io_object)) => copy_temp_file_to_io(temp_file, io_object),
+        None => Ok(()),
     }
 }
 
@@ -108,12 +134,10 @@ pub fn write_rows(
     write_args: crate::types::ParquetWriteArgs,
 ) -> Result<Value, MagnusError> {
     use crate::batch_manager::BatchSizeManager;
-    use crate::converter::RubyValueConverter;
     use crate::logger::RubyLogger;
     use crate::schema::{extract_field_schemas, process_schema_value, ruby_schema_to_parquet};
     use crate::string_cache::StringCache;
     use crate::utils::estimate_row_size;
-    use magnus::{RArray, TryConvert};
 
     // Convert data to array if it isn't already
     let data_array = if write_args.read_from.is_kind_of(ruby.class_array()) {
@@ -132,11 +156,10 @@ pub fn write_rows(
 
     // Process schema value
     let schema_hash = process_schema_value(ruby, write_args.schema_value, Some(&data_array))
-        .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+        .map_err(to_magnus_error)?;
 
     // Create schema
-    let schema = ruby_schema_to_parquet(schema_hash)
-        .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+    let schema = ruby_schema_to_parquet(schema_hash).map_err(to_magnus_error)?;
 
     // Extract field schemas for conversion hints
     let field_schemas = extract_field_schemas(&schema);
@@ -147,6 +170,8 @@ pub fn write_rows(
         write_args.write_to,
         schema.clone(),
         write_args.compression,
+        write_args.sorted_by,
+        write_args.verify_sorted,
     )?;
 
     // Create logger
@@ -182,41 +207,45 @@ pub fn write_rows(
     let mut batch_memory_size = 0usize;
     let mut total_rows = 0u64;
 
-    for row_value in data_array.into_iter() {
+    // Rows left out under `on_error: :skip` or `:collect`
+    let on_error = write_args.on_error;
+    let mut dead_letter = write_args.dead_letter.map(RubyIOWriter::new);
+    let mut rejected = Vec::new();
+    let mut skipped_rows = 0u64;
+
+    for (row_index, row_value) in data_array.into_iter().enumerate() {
+        let row_index = row_index as u64;
+
         // Convert Ruby row to ParquetValue vector
-        let row = if row_value.is_kind_of(ruby.class_array()) {
+        let converted = if row_value.is_kind_of(ruby.class_array()) {
             let array: RArray = TryConvert::try_convert(row_value)?;
-            let mut values = Vec::with_capacity(array.len());
+            convert_row(&mut converter, array, &field_schemas, row_index)
+        } else {
+            Err(ConversionError::new("each row must be an array")
+                .with_row_index(row_index)
+                .with_value_class(row_value.class().inspect()))
+        };
 
-            for (idx, item) in array.into_iter().enumerate() {
-                let schema_hint = field_schemas.get(idx);
-                let pq_value = converter
-                    .to_parquet_with_schema_hint(item, schema_hint)
-                    .map_err(|e| {
-                        let error_msg = e.to_string();
-                        // Check if this is an encoding error
-                        if error_msg.contains("EncodingError")
-                            || error_msg.contains("invalid utf-8")
-                        {
-                            // Extract the actual encoding error message
-                            if let Some(pos) = error_msg.find("EncodingError: ") {
-                                let encoding_msg = error_msg[pos + 15..].to_string();
-                                MagnusError::new(ruby.exception_encoding_error(), encoding_msg)
-                            } else {
-                                MagnusError::new(ruby.exception_encoding_error(), error_msg)
-                            }
-                        } else {
-                            MagnusError::new(ruby.exception_runtime_error(), error_msg)
+        let row = match on_error {
+            OnError::Raise => converted?,
+            OnError::Skip | OnError::Collect => {
+                match converted
+                    .and_then(|row| validate_row(&writer_output, row, &field_schemas, row_index))
+                {
+                    Ok(row) => row,
+                    Err(error) => {
+                        let _ = logger.warn(|| format!("Skipping row {}: {}", row_index, error));
+                        if let Some(io) = &mut dead_letter {
+                            write_dead_letter(ruby, io, row_value, &error)?;
                         }
-                    })?;
-                values.push(pq_value);
+                        skipped_rows += 1;
+                        if on_error == OnError::Collect {
+                            rejected.push(error);
+                        }
+                        continue;
+                    }
+                }
             }
-            values
-        } else {
-            return Err(MagnusError::new(
-                ruby.exception_type_error(),
-                "each row must be an array",
-            ));
         };
 
         // Record row size for dynamic batch sizing
@@ -250,13 +279,7 @@ pub fn write_rows(
                 batch_manager.current_batch_size,
                 batch_memory_size
             ));
-            match &mut writer_output {
-                WriterOutput::File(writer) | WriterOutput::TempFile(writer, _, _) => {
-                    writer.write_rows(std::mem::take(&mut batch)).map_err(|e| {
-                        MagnusError::new(ruby.exception_runtime_error(), e.to_string())
-                    })?;
-                }
-            }
+            write_batch(&mut writer_output, std::mem::take(&mut batch), on_error)?;
             batch_memory_size = 0;
         }
     }
@@ -265,17 +288,20 @@ pub fn write_rows(
     if !batch.is_empty() {
         let _ = logger.info(|| format!("Writing batch of {} rows", batch.len()));
         let _ = logger.debug(|| format!("Final batch: {} rows", batch.len()));
-        match &mut writer_output {
-            WriterOutput::File(writer) | WriterOutput::TempFile(writer, _, _) => {
-                writer
-                    .write_rows(batch)
-                    .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
-            }
-        }
+        write_batch(&mut writer_output, batch, on_error)?;
     }
 
     let _ = logger.info(|| format!("Finished writing {} rows to parquet file", total_rows));
 
+    if skipped_rows > 0 {
+        let _ = logger.info(|| {
+            format!(
+                "Left out {} rows that failed conversion or validation",
+                skipped_rows
+            )
+        });
+    }
+
     // Log string cache statistics if enabled
     if let Some(stats) = converter.string_cache_stats() {
         let _ = logger.info(|| {
@@ -291,7 +317,10 @@ pub fn write_rows(
     // Finalize the writer
     finalize_writer(writer_output)?;
 
-    Ok(ruby.qnil().as_value())
+    match on_error {
+        OnError::Collect => rejected_to_ruby(ruby, &rejected),
+        OnError::Raise | OnError::Skip => Ok(ruby.qnil().as_value()),
+    }
 }
 
 /// Write data in column format to a parquet file
@@ -299,9 +328,14 @@ pub fn write_columns(
     ruby: &Ruby,
     write_args: crate::types::ParquetWriteArgs,
 ) -> Result<Value, MagnusError> {
-    use crate::converter::RubyValueConverter;
     use crate::schema::{extract_field_schemas, process_schema_value, ruby_schema_to_parquet};
-    use magnus::{RArray, TryConvert};
+
+    if write_args.on_error != OnError::Raise {
+        return Err(MagnusError::new(
+            ruby.exception_arg_error(),
+            "on_error: is only supported by write_rows",
+        ));
+    }
 
     // Convert data to array for processing
     let data_array = if write_args.read_from.is_kind_of(ruby.class_array()) {
@@ -320,11 +354,10 @@ pub fn write_columns(
 
     // Process schema value
     let schema_hash = process_schema_value(ruby, write_args.schema_value, Some(&data_array))
-        .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+        .map_err(to_magnus_error)?;
 
     // Create schema
-    let schema = ruby_schema_to_parquet(schema_hash)
-        .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+    let schema = ruby_schema_to_parquet(schema_hash).map_err(to_magnus_error)?;
 
     // Extract field schemas for conversion hints
     let field_schemas = extract_field_schemas(&schema);
@@ -335,6 +368,8 @@ pub fn write_columns(
         write_args.write_to,
         schema.clone(),
         write_args.compression,
+        write_args.sorted_by,
+        write_args.verify_sorted,
     )?;
 
     // Get column names from schema
@@ -395,24 +430,11 @@ pub fn write_columns(
             let schema_hint = field_schemas.get(col_idx);
 
             for value in values_array.into_iter() {
+                let row_index = all_columns[col_idx].1.len() as u64;
                 let pq_value = converter
                     .to_parquet_with_schema_hint(value, schema_hint)
                     .map_err(|e| {
-                        let error_msg = e.to_string();
-                        // Check if this is an encoding error
-                        if error_msg.contains("EncodingError")
-                            || error_msg.contains("invalid utf-8")
-                        {
-                            // Extract the actual encoding error message
-                            if let Some(pos) = error_msg.find("EncodingError: ") {
-                                let encoding_msg = error_msg[pos + 15..].to_string();
-                                MagnusError::new(ruby.exception_encoding_error(), encoding_msg)
-                            } else {
-                                MagnusError::new(ruby.exception_encoding_error(), error_msg)
-                            }
-                        } else {
-                            MagnusError::new(ruby.exception_runtime_error(), error_msg)
-                        }
+                        MagnusError::from(value_error(e, row_index, schema_hint, value))
                     })?;
                 all_columns[col_idx].1.push(pq_value);
             }
@@ -422,9 +444,7 @@ pub fn write_columns(
     // Write the columns
     match &mut writer_output {
         WriterOutput::File(writer) | WriterOutput::TempFile(writer, _, _) => {
-            writer
-                .write_columns(all_columns)
-                .map_err(|e| MagnusError::new(ruby.exception_runtime_error(), e.to_string()))?;
+            writer.write_columns(all_columns).map_err(to_magnus_error)?;
         }
     }
 
@@ -433,3 +453,132 @@ pub fn write_columns(
 
     Ok(ruby.qnil().as_value())
 }
+
+/// Convert one row, stopping at the first value that can't be converted
+fn convert_row(
+    converter: &mut RubyValueConverter,
+    array: RArray,
+    field_schemas: &[SchemaNode],
+    row_index: u64,
+) -> Result<Vec<ParquetValue>, ConversionError> {
+    let mut values = Vec::with_capacity(array.len());
+    for (idx, item) in array.into_iter().enumerate() {
+        let schema_hint = field_schemas.get(idx);
+        let value = converter
+            .to_parquet_with_schema_hint(item, schema_hint)
+            .map_err(|e| value_error(e, row_index, schema_hint, item))?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Check a converted row against the writer's schema before it's batched,
+/// where a bad row would fail the whole batch
+fn validate_row(
+    writer_output: &WriterOutput,
+    row: Vec<ParquetValue>,
+    field_schemas: &[SchemaNode],
+    row_index: u64,
+) -> Result<Vec<ParquetValue>, ConversionError> {
+    if row.len() != field_schemas.len() {
+        return Err(ConversionError::new(format!(
+            "Row has {} values but schema has {} fields",
+            row.len(),
+            field_schemas.len()
+        ))
+        .with_row_index(row_index));
+    }
+
+    let writer = match writer_output {
+        WriterOutput::File(writer) | WriterOutput::TempFile(writer, _, _) => writer,
+    };
+    for (idx, (value, field)) in row.iter().zip(field_schemas).enumerate() {
+        writer.validate_value(idx, value).map_err(|e| {
+            ConversionError::new(e.to_string())
+                .with_row_index(row_index)
+                .with_column_path(field.name())
+                .with_expected_type(type_name(field))
+        })?;
+    }
+    Ok(row)
+}
+
+/// Write a batch of converted rows
+///
+/// Under `on_error: :skip` and `:collect` every row already passed
+/// [`validate_row`], so the writer doesn't check its values again. Failures
+/// only found once the batch is built, such as rows out of `sorted_by` order,
+/// still raise.
+fn write_batch(
+    writer_output: &mut WriterOutput,
+    rows: Vec<Vec<ParquetValue>>,
+    on_error: OnError,
+) -> Result<(), MagnusError> {
+    let writer = match writer_output {
+        WriterOutput::File(writer) | WriterOutput::TempFile(writer, _, _) => writer,
+    };
+    match on_error {
+        OnError::Raise => writer.write_rows(rows),
+        OnError::Skip | OnError::Collect => writer.write_rows_unchecked(rows),
+    }
+    .map_err(to_magnus_error)
+}
+
+/// Append a left out row to the dead letter IO as one line of NDJSON
+fn write_dead_letter(
+    ruby: &Ruby,
+    io: &mut RubyIOWriter,
+    row: Value,
+    error: &ConversionError,
+) -> Result<(), MagnusError> {
+    // Values JSON can't hold, such as invalid UTF-8, fall back to #inspect
+    let row = ruby_to_json(ruby, row).unwrap_or_else(|_| JsonValue::String(row.inspect()));
+    let line = json!({
+        "row_index": error.row_index,
+        "column": error.column_path,
+        "message": error.message,
+        "row": row,
+    });
+    writeln!(io, "{}", line).map_err(to_magnus_error)
+}
+
+/// `on_error: :collect` result: one Hash per left out row
+fn rejected_to_ruby(ruby: &Ruby, rejected: &[ConversionError]) -> Result<Value, MagnusError> {
+    let array = ruby.ary_new_capa(rejected.len());
+    for error in rejected {
+        let hash = ruby.hash_new();
+        hash.aset("row_index", error.row_index)?;
+        hash.aset("column", error.column_path.clone())?;
+        hash.aset("message", error.message.clone())?;
+        array.push(hash)?;
+    }
+    Ok(array.as_value())
+}
+
+/// `Parquet::ConversionError` for a value that couldn't be converted to its
+/// column's type
+fn value_error(
+    err: ParquetError,
+    row_index: u64,
+    field: Option<&SchemaNode>,
+    value: Value,
+) -> ConversionError {
+    let error = ConversionError::new(err.to_string())
+        .with_row_index(row_index)
+        .with_value_class(value.class().inspect());
+    match field {
+        Some(field) => error
+            .with_column_path(field.name())
+            .with_expected_type(type_name(field)),
+        None => error,
+    }
+}
+
+fn type_name(node: &SchemaNode) -> &'static str {
+    match node {
+        SchemaNode::Primitive { primitive_type, .. } => primitive_type.type_name(),
+        SchemaNode::List { .. } => "List",
+        SchemaNode::Map { .. } => "Map",
+        SchemaNode::Struct { .. } => "Struct",
+    }
+}
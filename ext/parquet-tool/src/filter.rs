@@ -0,0 +1,156 @@
+//! Row filters for `cat --filter`
+//!
+//! A filter compares one top level column with a literal, e.g. `age>=30` or
+//! `name=Alice`. The literal is cast to the column's Arrow type, so anything
+//! Arrow can cast from a string works, ISO 8601 dates and timestamps included.
+//! Rows where the column is null never match.
+
+use arrow::array::{Array, BooleanArray, RecordBatch, Scalar, StringArray};
+use arrow::compute::kernels::cmp;
+use arrow::compute::{and, cast};
+use parquet_core::{ParquetError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// Operators in the order they are searched for, longest first
+const OPERATORS: [(&str, Op); 7] = [
+    ("==", Op::Eq),
+    ("!=", Op::NotEq),
+    ("<=", Op::LtEq),
+    (">=", Op::GtEq),
+    ("=", Op::Eq),
+    ("<", Op::Lt),
+    (">", Op::Gt),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pub column: String,
+    op: Op,
+    value: String,
+}
+
+impl Filter {
+    pub fn parse(expr: &str) -> Result<Self> {
+        let (position, token, op) = OPERATORS
+            .iter()
+            .filter_map(|(token, op)| expr.find(token).map(|pos| (pos, *token, *op)))
+            // The leftmost operator wins; at the same position the longer one
+            .min_by_key(|(pos, token, _)| (*pos, usize::MAX - token.len()))
+            .ok_or_else(|| {
+                ParquetError::invalid_argument(format!(
+                    "Filter {:?} has no operator, expected one of = != < <= > >=",
+                    expr
+                ))
+            })?;
+
+        let column = expr[..position].trim();
+        if column.is_empty() {
+            return Err(ParquetError::invalid_argument(format!(
+                "Filter {:?} has no column",
+                expr
+            )));
+        }
+
+        Ok(Self {
+            column: column.to_string(),
+            op,
+            value: expr[position + token.len()..].trim().to_string(),
+        })
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> Result<BooleanArray> {
+        let column = batch.column_by_name(&self.column).ok_or_else(|| {
+            ParquetError::invalid_argument(format!("Unknown filter column {:?}", self.column))
+        })?;
+
+        let literal = StringArray::from(vec![self.value.as_str()]);
+        let literal = cast(&literal, column.data_type()).map_err(|e| {
+            ParquetError::invalid_argument(format!(
+                "Cannot compare {} with {:?}: {}",
+                self.column, self.value, e
+            ))
+        })?;
+        if literal.is_null(0) {
+            return Err(ParquetError::invalid_argument(format!(
+                "{:?} is not a valid value for {}",
+                self.value, self.column
+            )));
+        }
+        let literal = Scalar::new(literal);
+
+        let mask = match self.op {
+            Op::Eq => cmp::eq(column, &literal),
+            Op::NotEq => cmp::neq(column, &literal),
+            Op::Lt => cmp::lt(column, &literal),
+            Op::LtEq => cmp::lt_eq(column, &literal),
+            Op::Gt => cmp::gt(column, &literal),
+            Op::GtEq => cmp::gt_eq(column, &literal),
+        }?;
+        Ok(mask)
+    }
+}
+
+/// Rows of `batch` matching every filter
+pub fn apply(filters: &[Filter], batch: &RecordBatch) -> Result<RecordBatch> {
+    let mut mask: Option<BooleanArray> = None;
+    for filter in filters {
+        let matches = filter.evaluate(batch)?;
+        mask = Some(match mask {
+            Some(mask) => and(&mask, &matches)?,
+            None => matches,
+        });
+    }
+    match mask {
+        Some(mask) => Ok(arrow::compute::filter_record_batch(batch, &mask)?),
+        None => Ok(batch.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{ArrayRef, Int64Array};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parse() {
+        let filter = Filter::parse("age >= 30").unwrap();
+        assert_eq!(filter.column, "age");
+        assert_eq!(filter.op, Op::GtEq);
+        assert_eq!(filter.value, "30");
+
+        let filter = Filter::parse("name==a=b").unwrap();
+        assert_eq!(filter.op, Op::Eq);
+        assert_eq!(filter.value, "a=b");
+
+        assert_eq!(Filter::parse("id!=1").unwrap().op, Op::NotEq);
+        assert!(Filter::parse("age").is_err());
+        assert!(Filter::parse("=1").is_err());
+    }
+
+    #[test]
+    fn test_apply() {
+        let ids: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), Some(5), None, Some(9)]));
+        let names: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "b"]));
+        let batch = RecordBatch::try_from_iter(vec![("id", ids), ("name", names)]).unwrap();
+
+        let filters = vec![
+            Filter::parse("id>1").unwrap(),
+            Filter::parse("name=b").unwrap(),
+        ];
+        let filtered = apply(&filters, &batch).unwrap();
+        assert_eq!(filtered.num_rows(), 2);
+
+        let filters = vec![Filter::parse("id<x").unwrap()];
+        assert!(apply(&filters, &batch).is_err());
+    }
+}
@@ -0,0 +1,596 @@
+//! `parquet-tool`: inspect and convert Parquet files from the command line
+//!
+//! Commands print human readable text by default and JSON with `--json`.
+//! Everything is built on `parquet-core`, so files read exactly as they do
+//! from Ruby.
+
+mod filter;
+mod meta;
+mod source;
+mod values;
+
+use arrow::json::LineDelimitedWriter;
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
+use arrow::util::pretty::pretty_format_batches;
+use clap::{Parser, Subcommand};
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet::basic::Compression;
+use parquet::schema::printer::print_schema;
+use parquet_core::convert::{
+    arrow_ipc_to_parquet, csv_to_parquet, ndjson_to_parquet, parquet_to_csv, parquet_to_ndjson,
+    CsvOptions,
+};
+use parquet_core::{ColumnStatistics, ParquetError, Reader, Result, WriterBuilder};
+use serde_json::{json, Value as JsonValue};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use crate::filter::Filter;
+use crate::source::FileSource;
+
+#[derive(Parser)]
+#[command(
+    name = "parquet-tool",
+    version,
+    about = "Inspect and convert Parquet files"
+)]
+struct Cli {
+    /// Print JSON instead of human readable text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the schema
+    Schema { file: PathBuf },
+
+    /// Print file, row group and column chunk metadata
+    Meta {
+        file: PathBuf,
+        /// Also list every page of every column chunk from the page index
+        #[arg(long)]
+        page_index: bool,
+    },
+
+    /// Print the first rows
+    Head {
+        file: PathBuf,
+        /// Number of rows
+        #[arg(short = 'n', long, default_value_t = 10)]
+        rows: usize,
+        /// Comma separated top level columns to print
+        #[arg(long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+    },
+
+    /// Print all rows, optionally filtered
+    Cat {
+        file: PathBuf,
+        /// Comma separated top level columns to print
+        #[arg(long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+        /// Keep rows where e.g. `age>=30` or `name=Alice`; repeat to combine
+        #[arg(long = "filter")]
+        filters: Vec<String>,
+    },
+
+    /// Print the number of rows
+    Count { file: PathBuf },
+
+    /// Print decoded column statistics from the footer
+    Stats {
+        file: PathBuf,
+        /// Comma separated top level columns
+        #[arg(long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+        /// Also print each row group's statistics
+        #[arg(long)]
+        row_groups: bool,
+    },
+
+    /// Write Parquet, CSV, JSON Lines or Arrow IPC input as a Parquet file
+    ///
+    /// The input format is taken from its extension: .csv, .json, .jsonl,
+    /// .ndjson, .arrow, .ipc or .feather, and Parquet otherwise.
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+        /// e.g. snappy, zstd, zstd(9), gzip, lz4 or none
+        #[arg(long, default_value = "snappy")]
+        compression: String,
+        /// Maximum number of rows per row group
+        #[arg(long)]
+        row_group_size: Option<usize>,
+    },
+
+    /// Write rows as JSON Lines, to standard output without OUTPUT
+    ToJson {
+        file: PathBuf,
+        output: Option<PathBuf>,
+        /// Comma separated top level columns
+        #[arg(long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+    },
+
+    /// Write rows as CSV, to standard output without OUTPUT
+    ToCsv {
+        file: PathBuf,
+        output: Option<PathBuf>,
+        /// Comma separated top level columns
+        #[arg(long, value_delimiter = ',')]
+        columns: Option<Vec<String>>,
+        /// Single byte field delimiter
+        #[arg(long, default_value_t = ',')]
+        delimiter: char,
+        /// Leave out the header row
+        #[arg(long)]
+        no_header: bool,
+    },
+
+    /// Decode every page and check CRCs and row counts against the metadata
+    Verify {
+        file: PathBuf,
+        /// Skip comparing page CRCs
+        #[arg(long)]
+        no_checksums: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+
+    let result = run(cli, &mut out).and_then(|ok| {
+        out.flush()?;
+        Ok(ok)
+    });
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(ParquetError::Io(e)) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            let _ = out.flush();
+            eprintln!("parquet-tool: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run a command, returning whether it succeeded; only `verify` can fail
+/// without an error
+fn run(cli: Cli, out: &mut impl Write) -> Result<bool> {
+    let json = cli.json;
+    match cli.command {
+        Command::Schema { file } => schema(&file, json, out)?,
+        Command::Meta { file, page_index } => metadata(&file, page_index, json, out)?,
+        Command::Head {
+            file,
+            rows,
+            columns,
+        } => print_rows(&file, columns, &[], Some(rows), json, out)?,
+        Command::Cat {
+            file,
+            columns,
+            filters,
+        } => {
+            let filters = filters
+                .iter()
+                .map(|f| Filter::parse(f))
+                .collect::<Result<Vec<_>>>()?;
+            print_rows(&file, columns, &filters, None, json, out)?
+        }
+        Command::Count { file } => {
+            let metadata = load_metadata(&open(&file)?, false)?;
+            let num_rows = metadata.metadata().file_metadata().num_rows();
+            if json {
+                writeln!(out, "{}", json!({ "num_rows": num_rows }))?;
+            } else {
+                writeln!(out, "{}", num_rows)?;
+            }
+        }
+        Command::Stats {
+            file,
+            columns,
+            row_groups,
+        } => statistics(&file, columns, row_groups, json, out)?,
+        Command::Convert {
+            input,
+            output,
+            compression,
+            row_group_size,
+        } => {
+            let mut builder =
+                WriterBuilder::new().with_compression(parse_compression(&compression)?);
+            if let Some(rows) = row_group_size {
+                builder = builder.with_max_row_group_size(rows);
+            }
+            let rows = convert(&input, &output, builder)?;
+            if json {
+                writeln!(out, "{}", json!({ "rows": rows }))?;
+            } else {
+                writeln!(out, "Wrote {} rows to {}", rows, output.display())?;
+            }
+        }
+        Command::ToJson {
+            file,
+            output,
+            columns,
+        } => {
+            let reader = Reader::new(open(&file)?);
+            match output {
+                Some(path) => {
+                    let output = BufWriter::new(File::create(path)?);
+                    parquet_to_ndjson(reader, output, columns.as_deref())?
+                }
+                None => parquet_to_ndjson(reader, &mut *out, columns.as_deref())?,
+            };
+        }
+        Command::ToCsv {
+            file,
+            output,
+            columns,
+            delimiter,
+            no_header,
+        } => {
+            let delimiter = u8::try_from(delimiter).map_err(|_| {
+                ParquetError::invalid_argument(format!(
+                    "Delimiter {:?} is not a single byte",
+                    delimiter
+                ))
+            })?;
+            let options = CsvOptions::new()
+                .with_header(!no_header)
+                .with_delimiter(delimiter);
+            let reader = Reader::new(open(&file)?);
+            match output {
+                Some(path) => {
+                    let output = BufWriter::new(File::create(path)?);
+                    parquet_to_csv(reader, output, columns.as_deref(), &options)?
+                }
+                None => parquet_to_csv(reader, &mut *out, columns.as_deref(), &options)?,
+            };
+        }
+        Command::Verify { file, no_checksums } => return verify(&file, !no_checksums, json, out),
+    }
+    Ok(true)
+}
+
+fn open(path: &Path) -> Result<FileSource> {
+    FileSource::open(path).map_err(|e| {
+        ParquetError::Io(io::Error::new(
+            e.kind(),
+            format!("{}: {}", path.display(), e),
+        ))
+    })
+}
+
+fn load_metadata(source: &FileSource, page_index: bool) -> Result<ArrowReaderMetadata> {
+    let options = ArrowReaderOptions::new().with_page_index(page_index);
+    Ok(ArrowReaderMetadata::load(source, options)?)
+}
+
+fn parse_compression(name: &str) -> Result<Compression> {
+    let name = name.to_uppercase();
+    match name.as_str() {
+        "NONE" => Ok(Compression::UNCOMPRESSED),
+        _ => Compression::from_str(&name).map_err(|e| {
+            ParquetError::invalid_argument(format!("Invalid compression {:?}: {}", name, e))
+        }),
+    }
+}
+
+fn schema(file: &Path, json: bool, out: &mut impl Write) -> Result<()> {
+    let metadata = load_metadata(&open(file)?, false)?;
+    let root = metadata.metadata().file_metadata().schema();
+    if json {
+        writeln!(out, "{:#}", meta::schema_json(root))?;
+    } else {
+        let mut text = Vec::new();
+        print_schema(&mut text, root);
+        out.write_all(&text)?;
+    }
+    Ok(())
+}
+
+fn metadata(file: &Path, page_index: bool, json: bool, out: &mut impl Write) -> Result<()> {
+    let metadata = load_metadata(&open(file)?, page_index)?;
+    let page_index_schema = page_index.then_some(metadata.schema().as_ref());
+    let value = meta::metadata_json(metadata.metadata(), page_index_schema)?;
+    if json {
+        writeln!(out, "{:#}", value)?;
+        return Ok(());
+    }
+
+    writeln!(out, "num_rows: {}", value["num_rows"])?;
+    if let Some(created_by) = value["created_by"].as_str() {
+        writeln!(out, "created_by: {}", created_by)?;
+    }
+    for kv in value["key_value_metadata"].as_array().into_iter().flatten() {
+        let text = kv["value"].as_str().unwrap_or_default();
+        let text = match text.char_indices().nth(80) {
+            Some((end, _)) => format!("{}...", &text[..end]),
+            None => text.to_string(),
+        };
+        writeln!(
+            out,
+            "metadata {}: {}",
+            kv["key"].as_str().unwrap_or_default(),
+            text
+        )?;
+    }
+    for (idx, row_group) in value["row_groups"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .enumerate()
+    {
+        writeln!(
+            out,
+            "\nrow group {}: {} rows, {} bytes compressed",
+            idx, row_group["num_rows"], row_group["compressed_size"]
+        )?;
+        for column in row_group["columns"].as_array().into_iter().flatten() {
+            let encodings = column["encodings"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|e| e.as_str())
+                .collect::<Vec<_>>();
+            writeln!(
+                out,
+                "  {}: {} values, {}, {} -> {} bytes, encodings {}",
+                column["column_path"].as_str().unwrap_or_default(),
+                column["num_values"],
+                column["compression"].as_str().unwrap_or_default(),
+                column["total_uncompressed_size"],
+                column["total_compressed_size"],
+                encodings.join(", ")
+            )?;
+            for page in column["pages"].as_array().into_iter().flatten() {
+                writeln!(
+                    out,
+                    "    page @{}: first row {}, {} bytes, min {}, max {}, nulls {}",
+                    page["offset"],
+                    page["first_row_index"],
+                    page["compressed_size"],
+                    page["min"],
+                    page["max"],
+                    page["null_count"]
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Print rows as a table, or as JSON Lines with `--json`
+fn print_rows(
+    file: &Path,
+    columns: Option<Vec<String>>,
+    filters: &[Filter],
+    limit: Option<usize>,
+    json: bool,
+    out: &mut impl Write,
+) -> Result<()> {
+    // Filter columns are read too and dropped once the rows are filtered
+    let read_columns = columns.as_ref().map(|columns| {
+        let mut read = columns.clone();
+        for filter in filters {
+            if !read.contains(&filter.column) {
+                read.push(filter.column.clone());
+            }
+        }
+        read
+    });
+
+    let batches = Reader::new(open(file)?).read_batches(read_columns.as_deref(), None)?;
+    let mut selected = Vec::new();
+    let mut remaining = limit.unwrap_or(usize::MAX);
+    let mut json_writer = json.then(|| LineDelimitedWriter::new(&mut *out));
+
+    for batch in batches {
+        if remaining == 0 {
+            break;
+        }
+        let batch = filter::apply(filters, &batch?)?;
+        let batch = project(&batch, columns.as_deref())?;
+        let batch = batch.slice(0, batch.num_rows().min(remaining));
+        remaining -= batch.num_rows();
+
+        match &mut json_writer {
+            Some(writer) => writer.write(&batch)?,
+            None => selected.push(batch),
+        }
+    }
+
+    match json_writer {
+        Some(mut writer) => writer.finish()?,
+        None => writeln!(out, "{}", pretty_format_batches(&selected)?)?,
+    }
+    Ok(())
+}
+
+/// Keep the given columns in their requested order
+fn project(batch: &RecordBatch, columns: Option<&[String]>) -> Result<RecordBatch> {
+    let Some(columns) = columns else {
+        return Ok(batch.clone());
+    };
+    let schema = batch.schema();
+    let indices = columns
+        .iter()
+        .filter_map(|name| schema.index_of(name).ok())
+        .collect::<Vec<_>>();
+    Ok(batch.project(&indices)?)
+}
+
+fn statistics(
+    file: &Path,
+    columns: Option<Vec<String>>,
+    row_groups: bool,
+    json: bool,
+    out: &mut impl Write,
+) -> Result<()> {
+    let stats = Reader::new(open(file)?).statistics(columns.as_deref())?;
+
+    if json {
+        let mut value = json!({
+            "num_rows": stats.num_rows,
+            "columns": columns_json(&stats.columns),
+        });
+        if row_groups {
+            value["row_groups"] = stats
+                .row_groups
+                .iter()
+                .map(|rg| json!({ "num_rows": rg.num_rows, "columns": columns_json(&rg.columns) }))
+                .collect::<Vec<_>>()
+                .into();
+        }
+        writeln!(out, "{:#}", value)?;
+        return Ok(());
+    }
+
+    writeln!(out, "num_rows: {}", stats.num_rows)?;
+    write_columns_text(&stats.columns, out)?;
+    if row_groups {
+        for (idx, row_group) in stats.row_groups.iter().enumerate() {
+            writeln!(out, "\nrow group {}: {} rows", idx, row_group.num_rows)?;
+            write_columns_text(&row_group.columns, out)?;
+        }
+    }
+    Ok(())
+}
+
+fn columns_json(columns: &[ColumnStatistics]) -> JsonValue {
+    let mut hash = serde_json::Map::new();
+    for column in columns {
+        hash.insert(
+            column.path.clone(),
+            json!({
+                "min": column.min.as_ref().map(values::to_json),
+                "max": column.max.as_ref().map(values::to_json),
+                "null_count": column.null_count,
+                "distinct_count": column.distinct_count,
+                "num_values": column.num_values,
+                "total_compressed_size": column.compressed_size,
+                "total_uncompressed_size": column.uncompressed_size,
+            }),
+        );
+    }
+    JsonValue::Object(hash)
+}
+
+fn write_columns_text(columns: &[ColumnStatistics], out: &mut impl Write) -> Result<()> {
+    let optional = |value: Option<u64>| value.map_or("-".to_string(), |v| v.to_string());
+    for column in columns {
+        let bound = |value: &Option<parquet_core::ParquetValue>| {
+            value.as_ref().map_or("-".to_string(), values::display)
+        };
+        writeln!(
+            out,
+            "  {}: min {}, max {}, nulls {}, distinct {}, {} values, {} -> {} bytes",
+            column.path,
+            bound(&column.min),
+            bound(&column.max),
+            optional(column.null_count),
+            optional(column.distinct_count),
+            column.num_values,
+            column.uncompressed_size,
+            column.compressed_size
+        )?;
+    }
+    Ok(())
+}
+
+fn convert(input: &Path, output: &Path, builder: WriterBuilder) -> Result<usize> {
+    let extension = input
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase());
+    let writer = BufWriter::new(File::create(output)?);
+
+    match extension.as_deref() {
+        Some("csv") => {
+            let reader = BufReader::new(File::open(input)?);
+            csv_to_parquet(reader, writer, None, &CsvOptions::new(), builder)
+        }
+        Some("json" | "jsonl" | "ndjson") => {
+            ndjson_to_parquet(BufReader::new(File::open(input)?), writer, None, builder)
+        }
+        Some("arrow" | "ipc" | "feather") => {
+            arrow_ipc_to_parquet(BufReader::new(File::open(input)?), writer, None, builder)
+        }
+        _ => {
+            let batches = Reader::new(open(input)?).read_batches(None, None)?;
+            let mut writer = builder.build_with_arrow_schema(writer, batches.schema())?;
+            let mut rows = 0;
+            for batch in batches {
+                let batch = batch?;
+                rows += batch.num_rows();
+                writer.write_batch(&batch)?;
+            }
+            writer.close()?;
+            Ok(rows)
+        }
+    }
+}
+
+fn verify(file: &Path, checksums: bool, json: bool, out: &mut impl Write) -> Result<bool> {
+    let report = Reader::new(open(file)?).verify(checksums)?;
+
+    if json {
+        let problems = report
+            .problems
+            .iter()
+            .map(|problem| {
+                json!({
+                    "row_group": problem.row_group,
+                    "column": problem.column,
+                    "page_offset": problem.page_offset,
+                    "error": problem.message,
+                })
+            })
+            .collect::<Vec<_>>();
+        let value = json!({
+            "ok": report.is_ok(),
+            "num_rows": report.num_rows,
+            "row_groups": report.row_groups,
+            "pages": report.pages,
+            "checksums_verified": report.checksums_verified,
+            "problems": problems,
+        });
+        writeln!(out, "{:#}", value)?;
+    } else if report.is_ok() {
+        writeln!(
+            out,
+            "OK: {} rows in {} row groups, {} pages, {} checksums verified",
+            report.num_rows, report.row_groups, report.pages, report.checksums_verified
+        )?;
+    } else {
+        for problem in &report.problems {
+            let mut location = Vec::new();
+            if let Some(row_group) = problem.row_group {
+                location.push(format!("row group {}", row_group));
+            }
+            if let Some(column) = &problem.column {
+                location.push(format!("column {}", column));
+            }
+            if let Some(offset) = problem.page_offset {
+                location.push(format!("page at {}", offset));
+            }
+            if location.is_empty() {
+                writeln!(out, "{}", problem.message)?;
+            } else {
+                writeln!(out, "{}: {}", location.join(", "), problem.message)?;
+            }
+        }
+    }
+    Ok(report.is_ok())
+}
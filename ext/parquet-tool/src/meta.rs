@@ -0,0 +1,261 @@
+//! File metadata as JSON
+//!
+//! The structure and key names are the ones `Parquet.metadata` returns in
+//! Ruby, so scripts can move between the gem and the tool unchanged.
+
+use arrow::datatypes::Schema as ArrowSchema;
+use parquet::basic::{LogicalType, TimeUnit};
+use parquet::file::metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData};
+use parquet::schema::types::Type;
+use parquet_core::statistics::page_statistics;
+use parquet_core::Result;
+use serde_json::{json, Map, Value as JsonValue};
+
+use crate::values;
+
+/// The whole footer; with an Arrow schema, column chunks list their pages
+/// when the page index was loaded
+pub fn metadata_json(
+    metadata: &ParquetMetaData,
+    page_index_schema: Option<&ArrowSchema>,
+) -> Result<JsonValue> {
+    let file_metadata = metadata.file_metadata();
+    let key_value_metadata = file_metadata.key_value_metadata().map(|kvs| {
+        kvs.iter()
+            .map(|kv| json!({ "key": kv.key, "value": kv.value }))
+            .collect::<Vec<_>>()
+    });
+
+    let row_groups = metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .map(|(rg_idx, row_group)| row_group_json(metadata, rg_idx, row_group, page_index_schema))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "num_rows": file_metadata.num_rows(),
+        "created_by": file_metadata.created_by(),
+        "key_value_metadata": key_value_metadata,
+        "schema": schema_json(file_metadata.schema()),
+        "row_groups": row_groups,
+    }))
+}
+
+/// The root schema with its top level fields
+pub fn schema_json(schema: &Type) -> JsonValue {
+    let fields = schema.get_fields().iter().map(|field| field_json(field));
+    json!({
+        "name": schema.name(),
+        "fields": fields.collect::<Vec<_>>(),
+    })
+}
+
+fn field_json(field: &Type) -> JsonValue {
+    let mut hash = Map::new();
+    hash.insert("name".into(), field.name().into());
+    match field {
+        Type::PrimitiveType {
+            physical_type,
+            type_length,
+            scale,
+            precision,
+            ..
+        } => {
+            hash.insert("type".into(), "primitive".into());
+            hash.insert(
+                "physical_type".into(),
+                format!("{:?}", physical_type).into(),
+            );
+            hash.insert("type_length".into(), (*type_length).into());
+            hash.insert("scale".into(), (*scale).into());
+            hash.insert("precision".into(), (*precision).into());
+        }
+        Type::GroupType { .. } => {
+            hash.insert("type".into(), "group".into());
+        }
+    }
+
+    let basic_info = field.get_basic_info();
+    hash.insert(
+        "repetition".into(),
+        format!("{:?}", basic_info.repetition()).into(),
+    );
+    hash.insert(
+        "converted_type".into(),
+        format!("{:?}", basic_info.converted_type()).into(),
+    );
+    if let Some(logical_type) = basic_info.logical_type() {
+        hash.insert("logical_type".into(), logical_type_json(&logical_type));
+    }
+    JsonValue::Object(hash)
+}
+
+fn logical_type_json(logical_type: &LogicalType) -> JsonValue {
+    let unit_name = |unit: &TimeUnit| match unit {
+        TimeUnit::MILLIS(_) => "millis",
+        TimeUnit::MICROS(_) => "micros",
+        TimeUnit::NANOS(_) => "nanos",
+    };
+    match logical_type {
+        LogicalType::Decimal { scale, precision } => {
+            json!({ "type": "Decimal", "scale": scale, "precision": precision })
+        }
+        LogicalType::Time {
+            is_adjusted_to_u_t_c,
+            unit,
+        } => json!({
+            "type": "Time",
+            "is_adjusted_to_utc": is_adjusted_to_u_t_c.to_string(),
+            "unit": unit_name(unit),
+        }),
+        LogicalType::Timestamp {
+            is_adjusted_to_u_t_c,
+            unit,
+        } => json!({
+            "type": "Timestamp",
+            "is_adjusted_to_utc": is_adjusted_to_u_t_c,
+            "unit": unit_name(unit),
+        }),
+        LogicalType::Integer {
+            bit_width,
+            is_signed,
+        } => json!({
+            "type": "Integer",
+            "bit_width": bit_width,
+            "is_signed": is_signed.to_string(),
+        }),
+        other => json!({ "type": format!("{:?}", other) }),
+    }
+}
+
+fn row_group_json(
+    metadata: &ParquetMetaData,
+    rg_idx: usize,
+    row_group: &RowGroupMetaData,
+    page_index_schema: Option<&ArrowSchema>,
+) -> Result<JsonValue> {
+    let columns = row_group
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(col_idx, column)| {
+            let mut hash = column_json(column);
+            if let Some(arrow_schema) = page_index_schema {
+                if let Some(pages) = page_statistics(metadata, arrow_schema, rg_idx, col_idx)? {
+                    let pages = pages.iter().map(|page| {
+                        json!({
+                            "first_row_index": page.first_row_index,
+                            "offset": page.offset,
+                            "compressed_size": page.compressed_size,
+                            "min": page.min.as_ref().map(values::to_json),
+                            "max": page.max.as_ref().map(values::to_json),
+                            "null_count": page.null_count,
+                        })
+                    });
+                    hash.insert("pages".into(), pages.collect::<Vec<_>>().into());
+                }
+            }
+            Ok(JsonValue::Object(hash))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let sorting_columns = row_group.sorting_columns().map(|sorting_columns| {
+        sorting_columns
+            .iter()
+            .map(|sorting_column| {
+                let column_idx = sorting_column.column_idx as usize;
+                json!({
+                    "column_path": (column_idx < row_group.num_columns())
+                        .then(|| row_group.column(column_idx).column_path().string()),
+                    "column_idx": sorting_column.column_idx,
+                    "descending": sorting_column.descending,
+                    "nulls_first": sorting_column.nulls_first,
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    Ok(json!({
+        "num_columns": row_group.num_columns(),
+        "num_rows": row_group.num_rows(),
+        "total_byte_size": row_group.total_byte_size(),
+        "file_offset": row_group.file_offset(),
+        "ordinal": row_group.ordinal(),
+        "compressed_size": row_group.compressed_size(),
+        "sorting_columns": sorting_columns,
+        "columns": columns,
+    }))
+}
+
+fn column_json(column: &ColumnChunkMetaData) -> Map<String, JsonValue> {
+    let mut hash = Map::new();
+    hash.insert("column_path".into(), column.column_path().string().into());
+    hash.insert("file_path".into(), column.file_path().into());
+    hash.insert("file_offset".into(), column.file_offset().into());
+    hash.insert("num_values".into(), column.num_values().into());
+    hash.insert(
+        "compression".into(),
+        format!("{:?}", column.compression()).into(),
+    );
+    hash.insert(
+        "total_compressed_size".into(),
+        column.compressed_size().into(),
+    );
+    hash.insert(
+        "total_uncompressed_size".into(),
+        column.uncompressed_size().into(),
+    );
+    hash.insert("data_page_offset".into(), column.data_page_offset().into());
+
+    let optional = [
+        ("dictionary_page_offset", column.dictionary_page_offset()),
+        ("bloom_filter_offset", column.bloom_filter_offset()),
+        (
+            "bloom_filter_length",
+            column.bloom_filter_length().map(i64::from),
+        ),
+        ("offset_index_offset", column.offset_index_offset()),
+        (
+            "offset_index_length",
+            column.offset_index_length().map(i64::from),
+        ),
+        ("column_index_offset", column.column_index_offset()),
+        (
+            "column_index_length",
+            column.column_index_length().map(i64::from),
+        ),
+    ];
+    for (key, value) in optional.into_iter().filter_map(|(k, v)| v.map(|v| (k, v))) {
+        hash.insert(key.into(), value.into());
+    }
+
+    let encodings = column.encodings().iter().map(|e| format!("{:?}", e));
+    hash.insert("encodings".into(), encodings.collect::<Vec<_>>().into());
+
+    if let Some(stats) = column.statistics() {
+        hash.insert(
+            "statistics".into(),
+            json!({
+                "min_is_exact": stats.min_is_exact(),
+                "max_is_exact": stats.max_is_exact(),
+            }),
+        );
+    }
+
+    if let Some(page_encoding_stats) = column.page_encoding_stats() {
+        let stats = page_encoding_stats.iter().map(|stat| {
+            json!({
+                "page_type": format!("{:?}", stat.page_type),
+                "encoding": format!("{:?}", stat.encoding),
+                "count": stat.count,
+            })
+        });
+        hash.insert(
+            "page_encoding_stats".into(),
+            stats.collect::<Vec<_>>().into(),
+        );
+    }
+
+    hash
+}
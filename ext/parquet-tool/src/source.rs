@@ -0,0 +1,52 @@
+//! Cloneable access to a Parquet file on disk
+
+use bytes::Bytes;
+use parquet::errors::{ParquetError, Result};
+use parquet::file::reader::{ChunkReader, Length};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom, Take};
+use std::path::{Path, PathBuf};
+
+/// A file that is reopened for every read, so clones never share a cursor
+#[derive(Clone)]
+pub struct FileSource {
+    path: PathBuf,
+    len: u64,
+}
+
+impl FileSource {
+    pub fn open<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let len = File::open(&path)?.metadata()?.len();
+        Ok(Self { path, len })
+    }
+
+    fn open_at(&self, start: u64) -> Result<File> {
+        let mut file = File::open(&self.path).map_err(|e| ParquetError::External(Box::new(e)))?;
+        file.seek(SeekFrom::Start(start))
+            .map_err(|e| ParquetError::External(Box::new(e)))?;
+        Ok(file)
+    }
+}
+
+impl Length for FileSource {
+    fn len(&self) -> u64 {
+        self.len
+    }
+}
+
+impl ChunkReader for FileSource {
+    type T = Take<BufReader<File>>;
+
+    fn get_read(&self, start: u64) -> Result<Self::T> {
+        Ok(BufReader::new(self.open_at(start)?).take(self.len.saturating_sub(start)))
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes> {
+        let mut buffer = vec![0; length];
+        self.open_at(start)?
+            .read_exact(&mut buffer)
+            .map_err(|e| ParquetError::EOF(format!("{}", e)))?;
+        Ok(Bytes::from(buffer))
+    }
+}
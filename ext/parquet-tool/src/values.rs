@@ -0,0 +1,110 @@
+//! Text and JSON forms of the scalar values found in statistics
+
+use jiff::{Span, Timestamp};
+use parquet_core::ParquetValue;
+use serde_json::Value as JsonValue;
+
+/// Human readable form of a value
+pub fn display(value: &ParquetValue) -> String {
+    match value {
+        ParquetValue::Int8(v) => v.to_string(),
+        ParquetValue::Int16(v) => v.to_string(),
+        ParquetValue::Int32(v) => v.to_string(),
+        ParquetValue::Int64(v) => v.to_string(),
+        ParquetValue::UInt8(v) => v.to_string(),
+        ParquetValue::UInt16(v) => v.to_string(),
+        ParquetValue::UInt32(v) => v.to_string(),
+        ParquetValue::UInt64(v) => v.to_string(),
+        ParquetValue::Float16(v) | ParquetValue::Float32(v) => v.to_string(),
+        ParquetValue::Float64(v) => v.to_string(),
+        ParquetValue::Boolean(v) => v.to_string(),
+        ParquetValue::String(s) | ParquetValue::Json(s) | ParquetValue::Enum(s) => s.to_string(),
+        ParquetValue::Bytes(b) | ParquetValue::Geometry(b) => hex(b),
+        ParquetValue::Uuid(u) => u.to_string(),
+        ParquetValue::Date32(days) => date(*days as i64),
+        ParquetValue::Date64(millis) => date(millis.div_euclid(86_400_000)),
+        ParquetValue::Decimal128(v, scale) => decimal(v.to_string(), *scale),
+        ParquetValue::Decimal256(v, scale) => decimal(v.to_string(), *scale),
+        ParquetValue::TimestampSecond(v, _) => timestamp(Timestamp::from_second(*v).ok()),
+        ParquetValue::TimestampMillis(v, _) => timestamp(Timestamp::from_millisecond(*v).ok()),
+        ParquetValue::TimestampMicros(v, _) => timestamp(Timestamp::from_microsecond(*v).ok()),
+        ParquetValue::TimestampNanos(v, _) => {
+            timestamp(Timestamp::from_nanosecond(*v as i128).ok())
+        }
+        ParquetValue::Null => "null".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// JSON form of a value: numbers and booleans as such, everything else as
+/// its human readable text
+pub fn to_json(value: &ParquetValue) -> JsonValue {
+    match value {
+        ParquetValue::Int8(v) => (*v).into(),
+        ParquetValue::Int16(v) => (*v).into(),
+        ParquetValue::Int32(v) => (*v).into(),
+        ParquetValue::Int64(v) => (*v).into(),
+        ParquetValue::UInt8(v) => (*v).into(),
+        ParquetValue::UInt16(v) => (*v).into(),
+        ParquetValue::UInt32(v) => (*v).into(),
+        ParquetValue::UInt64(v) => (*v).into(),
+        ParquetValue::Float16(v) | ParquetValue::Float32(v) => v.into_inner().into(),
+        ParquetValue::Float64(v) => v.into_inner().into(),
+        ParquetValue::Boolean(v) => (*v).into(),
+        ParquetValue::Null => JsonValue::Null,
+        other => display(other).into(),
+    }
+}
+
+fn date(days: i64) -> String {
+    Span::new()
+        .try_days(days)
+        .and_then(|span| jiff::civil::date(1970, 1, 1).checked_add(span))
+        .map(|date| date.to_string())
+        .unwrap_or_else(|_| format!("{} days since epoch", days))
+}
+
+fn timestamp(ts: Option<Timestamp>) -> String {
+    ts.map(|ts| ts.to_string())
+        .unwrap_or_else(|| "out of range".to_string())
+}
+
+fn decimal(digits: String, scale: i8) -> String {
+    if scale <= 0 {
+        return format!("{}{}", digits, "0".repeat(scale.unsigned_abs() as usize));
+    }
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", digits.as_str()),
+    };
+    let scale = scale as usize;
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, int, frac)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(display(&ParquetValue::Decimal128(-250, 2)), "-2.50");
+        assert_eq!(display(&ParquetValue::Decimal128(5, 3)), "0.005");
+        assert_eq!(display(&ParquetValue::Decimal128(12, -2)), "1200");
+        assert_eq!(display(&ParquetValue::Date32(19737)), "2024-01-15");
+        assert_eq!(
+            display(&ParquetValue::TimestampMillis(1_705_314_600_000, None)),
+            "2024-01-15T10:30:00Z"
+        );
+        assert_eq!(to_json(&ParquetValue::Int32(7)), serde_json::json!(7));
+        assert_eq!(
+            to_json(&ParquetValue::Decimal128(1999, 2)),
+            serde_json::json!("19.99")
+        );
+    }
+}
@@ -1,8 +1,13 @@
 use magnus::scan_args::{get_kwargs, scan_args};
-use magnus::{Error as MagnusError, Ruby, Value};
+use magnus::value::ReprValue;
+use magnus::{Error as MagnusError, RArray, Ruby, TryConvert, Value};
 use parquet_ruby_adapter::utils::parse_string_or_symbol;
 use parquet_ruby_adapter::{
-    logger::RubyLogger, types::ParserResultType, utils::parse_parquet_write_args,
+    types::{
+        ColumnEnumeratorArgs, CsvArgs, GeometryFormat, InternStrings, KeyFormat, ParserResultType,
+        RowEnumeratorArgs,
+    },
+    utils::parse_parquet_write_args,
 };
 pub fn each_row(rb_self: Value, args: &[Value]) -> Result<Value, MagnusError> {
     let ruby = Ruby::get().map_err(|_| {
@@ -25,39 +30,139 @@ pub fn each_row(rb_self: Value, args: &[Value]) -> Result<Value, MagnusError> {
             Option<Option<Vec<String>>>, // columns
             Option<Option<bool>>,        // strict
             Option<Option<Value>>,       // logger
+            Option<Option<bool>>,        // parse_json
+            Option<Option<bool>>,        // symbolize_enums
+            Option<Option<Value>>,       // geometry_format
+            Option<Option<Value>>,       // keys
+            Option<Option<bool>>,        // skip_corrupt_row_groups
+            Option<Option<Value>>,       // filter
+            Option<Option<Value>>,       // intern_strings
+            Option<Option<Value>>,       // convert
         ),
         (),
     >(
         parsed_args.keywords,
         &[],
-        &["result_type", "columns", "strict", "logger"],
+        &[
+            "result_type",
+            "columns",
+            "strict",
+            "logger",
+            "parse_json",
+            "symbolize_enums",
+            "geometry_format",
+            "keys",
+            "skip_corrupt_row_groups",
+            "filter",
+            "intern_strings",
+            "convert",
+        ],
     )?;
 
+    // A Data or Struct class can be given in place of result_type: :struct
+    let mut row_class = None;
     let result_type: ParserResultType = if let Some(rt_value) = kwargs.optional.0.flatten() {
-        parse_string_or_symbol(&ruby, rt_value)?
-            .ok_or_else(|| {
-                MagnusError::new(magnus::exception::arg_error(), "result_type cannot be nil")
-            })?
-            .parse()
-            .map_err(|_| {
-                MagnusError::new(magnus::exception::arg_error(), "Invalid result_type value")
-            })?
+        if rt_value.is_kind_of(ruby.class_class()) {
+            row_class = Some(rt_value);
+            ParserResultType::Struct
+        } else {
+            parse_string_or_symbol(&ruby, rt_value)?
+                .ok_or_else(|| {
+                    MagnusError::new(magnus::exception::arg_error(), "result_type cannot be nil")
+                })?
+                .parse()
+                .map_err(|_| {
+                    MagnusError::new(magnus::exception::arg_error(), "Invalid result_type value")
+                })?
+        }
     } else {
         ParserResultType::Hash
     };
     let columns = kwargs.optional.1.flatten();
     let strict = kwargs.optional.2.flatten().unwrap_or(true);
-    let logger = RubyLogger::new(kwargs.optional.3.flatten())?;
+    let logger = kwargs.optional.3.flatten();
+    let parse_json = kwargs.optional.4.flatten().unwrap_or(false);
+    let symbolize_enums = kwargs.optional.5.flatten().unwrap_or(false);
+    let geometry_format: GeometryFormat = match kwargs.optional.6.flatten() {
+        Some(value) => parse_string_or_symbol(&ruby, value)?
+            .unwrap_or_default()
+            .parse()
+            .map_err(|e: String| MagnusError::new(magnus::exception::arg_error(), e))?,
+        None => GeometryFormat::default(),
+    };
+    let keys = match kwargs.optional.7.flatten() {
+        Some(value) if value.respond_to("call", false)? => KeyFormat::Proc(value),
+        Some(value) => match parse_string_or_symbol(&ruby, value)?.as_deref() {
+            Some("string") | None => KeyFormat::String,
+            Some("symbol") => KeyFormat::Symbol,
+            Some(other) => {
+                return Err(MagnusError::new(
+                    magnus::exception::arg_error(),
+                    format!(
+                        "Invalid keys value {:?}, expected :string, :symbol or a Proc",
+                        other
+                    ),
+                ))
+            }
+        },
+        None => KeyFormat::String,
+    };
+    let skip_corrupt_row_groups = kwargs.optional.8.flatten().unwrap_or(false);
+    let filter = kwargs.optional.9.flatten();
+    let intern_strings = match kwargs.optional.10.flatten() {
+        None => InternStrings::None,
+        Some(value) if value.is_kind_of(ruby.class_array()) => {
+            let columns: RArray = TryConvert::try_convert(value)?;
+            InternStrings::Columns(
+                columns
+                    .into_iter()
+                    .map(|column| {
+                        parse_string_or_symbol(&ruby, column)?.ok_or_else(|| {
+                            MagnusError::new(
+                                magnus::exception::arg_error(),
+                                "intern_strings columns cannot be nil",
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
+        }
+        Some(value) if value.is_kind_of(ruby.class_false_class()) => InternStrings::None,
+        Some(value) if value.is_kind_of(ruby.class_true_class()) => {
+            InternStrings::DictionaryEncoded
+        }
+        Some(value) => {
+            return Err(MagnusError::new(
+                magnus::exception::arg_error(),
+                format!(
+                "Invalid intern_strings value {}, expected true, false or an Array of column names",
+                value.inspect()
+            ),
+            ))
+        }
+    };
+    let convert = kwargs.optional.11.flatten();
 
     // Delegate to parquet_ruby_adapter
     parquet_ruby_adapter::reader::each_row(
         &ruby,
-        rb_self,
-        to_read,
-        result_type,
-        columns,
-        strict,
-        logger,
+        RowEnumeratorArgs {
+            rb_self,
+            to_read,
+            result_type,
+            row_class,
+            columns,
+            strict,
+            logger,
+            parse_json,
+            symbolize_enums,
+            geometry_format,
+            keys,
+            skip_corrupt_row_groups,
+            filter,
+            intern_strings,
+            convert,
+        },
     )
 }
 
@@ -83,12 +188,24 @@ pub fn each_column(rb_self: Value, args: &[Value]) -> Result<Value, MagnusError>
             Option<Option<usize>>,       // batch_size
             Option<Option<bool>>,        // strict
             Option<Option<Value>>,       // logger
+            Option<Option<bool>>,        // skip_corrupt_row_groups
+            Option<Option<Value>>,       // filter
+            Option<Option<Value>>,       // convert
         ),
         (),
     >(
         parsed_args.keywords,
         &[],
-        &["result_type", "columns", "batch_size", "strict", "logger"],
+        &[
+            "result_type",
+            "columns",
+            "batch_size",
+            "strict",
+            "logger",
+            "skip_corrupt_row_groups",
+            "filter",
+            "convert",
+        ],
     )?;
 
     let result_type: ParserResultType = if let Some(rt_value) = kwargs.optional.0.flatten() {
@@ -116,21 +233,263 @@ pub fn each_column(rb_self: Value, args: &[Value]) -> Result<Value, MagnusError>
         None
     };
     let strict = kwargs.optional.3.flatten().unwrap_or(true);
-    let logger = RubyLogger::new(kwargs.optional.4.flatten())?;
+    let logger = kwargs.optional.4.flatten();
+    let skip_corrupt_row_groups = kwargs.optional.5.flatten().unwrap_or(false);
+    let filter = kwargs.optional.6.flatten();
+    let convert = kwargs.optional.7.flatten();
 
     // Delegate to parquet_ruby_adapter
     parquet_ruby_adapter::reader::each_column(
         &ruby,
-        rb_self,
+        ColumnEnumeratorArgs {
+            rb_self,
+            to_read,
+            result_type,
+            columns,
+            batch_size,
+            strict,
+            logger,
+            skip_corrupt_row_groups,
+            filter,
+            convert,
+        },
+    )
+}
+
+pub fn metadata(args: &[Value]) -> Result<Value, MagnusError> {
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (to_read,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<_, (), (Option<Option<bool>>,), ()>(
+        parsed_args.keywords,
+        &[],
+        &["page_index"],
+    )?;
+    let page_index = kwargs.optional.0.flatten().unwrap_or(false);
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::metadata::parse_metadata(to_read, page_index)
+}
+
+pub fn statistics(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (to_read,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (),
+        (
+            Option<Option<Vec<String>>>, // columns
+            Option<Option<bool>>,        // row_groups
+        ),
+        (),
+    >(parsed_args.keywords, &[], &["columns", "row_groups"])?;
+
+    let columns = kwargs.optional.0.flatten();
+    let row_groups = kwargs.optional.1.flatten().unwrap_or(false);
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::statistics::statistics(&ruby, to_read, columns, row_groups)
+}
+
+pub fn verify(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (to_read,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<_, (), (Option<Option<bool>>,), ()>(
+        parsed_args.keywords,
+        &[],
+        &["checksums"],
+    )?;
+    let checksums = kwargs.optional.0.flatten().unwrap_or(true);
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::verify::verify(&ruby, to_read, checksums)
+}
+
+pub fn salvage(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (to_read,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (),
+        (
+            Option<Option<Value>>,  // schema
+            Option<Option<Value>>,  // schema_from
+            Option<Option<Value>>,  // write_to
+            Option<Option<String>>, // compression
+            Option<Option<Value>>,  // logger
+        ),
+        (),
+    >(
+        parsed_args.keywords,
+        &[],
+        &["schema", "schema_from", "write_to", "compression", "logger"],
+    )?;
+    let logger = kwargs.optional.4.flatten();
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::salvage::salvage(
+        &ruby,
         to_read,
-        result_type,
-        columns,
-        batch_size,
-        strict,
+        kwargs.optional.0.flatten(),
+        kwargs.optional.1.flatten(),
+        kwargs.optional.2.flatten(),
+        kwargs.optional.3.flatten(),
         logger,
     )
 }
 
+pub fn sample(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (to_read,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (),
+        (
+            Option<Option<usize>>,       // n
+            Option<Option<f64>>,         // sample_rate
+            Option<Option<u64>>,         // seed
+            Option<Option<Vec<String>>>, // columns
+        ),
+        (),
+    >(
+        parsed_args.keywords,
+        &[],
+        &["n", "sample_rate", "seed", "columns"],
+    )?;
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::sample::sample(
+        &ruby,
+        to_read,
+        kwargs.optional.0.flatten(),
+        kwargs.optional.1.flatten(),
+        kwargs.optional.2.flatten(),
+        kwargs.optional.3.flatten(),
+    )
+}
+
+pub fn count(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (to_read,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs =
+        get_kwargs::<_, (), (Option<Option<Value>>,), ()>(parsed_args.keywords, &[], &["filter"])?;
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::aggregate::count(&ruby, to_read, kwargs.optional.0.flatten())
+}
+
+pub fn min(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value, String), (), (), (), _, ()>(args)?;
+    let (to_read, column) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs =
+        get_kwargs::<_, (), (Option<Option<Value>>,), ()>(parsed_args.keywords, &[], &["filter"])?;
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::aggregate::min(&ruby, to_read, column, kwargs.optional.0.flatten())
+}
+
+pub fn max(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value, String), (), (), (), _, ()>(args)?;
+    let (to_read, column) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs =
+        get_kwargs::<_, (), (Option<Option<Value>>,), ()>(parsed_args.keywords, &[], &["filter"])?;
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::aggregate::max(&ruby, to_read, column, kwargs.optional.0.flatten())
+}
+
+pub fn null_count(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value, String), (), (), (), _, ()>(args)?;
+    let (to_read, column) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs =
+        get_kwargs::<_, (), (Option<Option<Value>>,), ()>(parsed_args.keywords, &[], &["filter"])?;
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::aggregate::null_count(&ruby, to_read, column, kwargs.optional.0.flatten())
+}
+
 pub fn write_rows(args: &[Value]) -> Result<Value, MagnusError> {
     let ruby = Ruby::get().map_err(|_| {
         MagnusError::new(
@@ -160,3 +519,283 @@ pub fn write_columns(args: &[Value]) -> Result<Value, MagnusError> {
     // Delegate to parquet_ruby_adapter
     parquet_ruby_adapter::writer::write_columns(&ruby, write_args)
 }
+
+pub fn to_ndjson(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value, Value), (), (), (), _, ()>(args)?;
+    let (to_read, write_to) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<_, (), (Option<Option<Vec<String>>>,), ()>(
+        parsed_args.keywords,
+        &[],
+        &["columns"],
+    )?;
+    let columns = kwargs.optional.0.flatten();
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::convert::to_ndjson(&ruby, to_read, write_to, columns)
+}
+
+pub fn from_ndjson(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value, Value), (), (), (), _, ()>(args)?;
+    let (read_from, write_to) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (),
+        (
+            Option<Option<Value>>,  // schema
+            Option<Option<String>>, // compression
+        ),
+        (),
+    >(parsed_args.keywords, &[], &["schema", "compression"])?;
+    let schema = kwargs.optional.0.flatten();
+    let compression = kwargs.optional.1.flatten();
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::convert::from_ndjson(&ruby, read_from, write_to, schema, compression)
+}
+
+pub fn to_csv(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (to_read,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (Value,),
+        (
+            Option<Option<Vec<String>>>, // columns
+            Option<Option<bool>>,        // header
+            Option<Option<String>>,      // delimiter
+            Option<Option<Vec<String>>>, // null_values
+            Option<Option<String>>,      // date_format
+            Option<Option<String>>,      // timestamp_format
+        ),
+        (),
+    >(
+        parsed_args.keywords,
+        &["write_to"],
+        &[
+            "columns",
+            "header",
+            "delimiter",
+            "null_values",
+            "date_format",
+            "timestamp_format",
+        ],
+    )?;
+    let (write_to,) = kwargs.required;
+    let csv_args = CsvArgs {
+        header: kwargs.optional.1.flatten(),
+        delimiter: kwargs.optional.2.flatten(),
+        null_values: kwargs.optional.3.flatten(),
+        date_format: kwargs.optional.4.flatten(),
+        timestamp_format: kwargs.optional.5.flatten(),
+    };
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::convert::to_csv(
+        &ruby,
+        to_read,
+        write_to,
+        kwargs.optional.0.flatten(),
+        csv_args,
+    )
+}
+
+pub fn from_csv(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (read_from,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (Value,),
+        (
+            Option<Option<Value>>,       // schema
+            Option<Option<bool>>,        // header
+            Option<Option<String>>,      // delimiter
+            Option<Option<Vec<String>>>, // null_values
+            Option<Option<String>>,      // date_format
+            Option<Option<String>>,      // timestamp_format
+            Option<Option<String>>,      // compression
+        ),
+        (),
+    >(
+        parsed_args.keywords,
+        &["write_to"],
+        &[
+            "schema",
+            "header",
+            "delimiter",
+            "null_values",
+            "date_format",
+            "timestamp_format",
+            "compression",
+        ],
+    )?;
+    let (write_to,) = kwargs.required;
+    let csv_args = CsvArgs {
+        header: kwargs.optional.1.flatten(),
+        delimiter: kwargs.optional.2.flatten(),
+        null_values: kwargs.optional.3.flatten(),
+        date_format: kwargs.optional.4.flatten(),
+        timestamp_format: kwargs.optional.5.flatten(),
+    };
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::convert::from_csv(
+        &ruby,
+        read_from,
+        write_to,
+        kwargs.optional.0.flatten(),
+        csv_args,
+        kwargs.optional.6.flatten(),
+    )
+}
+
+pub fn to_arrow_ipc(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value, Value), (), (), (), _, ()>(args)?;
+    let (to_read, write_to) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (),
+        (
+            Option<Option<Value>>,       // format
+            Option<Option<Vec<String>>>, // columns
+        ),
+        (),
+    >(parsed_args.keywords, &[], &["format", "columns"])?;
+    let format = match kwargs.optional.0.flatten() {
+        Some(value) => parse_string_or_symbol(&ruby, value)?,
+        None => None,
+    };
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::convert::to_arrow_ipc(
+        &ruby,
+        to_read,
+        write_to,
+        format,
+        kwargs.optional.1.flatten(),
+    )
+}
+
+pub fn from_arrow_ipc(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value, Value), (), (), (), _, ()>(args)?;
+    let (read_from, write_to) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (),
+        (
+            Option<Option<String>>, // compression
+            Option<Option<usize>>,  // flush_threshold
+        ),
+        (),
+    >(
+        parsed_args.keywords,
+        &[],
+        &["compression", "flush_threshold"],
+    )?;
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::convert::from_arrow_ipc(
+        &ruby,
+        read_from,
+        write_to,
+        kwargs.optional.0.flatten(),
+        kwargs.optional.1.flatten(),
+    )
+}
+
+pub fn write_arrow(args: &[Value]) -> Result<Value, MagnusError> {
+    let ruby = Ruby::get().map_err(|_| {
+        MagnusError::new(
+            magnus::exception::runtime_error(),
+            "Failed to get Ruby runtime",
+        )
+    })?;
+
+    // Parse arguments
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (data,) = parsed_args.required;
+
+    // Parse keyword arguments
+    let kwargs = get_kwargs::<
+        _,
+        (Value,),
+        (
+            Option<Option<Value>>,  // schema
+            Option<Option<String>>, // compression
+        ),
+        (),
+    >(
+        parsed_args.keywords,
+        &["write_to"],
+        &["schema", "compression"],
+    )?;
+    let (write_to,) = kwargs.required;
+
+    // Delegate to parquet_ruby_adapter
+    parquet_ruby_adapter::convert::write_arrow(
+        &ruby,
+        data,
+        write_to,
+        kwargs.optional.0.flatten(),
+        kwargs.optional.1.flatten(),
+    )
+}
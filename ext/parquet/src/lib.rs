@@ -3,8 +3,11 @@ mod allocator;
 
 use magnus::{function, method, Error, Ruby};
 
-use crate::adapter_ffi::{each_column, each_row, write_columns, write_rows};
-use parquet_ruby_adapter::metadata::parse_metadata;
+use crate::adapter_ffi::{
+    count, each_column, each_row, from_arrow_ipc, from_csv, from_ndjson, max, metadata, min,
+    null_count, salvage, sample, statistics, to_arrow_ipc, to_csv, to_ndjson, verify, write_arrow,
+    write_columns, write_rows,
+};
 
 /// Initializes the Ruby extension and defines methods.
 #[magnus::init]
@@ -14,11 +17,26 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
 
     let module = ruby.define_module("Parquet")?;
 
-    module.define_module_function("metadata", function!(parse_metadata, 1))?;
+    module.define_module_function("metadata", function!(metadata, -1))?;
+    module.define_module_function("statistics", function!(statistics, -1))?;
+    module.define_module_function("verify", function!(verify, -1))?;
+    module.define_module_function("salvage", function!(salvage, -1))?;
+    module.define_module_function("sample", function!(sample, -1))?;
+    module.define_module_function("count", function!(count, -1))?;
+    module.define_module_function("min", function!(min, -1))?;
+    module.define_module_function("max", function!(max, -1))?;
+    module.define_module_function("null_count", function!(null_count, -1))?;
     module.define_module_function("each_row", method!(each_row, -1))?;
     module.define_module_function("each_column", method!(each_column, -1))?;
     module.define_module_function("write_rows", function!(write_rows, -1))?;
     module.define_module_function("write_columns", function!(write_columns, -1))?;
+    module.define_module_function("to_ndjson", function!(to_ndjson, -1))?;
+    module.define_module_function("from_ndjson", function!(from_ndjson, -1))?;
+    module.define_module_function("to_csv", function!(to_csv, -1))?;
+    module.define_module_function("from_csv", function!(from_csv, -1))?;
+    module.define_module_function("to_arrow_ipc", function!(to_arrow_ipc, -1))?;
+    module.define_module_function("from_arrow_ipc", function!(from_arrow_ipc, -1))?;
+    module.define_module_function("write_arrow", function!(write_arrow, -1))?;
 
     Ok(())
 }
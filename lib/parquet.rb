@@ -1,4 +1,5 @@
 require_relative "parquet/version"
+require_relative "parquet/errors"
 require_relative "parquet/schema"
 
 begin
@@ -8,34 +8,227 @@ module Parquet
   # - Schema information (fields, types, etc.)
   # - Row group details
   # - Column chunk information (compression, encodings, statistics)
-  sig { params(path: String).returns(T::Hash[String, T.untyped]) }
-  def self.metadata(path)
+  #
+  # With `page_index: true` the column and offset indexes are loaded, and each column
+  # chunk gets a "pages" array with every page's first_row_index, offset, compressed_size,
+  # min, max and null_count. min and max are decoded like row values.
+  sig do
+    params(
+      path: T.any(String, File, StringIO, IO),
+      page_index: T.nilable(T::Boolean)
+    ).returns(T::Hash[String, T.untyped])
+  end
+  def self.metadata(path, page_index: nil)
+  end
+
+  # Returns column statistics from the file footer, with min and max decoded to the
+  # same Ruby values each_row would return (BigDecimal, Time, Date, ...)
+  #
+  # Options:
+  #   - `columns`: When present, only statistics for these top level columns are returned.
+  #   - `row_groups`: When true, also returns the statistics of each row group.
+  #
+  # Columns are keyed by leaf path ("address.city"). min and max are only decoded for top
+  # level columns; distinct_count is only aggregated for files with a single row group.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      columns: T.nilable(T::Array[String]),
+      row_groups: T.nilable(T::Boolean)
+    ).returns(T::Hash[String, T.untyped])
+  end
+  def self.statistics(input, columns: nil, row_groups: nil)
+  end
+
+  # Decompresses and decodes every page of every column chunk, checking page CRCs and that
+  # row and value counts match the metadata.
+  #
+  # Options:
+  #   - `checksums`: Whether to compare page CRCs when present (defaults to true)
+  #
+  # Returns {"ok", "num_rows", "row_groups", "pages", "checksums_verified", "problems"}, where
+  # each problem is {"row_group", "column", "page_offset", "error"} and the location keys are
+  # nil when a problem is not specific to one.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      checksums: T.nilable(T::Boolean)
+    ).returns(T::Hash[String, T.untyped])
+  end
+  def self.verify(input, checksums: nil)
+  end
+
+  # Recovers the row groups of a file whose footer is missing, e.g. because the writer
+  # crashed before closing it, by scanning its page headers.
+  #
+  # Options:
+  #   - `schema`: The schema the file was written with, in the DSL or hash form.
+  #   - `schema_from`: A complete file written with the same schema, in place of `schema`.
+  #   - `write_to`: Writes the recovered rows to this path or IO as a new file.
+  #   - `compression`: Compression of the repaired file (defaults to snappy).
+  #
+  # With a block, each recovered row is yielded as a Hash instead. Returns {"num_rows",
+  # "row_groups", "pages", "file_size", "scanned_bytes", "problems"}, with problems shaped
+  # as in `verify`.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      schema: T.untyped,
+      schema_from: T.nilable(T.any(String, File, StringIO, IO)),
+      write_to: T.nilable(T.any(String, IO)),
+      compression: T.nilable(String),
+      logger: T.untyped,
+      blk: T.nilable(T.proc.params(row: T::Hash[String, T.untyped]).void)
+    ).returns(T::Hash[String, T.untyped])
+  end
+  def self.salvage(input, schema: nil, schema_from: nil, write_to: nil, compression: nil, logger: nil, &blk)
+  end
+
+  # Reads a uniformly random sample of rows as Hashes, picking row indices across row groups
+  # from the footer's row counts so rows that are not picked are never converted.
+  #
+  # Options:
+  #   - `n`: Number of rows to sample; every row when the file has fewer.
+  #   - `sample_rate`: Fraction of rows to sample instead, each row picked independently.
+  #   - `seed`: Makes the sample reproducible.
+  #   - `columns`: When present, only the specified columns will be included in the output.
+  #
+  # Exactly one of `n` and `sample_rate` is given. Rows are returned in file order, or yielded
+  # as they are read with a block.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      n: T.nilable(Integer),
+      sample_rate: T.nilable(Float),
+      seed: T.nilable(Integer),
+      columns: T.nilable(T::Array[String])
+    ).returns(T::Array[T::Hash[String, T.untyped]])
+  end
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      n: T.nilable(Integer),
+      sample_rate: T.nilable(Float),
+      seed: T.nilable(Integer),
+      columns: T.nilable(T::Array[String]),
+      blk: T.nilable(T.proc.params(row: T::Hash[String, T.untyped]).void)
+    ).returns(NilClass)
+  end
+  def self.sample(input, n: nil, sample_rate: nil, seed: nil, columns: nil, &blk)
+  end
+
+  # Returns the number of rows, answered from the footer's row counts.
+  #
+  # With `filter:` (as for each_row) row groups whose statistics show every row matches are
+  # counted without being read; only row groups straddling a range bound are scanned.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped])
+    ).returns(Integer)
+  end
+  def self.count(input, filter: nil)
+  end
+
+  # Returns the smallest non null value of a top level column, decoded like row values, or
+  # nil when there is none. Exact footer statistics are used where they cover the filter,
+  # and only the remaining row groups are scanned.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      column: String,
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped])
+    ).returns(T.untyped)
+  end
+  def self.min(input, column, filter: nil)
+  end
+
+  # Returns the largest non null value of a top level column, like `min`.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      column: String,
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped])
+    ).returns(T.untyped)
+  end
+  def self.max(input, column, filter: nil)
+  end
+
+  # Returns the number of nulls in a top level column, from footer null counts where they
+  # cover the filter.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      column: String,
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped])
+    ).returns(Integer)
+  end
+  def self.null_count(input, column, filter: nil)
   end
 
   # Options:
   #   - `input`: String, File, or IO object containing parquet data
   #   - `result_type`: String specifying the output format
-  #                    ("hash" or "array" or :hash or :array)
+  #                    ("hash" or "array" or "struct" or :hash or :array or :struct),
+  #                    or a Data/Struct class whose members name the columns to read into
   #   - `columns`: When present, only the specified columns will be included in the output.
   #                This is useful for reducing how much data is read and improving performance.
+  #   - `parse_json`: When true, columns with the JSON logical type are returned as parsed
+  #                   Hashes/Arrays instead of raw JSON strings.
+  #   - `symbolize_enums`: When true, columns with the ENUM logical type are returned as
+  #                        Symbols instead of Strings.
+  #   - `geometry_format`: How geometry columns are returned: :wkt (default), :coordinates
+  #                        for GeoJSON style Hashes, or :wkb for binary Strings.
+  #   - `keys`: How Hash keys are built for columns, nested struct fields and String map keys:
+  #             :string (default), :symbol, or a Proc called with each name.
+  #   - `skip_corrupt_row_groups`: When true, row groups that fail to decode are left out and
+  #                                reported as warnings to the `logger:` instead of raising.
+  #   - `filter`: Hash of top level column names to Ranges (open ended or excluding their end)
+  #               or single values. Only matching rows are returned, and row groups whose
+  #               statistics lie outside every range are never read. Files written with
+  #               `sorted_by:` find the row groups for their leading sort column by binary search.
+  #   - `intern_strings`: true to return the values of every dictionary encoded string column,
+  #                       or of the named columns, as frozen Strings shared by every row
+  #                       with the same value
+  #   - `convert`: Ruby types to return values as, by type: `decimal:` (:big_decimal, :float or
+  #                :string), `timestamp:` (:time, :integer in the column's unit, or :iso8601),
+  #                `date:` (:date, :integer days since the epoch, or :string) and `binary:`
+  #                (:bytes or :string for UTF-8). A `columns:` Hash of column names to the same
+  #                options overrides them for top level columns.
   sig do
     params(
       input: T.any(String, File, StringIO, IO),
-      result_type: T.nilable(T.any(String, Symbol)),
+      result_type: T.nilable(T.any(String, Symbol, T::Class[T.anything])),
       columns: T.nilable(T::Array[String]),
-      strict: T.nilable(T::Boolean)
-    ).returns(T::Enumerator[T.any(T::Hash[String, T.untyped], T::Array[T.untyped])])
+      strict: T.nilable(T::Boolean),
+      parse_json: T.nilable(T::Boolean),
+      symbolize_enums: T.nilable(T::Boolean),
+      geometry_format: T.nilable(T.any(String, Symbol)),
+      keys: T.nilable(T.any(String, Symbol, T.proc.params(name: String).returns(T.untyped))),
+      skip_corrupt_row_groups: T.nilable(T::Boolean),
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped]),
+      intern_strings: T.nilable(T.any(T::Boolean, T::Array[T.any(String, Symbol)])),
+      convert: T.nilable(T::Hash[Symbol, T.untyped])
+    ).returns(T::Enumerator[T.untyped])
   end
   sig do
     params(
       input: T.any(String, File, StringIO, IO),
-      result_type: T.nilable(T.any(String, Symbol)),
+      result_type: T.nilable(T.any(String, Symbol, T::Class[T.anything])),
       columns: T.nilable(T::Array[String]),
       strict: T.nilable(T::Boolean),
-      blk: T.nilable(T.proc.params(row: T.any(T::Hash[String, T.untyped], T::Array[T.untyped])).void)
+      parse_json: T.nilable(T::Boolean),
+      symbolize_enums: T.nilable(T::Boolean),
+      geometry_format: T.nilable(T.any(String, Symbol)),
+      keys: T.nilable(T.any(String, Symbol, T.proc.params(name: String).returns(T.untyped))),
+      skip_corrupt_row_groups: T.nilable(T::Boolean),
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped]),
+      intern_strings: T.nilable(T.any(T::Boolean, T::Array[T.any(String, Symbol)])),
+      convert: T.nilable(T::Hash[Symbol, T.untyped]),
+      blk: T.nilable(T.proc.params(row: T.untyped).void)
     ).returns(NilClass)
   end
-  def self.each_row(input, result_type: nil, columns: nil, strict: nil, &blk)
+  def self.each_row(input, result_type: nil, columns: nil, strict: nil, parse_json: nil, symbolize_enums: nil, geometry_format: nil, keys: nil, skip_corrupt_row_groups: nil, filter: nil, intern_strings: nil, convert: nil, &blk)
   end
 
   # Options:
@@ -44,13 +237,27 @@ module Parquet
   #                    ("hash" or "array" or :hash or :array)
   #   - `columns`: When present, only the specified columns will be included in the output.
   #   - `batch_size`: When present, specifies the number of rows per batch
+  #   - `skip_corrupt_row_groups`: When true, row groups that fail to decode are left out and
+  #                                reported as warnings to the `logger:` instead of raising.
+  #   - `filter`: Hash of top level column names to Ranges (open ended or excluding their end)
+  #               or single values. Only matching rows are returned, and row groups whose
+  #               statistics lie outside every range are never read. Files written with
+  #               `sorted_by:` find the row groups for their leading sort column by binary search.
+  #   - `convert`: Ruby types to return values as, by type: `decimal:` (:big_decimal, :float or
+  #                :string), `timestamp:` (:time, :integer in the column's unit, or :iso8601),
+  #                `date:` (:date, :integer days since the epoch, or :string) and `binary:`
+  #                (:bytes or :string for UTF-8). A `columns:` Hash of column names to the same
+  #                options overrides them for top level columns.
   sig do
     params(
       input: T.any(String, File, StringIO, IO),
       result_type: T.nilable(T.any(String, Symbol)),
       columns: T.nilable(T::Array[String]),
       batch_size: T.nilable(Integer),
-      strict: T.nilable(T::Boolean)
+      strict: T.nilable(T::Boolean),
+      skip_corrupt_row_groups: T.nilable(T::Boolean),
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped]),
+      convert: T.nilable(T::Hash[Symbol, T.untyped])
     ).returns(T::Enumerator[T.any(T::Hash[String, T.untyped], T::Array[T.untyped])])
   end
   sig do
@@ -60,11 +267,14 @@ module Parquet
       columns: T.nilable(T::Array[String]),
       batch_size: T.nilable(Integer),
       strict: T.nilable(T::Boolean),
+      skip_corrupt_row_groups: T.nilable(T::Boolean),
+      filter: T.nilable(T::Hash[T.any(String, Symbol), T.untyped]),
+      convert: T.nilable(T::Hash[Symbol, T.untyped]),
       blk:
         T.nilable(T.proc.params(batch: T.any(T::Hash[String, T::Array[T.untyped]], T::Array[T::Array[T.untyped]])).void)
     ).returns(NilClass)
   end
-  def self.each_column(input, result_type: nil, columns: nil, batch_size: nil, strict: nil, &blk)
+  def self.each_column(input, result_type: nil, columns: nil, batch_size: nil, strict: nil, skip_corrupt_row_groups: nil, filter: nil, convert: nil, &blk)
   end
 
   # Options:
@@ -74,6 +284,12 @@ module Parquet
   #     - `uint8`, `uint16`, `uint32`, `uint64`
   #     - `float`, `double`
   #     - `string`
+  #     - `json` (Hash/Array values are serialized, Strings must already be valid JSON)
+  #     - `enum` (Strings or Symbols, optionally restricted with `values:`)
+  #     - `duration`, `duration_second`, `duration_millis`, `duration_micros`, `duration_nanos` (numeric seconds)
+  #     - `interval` (Hash with `:months`, `:days` and `:millis`)
+  #     - `variant` (any nesting of Hash, Array and scalar values)
+  #     - `geometry`, `geography` (WKT, WKB, coordinate Arrays or GeoJSON style Hashes)
   #     - `binary`
   #     - `boolean`
   #     - `date32`
@@ -84,6 +300,13 @@ module Parquet
   #   - `compression`: Optional compression type to use (defaults to "zstd")
   #                   Supported values: "none", "uncompressed", "snappy", "gzip", "lz4", "zstd"
   #   - `sample_size`: Optional number of rows to sample for size estimation (defaults to 100)
+  #   - `sorted_by`: Order the rows are written in, declared as the `sorting_columns` of every row
+  #                  group: column names or arrays like `[[:tenant_id, :asc], [:ts, :desc, nulls_first: true]]`
+  #   - `verify_sorted`: When true, raise at the first row out of the `sorted_by` order
+  #   - `on_error`: What to do with rows that can't be converted or don't fit the schema:
+  #                 `:raise` (default), `:skip` to leave them out, or `:collect` to leave them
+  #                 out and return an array of `{"row_index", "column", "message"}` hashes
+  #   - `dead_letter`: IO the left out rows are written to as NDJSON, with their error
   sig do
     params(
       read_from: T::Enumerator[T::Array[T.untyped]],
@@ -92,8 +315,12 @@ module Parquet
       batch_size: T.nilable(Integer),
       flush_threshold: T.nilable(Integer),
       compression: T.nilable(String),
-      sample_size: T.nilable(Integer)
-    ).void
+      sample_size: T.nilable(Integer),
+      sorted_by: T.nilable(T::Array[T.untyped]),
+      verify_sorted: T.nilable(T::Boolean),
+      on_error: T.nilable(Symbol),
+      dead_letter: T.nilable(IO)
+    ).returns(T.nilable(T::Array[T::Hash[String, T.untyped]]))
   end
   def self.write_rows(
     read_from,
@@ -102,7 +329,11 @@ module Parquet
     batch_size: nil,
     flush_threshold: nil,
     compression: nil,
-    sample_size: nil
+    sample_size: nil,
+    sorted_by: nil,
+    verify_sorted: nil,
+    on_error: nil,
+    dead_letter: nil
   )
   end
 
@@ -113,6 +344,12 @@ module Parquet
   #     - `uint8`, `uint16`, `uint32`, `uint64`
   #     - `float`, `double`
   #     - `string`
+  #     - `json` (Hash/Array values are serialized, Strings must already be valid JSON)
+  #     - `enum` (Strings or Symbols, optionally restricted with `values:`)
+  #     - `duration`, `duration_second`, `duration_millis`, `duration_micros`, `duration_nanos` (numeric seconds)
+  #     - `interval` (Hash with `:months`, `:days` and `:millis`)
+  #     - `variant` (any nesting of Hash, Array and scalar values)
+  #     - `geometry`, `geography` (WKT, WKB, coordinate Arrays or GeoJSON style Hashes)
   #     - `binary`
   #     - `boolean`
   #     - `date32`
@@ -122,15 +359,230 @@ module Parquet
   #   - `flush_threshold`: Optional memory threshold in bytes before flushing (defaults to 64MB)
   #   - `compression`: Optional compression type to use (defaults to "zstd")
   #                   Supported values: "none", "uncompressed", "snappy", "gzip", "lz4", "zstd"
+  #   - `sorted_by`: Order the rows are written in, declared as the `sorting_columns` of every row
+  #                  group: column names or arrays like `[[:tenant_id, :asc], [:ts, :desc, nulls_first: true]]`
+  #   - `verify_sorted`: When true, raise at the first row out of the `sorted_by` order
   sig do
     params(
       read_from: T::Enumerator[T::Array[T::Array[T.untyped]]],
       schema: T::Array[T::Hash[String, String]],
       write_to: T.any(String, IO),
       flush_threshold: T.nilable(Integer),
-      compression: T.nilable(String)
+      compression: T.nilable(String),
+      sorted_by: T.nilable(T::Array[T.untyped]),
+      verify_sorted: T.nilable(T::Boolean)
     ).void
   end
-  def self.write_columns(read_from, schema:, write_to:, flush_threshold: nil, compression: nil)
+  def self.write_columns(read_from, schema:, write_to:, flush_threshold: nil, compression: nil, sorted_by: nil, verify_sorted: nil)
+  end
+
+  # Writes the rows of a Parquet file as newline delimited JSON, one object per row.
+  # Record batches are converted in Rust without creating Ruby objects.
+  #
+  # Options:
+  #   - `columns`: When present, only these columns are written.
+  #
+  # Returns the number of rows written.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      write_to: T.any(String, IO),
+      columns: T.nilable(T::Array[String])
+    ).returns(Integer)
+  end
+  def self.to_ndjson(input, write_to, columns: nil)
+  end
+
+  # Writes newline delimited JSON as a Parquet file.
+  #
+  # Options:
+  #   - `schema`: A schema as accepted by write_rows. When omitted, the schema is inferred
+  #               from all records before writing.
+  #   - `compression`: Compression type, as for write_rows (defaults to "snappy")
+  #
+  # Returns the number of rows written.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      write_to: T.any(String, IO),
+      schema: T.untyped,
+      compression: T.nilable(String)
+    ).returns(Integer)
+  end
+  def self.from_ndjson(input, write_to, schema: nil, compression: nil)
+  end
+
+  # Writes CSV as a Parquet file.
+  #
+  # Options:
+  #   - `write_to`: Path or IO object to write the Parquet file to
+  #   - `schema`: A schema as accepted by write_rows. When omitted, the schema is inferred
+  #               from the first 1000 rows, recognising only ISO 8601 dates and timestamps.
+  #   - `header`: Whether the first row holds column names (defaults to true)
+  #   - `delimiter`: Single byte field separator (defaults to ",")
+  #   - `null_values`: Strings read as null (defaults to [""])
+  #   - `date_format`, `timestamp_format`: strftime formats for date and timestamp columns
+  #                                         without a `format` of their own in the schema
+  #   - `compression`: Compression type, as for write_rows (defaults to "snappy")
+  #
+  # Returns the number of rows written.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      write_to: T.any(String, IO),
+      schema: T.untyped,
+      header: T.nilable(T::Boolean),
+      delimiter: T.nilable(String),
+      null_values: T.nilable(T::Array[String]),
+      date_format: T.nilable(String),
+      timestamp_format: T.nilable(String),
+      compression: T.nilable(String)
+    ).returns(Integer)
+  end
+  def self.from_csv(
+    input,
+    write_to:,
+    schema: nil,
+    header: nil,
+    delimiter: nil,
+    null_values: nil,
+    date_format: nil,
+    timestamp_format: nil,
+    compression: nil
+  )
+  end
+
+  # Writes the rows of a Parquet file as CSV. Nested columns are not supported.
+  #
+  # Options:
+  #   - `write_to`: Path or IO object to write the CSV to
+  #   - `columns`: When present, only these columns are written.
+  #   - `header`: Whether to write a row of column names (defaults to true)
+  #   - `delimiter`: Single byte field separator (defaults to ",")
+  #   - `null_values`: Nulls are written as the first string (defaults to "")
+  #   - `date_format`, `timestamp_format`: strftime formats for dates and timestamps
+  #
+  # Returns the number of rows written.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      write_to: T.any(String, IO),
+      columns: T.nilable(T::Array[String]),
+      header: T.nilable(T::Boolean),
+      delimiter: T.nilable(String),
+      null_values: T.nilable(T::Array[String]),
+      date_format: T.nilable(String),
+      timestamp_format: T.nilable(String)
+    ).returns(Integer)
+  end
+  def self.to_csv(
+    input,
+    write_to:,
+    columns: nil,
+    header: nil,
+    delimiter: nil,
+    null_values: nil,
+    date_format: nil,
+    timestamp_format: nil
+  )
+  end
+
+  # Writes the rows of a Parquet file as Arrow IPC.
+  #
+  # Options:
+  #   - `format`: :file (the default) for the random access file format, also known as
+  #               Feather V2, or :stream for the streaming format
+  #   - `columns`: When present, only these columns are written.
+  #
+  # Returns the number of rows written.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      write_to: T.any(String, IO),
+      format: T.nilable(T.any(String, Symbol)),
+      columns: T.nilable(T::Array[String])
+    ).returns(Integer)
+  end
+  def self.to_arrow_ipc(input, write_to, format: nil, columns: nil)
+  end
+
+  # Writes an Arrow IPC file or stream as a Parquet file. The format is detected from the
+  # leading bytes and the Parquet schema follows the Arrow schema.
+  #
+  # Options:
+  #   - `compression`: Compression type, as for write_rows (defaults to "snappy")
+  #   - `flush_threshold`: Buffered bytes after which a row group is written
+  #
+  # Returns the number of rows written.
+  sig do
+    params(
+      input: T.any(String, File, StringIO, IO),
+      write_to: T.any(String, IO),
+      compression: T.nilable(String),
+      flush_threshold: T.nilable(Integer)
+    ).returns(Integer)
+  end
+  def self.from_arrow_ipc(input, write_to, compression: nil, flush_threshold: nil)
+  end
+
+  # Writes Arrow data as a Parquet file without converting values through Ruby.
+  #
+  # `data` is one of:
+  #   - the address of an Arrow C stream (`ArrowArrayStream`)
+  #   - an `[array, schema]` pair of Arrow C array addresses
+  #   - an object responding to `export`, such as a red-arrow RecordBatchReader or RecordBatch
+  #   - Arrow IPC bytes in a String, or an IO of IPC data
+  #
+  # Streams and arrays are released once read. Structs behind plain addresses stay with the caller.
+  #
+  # Options:
+  #   - `write_to`: Path or IO to write the Parquet file to
+  #   - `schema`: Schema to cast the columns to by name; defaults to the Arrow schema
+  #   - `compression`: Compression type, as for write_rows (defaults to "snappy")
+  #
+  # Returns the number of rows written.
+  sig do
+    params(
+      data: T.any(Integer, T::Array[Integer], String, IO, StringIO, T.untyped),
+      write_to: T.any(String, IO),
+      schema: T.untyped,
+      compression: T.nilable(String)
+    ).returns(Integer)
+  end
+  def self.write_arrow(data, write_to:, schema: nil, compression: nil)
+  end
+
+  # Base class of errors raised by Parquet; a RuntimeError
+  class Error < RuntimeError; end
+
+  # A schema that can't be built, or data that doesn't match it
+  class SchemaError < Error; end
+
+  # Reading from or writing to a file or IO object failed
+  class IOError < Error; end
+
+  # A type or feature this library doesn't support
+  class UnsupportedTypeError < Error; end
+
+  # A file that isn't valid Parquet, or whose data can't be decoded
+  class CorruptFileError < Error; end
+
+  # A value that couldn't be converted to or from its Parquet type
+  class ConversionError < Error
+    sig { returns(T.nilable(Integer)) }
+    def row_index
+    end
+
+    sig { returns(T.nilable(String)) }
+    def column_path
+    end
+
+    sig { returns(T.nilable(String)) }
+    def expected_type
+    end
+
+    sig { returns(T.nilable(String)) }
+    def value_class
+    end
   end
 end
@@ -0,0 +1,38 @@
+# frozen_string_literal: true
+
+module Parquet
+  # Base class of errors raised by Parquet, itself a RuntimeError. Failures
+  # that used to raise IOError, TypeError or EncodingError raise subclasses
+  # of this instead, so they're no longer caught by rescuing those classes.
+  class Error < RuntimeError; end
+
+  # A schema that can't be built, or data that doesn't match it
+  class SchemaError < Error; end
+
+  # Reading from or writing to a file or IO object failed
+  class IOError < Error; end
+
+  # A type or feature this library doesn't support
+  class UnsupportedTypeError < Error; end
+
+  # A file that isn't valid Parquet, or whose data can't be decoded
+  class CorruptFileError < Error; end
+
+  # A value that couldn't be converted to or from its Parquet type
+  #
+  # Attributes are nil when unknown; `expected_type` and `value_class` are
+  # only set when writing.
+  class ConversionError < Error
+    # @return [Integer, nil] index of the row being read or written
+    attr_reader :row_index
+
+    # @return [String, nil] name of the top level column
+    attr_reader :column_path
+
+    # @return [String, nil] Parquet type the value was converted to, e.g. "Int32"
+    attr_reader :expected_type
+
+    # @return [String, nil] class name of the Ruby value being written
+    attr_reader :value_class
+  end
+end
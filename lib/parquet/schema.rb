@@ -11,6 +11,22 @@ module Parquet
     #     field :id, :int64, nullable: false  # ID cannot be null
     #     field :name, :string  # Default nullable: true
     #
+    #     # JSON column: accepts Hash/Array values or pre-encoded JSON strings
+    #     field :payload, :json
+    #
+    #     # Enum column restricted to a fixed set of values
+    #     field :status, :enum, values: [:active, :inactive]
+    #
+    #     # Elapsed time in numeric seconds, and calendar intervals
+    #     field :elapsed, :duration, unit: :micros
+    #     field :sla_window, :interval
+    #
+    #     # Semi-structured data of any shape
+    #     field :attributes, :variant
+    #
+    #     # GeoParquet geometry stored as WKB
+    #     field :location, :geometry, crs: "EPSG:4326"
+    #
     #     # Decimal field with precision and scale
     #     field :price, :decimal, precision: 10, scale: 2
     #
@@ -48,7 +64,7 @@ module Parquet
 
       # Define a field in the schema
       # @param name [String, Symbol] field name
-      # @param type [Symbol] data type (:int32, :int64, :string, :list, :map, :struct, :decimal, etc)
+      # @param type [Symbol] data type (:int32, :int64, :string, :json, :enum, :variant, :geometry, :list, :map, :struct, :decimal, etc)
       # @param nullable [Boolean] whether the field can be null (default: true)
       # @param kwargs [Hash] additional options depending on type
       #
@@ -59,8 +75,13 @@ module Parquet
       #   - `key_nullable:, value_nullable:` controls nullability of map keys/values (default: true)
       #   - `format:` if you want to store some format string
       #   - `precision:, scale:` if type == :decimal (precision defaults to 38, scale to 0)
+      #   - `values:` if type == :enum, the allowed values (Strings or Symbols); unrestricted if omitted
+      #   - `unit:` if type == :duration, one of :second, :millis (default), :micros, :nanos
+      #     (plural and spelled out forms such as :seconds or :milliseconds also work)
+      #   - `crs:` if type is :geometry or :geography, PROJJSON (String or Hash) or an id like "EPSG:4326"
       #   - `has_timezone:` if type is timestamp - true means UTC storage (default), false means local/unzoned
-      #   - `timezone:` (DEPRECATED) if type is timestamp - any value means UTC storage
+      #   - `timezone:` if type is timestamp - an IANA zone like "America/New_York" or an offset like "+09:00";
+      #     values are stored as UTC and read back in this zone
       #   - `nullable:` default to true if not specified
       def field(name, type, nullable: true, **kwargs, &block)
         field_hash = { name: name.to_s, type: type, nullable: !!nullable }
@@ -76,9 +97,8 @@ module Parquet
             # If explicitly false, don't store timezone (indicates local/unzoned)
             field_hash[:timezone] = "UTC" if kwargs[:has_timezone]
           elsif kwargs.key?(:timezone)
-            # Legacy support: any timezone value means UTC storage
-            # Store "UTC" regardless of the actual value to make it clear
-            field_hash[:timezone] = "UTC"
+            # Any zone means UTC storage; the zone is kept in the Arrow schema
+            field_hash[:timezone] = kwargs[:timezone].nil? ? "UTC" : kwargs[:timezone].to_s
           else
             # Default behavior when neither parameter is specified: UTC storage
             field_hash[:timezone] = "UTC"
@@ -160,6 +180,12 @@ module Parquet
             field_hash[:precision] = kwargs[:precision]
             field_hash[:scale] = kwargs[:scale]
           end
+        when :enum
+          field_hash[:values] = kwargs[:values].map(&:to_s) if kwargs[:values]
+        when :duration
+          field_hash[:unit] = kwargs[:unit] if kwargs.key?(:unit)
+        when :geometry, :geography
+          field_hash[:crs] = kwargs[:crs] if kwargs.key?(:crs)
         else
           # primitive type: :int32, :int64, :string, etc.
           # do nothing else special
@@ -0,0 +1,49 @@
+require_relative 'test_helper'
+
+class AggregateTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_aggregate_#{Process.pid}.parquet")
+    schema = Parquet::Schema.define do
+      field :tenant_id, :int32, nullable: false
+      field :ts, :int64
+      field :name, :string
+    end
+    # Five row groups of ten rows, one tenant each
+    rows = (0...5).flat_map do |tenant_id|
+      (0...10).map do |i|
+        [tenant_id, i.zero? ? nil : tenant_id * 100 + i, i.even? ? nil : "name #{i}"]
+      end
+    end
+    Parquet.write_rows(rows.each, schema: schema, write_to: @test_file, batch_size: 10)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_aggregates
+    assert_equal 50, Parquet.count(@test_file)
+    assert_equal 1, Parquet.min(@test_file, "ts")
+    assert_equal 409, Parquet.max(@test_file, "ts")
+    assert_equal 5, Parquet.null_count(@test_file, "ts")
+    assert_equal 25, Parquet.null_count(@test_file, "name")
+    assert_equal "name 9", Parquet.max(@test_file, "name")
+  end
+
+  def test_aggregates_with_filter
+    assert_equal 20, Parquet.count(@test_file, filter: { tenant_id: 1..2 })
+    assert_equal 101, Parquet.min(@test_file, "ts", filter: { tenant_id: 1..2 })
+    assert_equal 10, Parquet.null_count(@test_file, "name", filter: { tenant_id: 1..2 })
+
+    # Straddles row groups 1 and 2, which are scanned
+    assert_equal 9, Parquet.count(@test_file, filter: { ts: 105...205 })
+    assert_equal 204, Parquet.max(@test_file, "ts", filter: { ts: 105...205 })
+
+    assert_equal 0, Parquet.count(@test_file, filter: { tenant_id: 9 })
+    assert_nil Parquet.max(@test_file, "ts", filter: { tenant_id: 9 })
+  end
+
+  def test_unknown_column
+    assert_raises(RuntimeError) { Parquet.min(@test_file, "missing") }
+  end
+end
@@ -0,0 +1,89 @@
+require_relative 'test_helper'
+require 'stringio'
+
+class ArrowIpcTest < Minitest::Test
+  def setup
+    @parquet_file = File.join(Dir.tmpdir, "test_arrow_ipc_#{Process.pid}.parquet")
+    @ipc_file = File.join(Dir.tmpdir, "test_arrow_ipc_#{Process.pid}.arrow")
+    @copy_file = File.join(Dir.tmpdir, "test_arrow_ipc_copy_#{Process.pid}.parquet")
+
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :name, :string
+      field :tags, :list, item: :string
+    end
+    data = [[1, "Alice", ["a", "b"]], [2, nil, []]]
+    Parquet.write_rows(data.each, schema: schema, write_to: @parquet_file)
+  end
+
+  def teardown
+    [@parquet_file, @ipc_file, @copy_file].each { |f| File.delete(f) if File.exist?(f) }
+  end
+
+  def test_file_roundtrip
+    assert_equal 2, Parquet.to_arrow_ipc(@parquet_file, @ipc_file)
+    assert_equal "ARROW1", File.binread(@ipc_file, 6)
+
+    assert_equal 2, Parquet.from_arrow_ipc(@ipc_file, @copy_file, compression: "zstd")
+    assert_equal Parquet.each_row(@parquet_file).to_a, Parquet.each_row(@copy_file).to_a
+  end
+
+  def test_stream_roundtrip_through_io
+    io = StringIO.new
+    io.binmode
+    Parquet.to_arrow_ipc(@parquet_file, io, format: :stream, columns: ["id", "name"])
+    refute_equal "ARROW1", io.string[0, 6]
+
+    output = StringIO.new
+    output.binmode
+    Parquet.from_arrow_ipc(StringIO.new(io.string), output)
+    rows = Parquet.each_row(StringIO.new(output.string)).to_a
+    assert_equal [{ "id" => 1, "name" => "Alice" }, { "id" => 2, "name" => nil }], rows
+  end
+
+  def test_write_arrow_from_ipc_bytes
+    io = StringIO.new
+    io.binmode
+    Parquet.to_arrow_ipc(@parquet_file, io, format: :stream)
+
+    assert_equal 2, Parquet.write_arrow(io.string, write_to: @copy_file)
+    assert_equal Parquet.each_row(@parquet_file).to_a, Parquet.each_row(@copy_file).to_a
+  end
+
+  def test_write_arrow_with_schema
+    Parquet.to_arrow_ipc(@parquet_file, @ipc_file, columns: ["id", "name"])
+    schema = Parquet::Schema.define do
+      field :id, :int32, nullable: false
+      field :name, :string
+    end
+
+    File.open(@ipc_file, "rb") do |input|
+      Parquet.write_arrow(input, write_to: @copy_file, schema: schema)
+    end
+    assert_equal "INT32", Parquet.metadata(@copy_file)["schema"]["fields"][0]["physical_type"]
+    assert_equal [1, 2], Parquet.each_row(@copy_file).map { |row| row["id"] }
+  end
+
+  def test_write_arrow_rejects_null_address
+    assert_raises(ArgumentError) { Parquet.write_arrow(0, write_to: @copy_file) }
+  end
+
+  def test_write_arrow_from_red_arrow
+    begin
+      require 'arrow'
+    rescue LoadError
+      skip "red-arrow is not installed"
+    end
+
+    table = Arrow::Table.new("id" => Arrow::Int64Array.new([1, 2, 3]))
+    reader = Arrow::TableBatchReader.new(table)
+    assert_equal 3, Parquet.write_arrow(reader, write_to: @copy_file)
+    assert_equal [1, 2, 3], Parquet.each_row(@copy_file).map { |row| row["id"] }
+  end
+
+  def test_invalid_format
+    assert_raises(ArgumentError) do
+      Parquet.to_arrow_ipc(@parquet_file, @ipc_file, format: :feather)
+    end
+  end
+end
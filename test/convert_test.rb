@@ -0,0 +1,87 @@
+require_relative 'test_helper'
+
+class ConvertTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_convert_#{Process.pid}.parquet")
+    schema = Parquet::Schema.define do
+      field :amount, :decimal, precision: 10, scale: 2
+      field :created_at, :timestamp_micros, timezone: "UTC"
+      field :updated_at, :timestamp_micros, timezone: "UTC"
+      field :day, :date32
+      field :payload, :binary
+      field :totals, :struct do
+        field :net, :decimal, precision: 10, scale: 2
+      end
+    end
+    @time = Time.utc(2024, 1, 15, 10, 30, 0, 123456)
+    data = [
+      [BigDecimal("12.50"), @time, @time, Date.new(2024, 1, 15), "héllo".b, { "net" => BigDecimal("1.25") }],
+      [nil, nil, nil, nil, nil, nil]
+    ]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_default_conversions
+    row = Parquet.each_row(@test_file).first
+    assert_equal BigDecimal("12.50"), row["amount"]
+    assert_equal @time, row["created_at"]
+    assert_equal Date.new(2024, 1, 15), row["day"]
+    assert_equal Encoding::ASCII_8BIT, row["payload"].encoding
+  end
+
+  def test_convert_by_type
+    rows = Parquet.each_row(
+      @test_file,
+      convert: { decimal: :float, timestamp: :integer, date: :integer, binary: :string }
+    ).to_a
+
+    assert_equal 12.5, rows[0]["amount"]
+    assert_equal (@time.to_r * 1_000_000).to_i, rows[0]["created_at"]
+    assert_equal (Date.new(2024, 1, 15) - Date.new(1970, 1, 1)).to_i, rows[0]["day"]
+    assert_equal "héllo", rows[0]["payload"]
+    assert_equal Encoding::UTF_8, rows[0]["payload"].encoding
+    # Nested values use the conversions of their column
+    assert_equal({ "net" => 1.25 }, rows[0]["totals"])
+    assert_equal [nil] * 6, rows[1].values
+  end
+
+  def test_convert_to_strings
+    row = Parquet.each_row(@test_file, convert: { decimal: :string, timestamp: :iso8601, date: :string }).first
+    assert_equal "12.50", row["amount"]
+    assert_equal "2024-01-15T10:30:00.123456Z", row["created_at"]
+    assert_equal "2024-01-15", row["day"]
+  end
+
+  def test_convert_per_column
+    row = Parquet.each_row(
+      @test_file,
+      convert: { timestamp: :integer, columns: { "created_at" => { timestamp: :iso8601 }, amount: { decimal: :string } } }
+    ).first
+
+    assert_equal "2024-01-15T10:30:00.123456Z", row["created_at"]
+    assert_equal (@time.to_r * 1_000_000).to_i, row["updated_at"]
+    assert_equal "12.50", row["amount"]
+    assert_equal({ "net" => BigDecimal("1.25") }, row["totals"])
+  end
+
+  def test_convert_struct_rows
+    row = Parquet.each_row(@test_file, result_type: :struct, convert: { columns: { totals: { decimal: :float } } }).first
+    assert_equal 1.25, row.totals.net
+    assert_equal BigDecimal("12.50"), row.amount
+  end
+
+  def test_convert_columns
+    batch = Parquet.each_column(@test_file, convert: { decimal: :float, columns: { day: { date: :string } } }).first
+    assert_equal [12.5, nil], batch["amount"]
+    assert_equal ["2024-01-15", nil], batch["day"]
+  end
+
+  def test_invalid_convert
+    assert_raises(ArgumentError) { Parquet.each_row(@test_file, convert: { decimal: :double }) { |_| } }
+    assert_raises(ArgumentError) { Parquet.each_row(@test_file, convert: { money: :float }) { |_| } }
+  end
+end
@@ -0,0 +1,89 @@
+require_relative 'test_helper'
+require 'date'
+require 'stringio'
+
+class CsvTest < Minitest::Test
+  def setup
+    @parquet_file = File.join(Dir.tmpdir, "test_csv_#{Process.pid}.parquet")
+    @csv_file = File.join(Dir.tmpdir, "test_csv_#{Process.pid}.csv")
+  end
+
+  def teardown
+    [@parquet_file, @csv_file].each { |f| File.delete(f) if File.exist?(f) }
+  end
+
+  def test_from_csv_infers_schema
+    File.write(@csv_file, <<~CSV)
+      id,name,score,joined
+      1,Alice,1.5,2024-01-15
+      2,,2,2024-02-01
+    CSV
+
+    assert_equal 2, Parquet.from_csv(@csv_file, write_to: @parquet_file)
+    rows = Parquet.each_row(@parquet_file).to_a
+    assert_equal({ "id" => 1, "name" => "Alice", "score" => 1.5, "joined" => Date.new(2024, 1, 15) }, rows[0])
+    assert_equal({ "id" => 2, "name" => nil, "score" => 2.0, "joined" => Date.new(2024, 2, 1) }, rows[1])
+  end
+
+  def test_from_csv_with_schema_and_options
+    schema = Parquet::Schema.define do
+      field :id, :int32, nullable: false
+      field :joined, :date32, format: "%d/%m/%Y"
+      field :seen_at, :timestamp_millis
+    end
+
+    input = StringIO.new("1;15/01/2024;2024-01-15 10:30\n2;NA;NA\n")
+    output = StringIO.new
+    output.binmode
+    Parquet.from_csv(
+      input,
+      write_to: output,
+      schema: schema,
+      header: false,
+      delimiter: ";",
+      null_values: ["NA"],
+      timestamp_format: "%Y-%m-%d %H:%M",
+      compression: "zstd"
+    )
+
+    rows = Parquet.each_row(StringIO.new(output.string)).to_a
+    assert_equal 1, rows[0]["id"]
+    assert_equal Date.new(2024, 1, 15), rows[0]["joined"]
+    assert_equal Time.utc(2024, 1, 15, 10, 30).to_i, rows[0]["seen_at"].to_i
+    assert_nil rows[1]["joined"]
+    assert_nil rows[1]["seen_at"]
+  end
+
+  def test_from_csv_rejects_long_delimiter
+    File.write(@csv_file, "a\n1\n")
+    assert_raises(ArgumentError) do
+      Parquet.from_csv(@csv_file, write_to: @parquet_file, delimiter: "::")
+    end
+  end
+
+  def test_to_csv
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :name, :string
+    end
+    Parquet.write_rows([[1, "Alice, B."], [2, nil]].each, schema: schema, write_to: @parquet_file)
+
+    assert_equal 2, Parquet.to_csv(@parquet_file, write_to: @csv_file)
+    assert_equal "id,name\n1,\"Alice, B.\"\n2,\n", File.read(@csv_file)
+
+    io = StringIO.new
+    Parquet.to_csv(@parquet_file, write_to: io, columns: ["id"], header: false, delimiter: "\t")
+    assert_equal "1\n2\n", io.string
+  end
+
+  def test_roundtrip
+    File.write(@csv_file, "id,name\n1,Alice\n2,Bob\n")
+    copy = File.join(Dir.tmpdir, "test_csv_copy_#{Process.pid}.csv")
+
+    Parquet.from_csv(@csv_file, write_to: @parquet_file)
+    Parquet.to_csv(@parquet_file, write_to: copy)
+    assert_equal File.read(@csv_file), File.read(copy)
+  ensure
+    File.delete(copy) if copy && File.exist?(copy)
+  end
+end
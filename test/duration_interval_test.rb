@@ -0,0 +1,123 @@
+require_relative 'test_helper'
+
+class DurationIntervalTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_duration_interval_#{Process.pid}.parquet")
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_duration_roundtrip
+    schema = Parquet::Schema.define do
+      field :seconds, :duration, unit: :second
+      field :millis, :duration
+      field :nanos, :duration, unit: :nanos
+    end
+
+    data = [
+      [90, 1.5, Rational(1, 3)],
+      [0, 2, 0.000000001],
+      [nil, nil, nil]
+    ]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file).to_a
+    assert_equal 90, rows[0]["seconds"]
+    assert_equal Rational(3, 2), rows[0]["millis"]
+    assert_equal Rational(333_333_333, 1_000_000_000), rows[0]["nanos"]
+    assert_equal 0, rows[1]["seconds"]
+    assert_equal 2, rows[1]["millis"]
+    assert_equal Rational(1, 1_000_000_000), rows[1]["nanos"]
+    assert_nil rows[2]["seconds"]
+    assert_nil rows[2]["millis"]
+    assert_nil rows[2]["nanos"]
+  end
+
+  def test_duration_unit_spellings
+    schema = Parquet::Schema.define do
+      field :seconds, :duration, unit: :seconds
+      field :millis, :duration, unit: :milliseconds
+      field :micros, :duration, unit: "microsecond"
+    end
+
+    Parquet.write_rows([[1, 2, 3]].each, schema: schema, write_to: @test_file)
+
+    row = Parquet.each_row(@test_file).first
+    assert_equal({ "seconds" => 1, "millis" => 2, "micros" => 3 }, row)
+  end
+
+  def test_duration_rejects_unknown_unit
+    schema = Parquet::Schema.define do
+      field :elapsed, :duration, unit: :fortnights
+    end
+
+    error = assert_raises(ArgumentError) do
+      Parquet.write_rows([[1]].each, schema: schema, write_to: @test_file)
+    end
+    assert_match(/fortnights/, error.message)
+    assert_match(/:second, :millis, :micros and :nanos/, error.message)
+  end
+
+  def test_duration_rejects_non_numeric
+    schema = Parquet::Schema.define do
+      field :elapsed, :duration
+    end
+
+    assert_raises do
+      Parquet.write_rows([["10 minutes"]].each, schema: schema, write_to: @test_file)
+    end
+  end
+
+  def test_interval_roundtrip
+    schema = Parquet::Schema.define do
+      field :window, :interval
+    end
+
+    data = [
+      [{ months: 1, days: 2, millis: 500 }],
+      [{ "days" => 7 }],
+      [nil]
+    ]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file).to_a
+    assert_equal({ months: 1, days: 2, millis: 500 }, rows[0]["window"])
+    assert_equal({ months: 0, days: 7, millis: 0 }, rows[1]["window"])
+    assert_nil rows[2]["window"]
+  end
+
+  def test_interval_in_list
+    schema = Parquet::Schema.define do
+      field :windows, :list, item: :interval
+    end
+
+    Parquet.write_rows([[[{ months: 12 }, { days: 1 }]]].each, schema: schema, write_to: @test_file)
+
+    row = Parquet.each_row(@test_file).first
+    assert_equal [{ months: 12, days: 0, millis: 0 }, { months: 0, days: 1, millis: 0 }], row["windows"]
+  end
+
+  def test_interval_rejects_unknown_or_missing_parts
+    schema = Parquet::Schema.define do
+      field :window, :interval
+    end
+
+    [{ milliseconds: 500 }, { "ms" => 5 }, { months: 1, weeks: 2 }, {}].each do |window|
+      assert_raises(Parquet::ConversionError, window.inspect) do
+        Parquet.write_rows([[window]].each, schema: schema, write_to: @test_file)
+      end
+    end
+  end
+
+  def test_interval_rejects_non_hash
+    schema = Parquet::Schema.define do
+      field :window, :interval
+    end
+
+    assert_raises do
+      Parquet.write_rows([[30]].each, schema: schema, write_to: @test_file)
+    end
+  end
+end
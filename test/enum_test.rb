@@ -0,0 +1,73 @@
+require_relative 'test_helper'
+
+class EnumTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_enum_#{Process.pid}.parquet")
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_enum_roundtrip
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :status, :enum, values: [:active, :inactive, :banned]
+    end
+
+    data = [[1, :active], [2, "inactive"], [3, :active], [4, nil]]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file).to_a
+    assert_equal ["active", "inactive", "active", nil], rows.map { |r| r["status"] }
+  end
+
+  def test_symbolize_enums
+    schema = Parquet::Schema.define do
+      field :status, :enum
+    end
+
+    data = [["active"], ["inactive"], ["active"]]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file, symbolize_enums: true).to_a
+    statuses = rows.map { |r| r["status"] }
+    assert_equal [:active, :inactive, :active], statuses
+    assert statuses.all?(&:frozen?)
+
+    arrays = Parquet.each_row(@test_file, result_type: :array, symbolize_enums: true).to_a
+    assert_equal [[:active], [:inactive], [:active]], arrays
+  end
+
+  def test_enum_value_outside_whitelist_raises
+    schema = Parquet::Schema.define do
+      field :status, :enum, values: %w[active inactive]
+    end
+
+    error = assert_raises do
+      Parquet.write_rows([["deleted"]].each, schema: schema, write_to: @test_file)
+    end
+    assert_match(/deleted/, error.message)
+  end
+
+  def test_enum_rejects_non_string_values
+    schema = Parquet::Schema.define do
+      field :status, :enum
+    end
+
+    assert_raises do
+      Parquet.write_rows([[42]].each, schema: schema, write_to: @test_file)
+    end
+  end
+
+  def test_enum_in_list
+    schema = Parquet::Schema.define do
+      field :tags, :list, item: :enum
+    end
+
+    Parquet.write_rows([[[:a, :b]], [["b"]]].each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file, symbolize_enums: true).to_a
+    assert_equal [[:a, :b], [:b]], rows.map { |r| r["tags"] }
+  end
+end
@@ -28,16 +28,78 @@ class ErrorTest < Minitest::Test
 
     begin
       error =
-        assert_raises(EncodingError) do
+        assert_raises(Parquet::ConversionError) do
           Parquet.write_rows([[invalid_utf8_bytes]].each, schema: schema, write_to: temp_path)
         end
 
       assert_match(/invalid utf-8 sequence/i, error.message)
+      assert_equal 0, error.row_index
+      assert_equal "payload", error.column_path
+      assert_equal "String", error.value_class
     ensure
       File.delete(temp_path) if File.exist?(temp_path)
     end
   end
 
+  def test_error_hierarchy
+    assert_operator Parquet::Error, :<, RuntimeError
+    [
+      Parquet::SchemaError,
+      Parquet::ConversionError,
+      Parquet::IOError,
+      Parquet::UnsupportedTypeError,
+      Parquet::CorruptFileError
+    ].each { |klass| assert_operator klass, :<, Parquet::Error }
+  end
+
+  def test_nonexistent_file_raises_io_error
+    assert_raises(Parquet::IOError) { Parquet.each_row("test/nonexistent.parquet").to_a }
+  end
+
+  def test_non_parquet_file_raises_corrupt_file_error
+    path = "test/not_parquet.txt"
+    File.write(path, "Just some text data.")
+
+    assert_raises(Parquet::CorruptFileError) { Parquet.each_row(path).to_a }
+  ensure
+    File.delete(path) if File.exist?(path)
+  end
+
+  def test_conversion_error_attributes_write_rows
+    temp_path = "test/conversion_error_rows.parquet"
+    schema = [{ "id" => "int64" }, { "name" => "string" }]
+
+    error =
+      assert_raises(Parquet::ConversionError) do
+        Parquet.write_rows([[1, "one"], ["two", "two"]].each, schema: schema, write_to: temp_path)
+      end
+
+    assert_equal 1, error.row_index
+    assert_equal "id", error.column_path
+    assert_equal "Int64", error.expected_type
+    assert_equal "String", error.value_class
+  ensure
+    File.delete(temp_path) if File.exist?(temp_path)
+  end
+
+  def test_conversion_error_attributes_write_columns
+    temp_path = "test/conversion_error_columns.parquet"
+    schema = [{ "id" => "int32" }, { "name" => "string" }]
+    batches = [[[1, 2], %w[a b]], [[3, :four], %w[c d]]]
+
+    error =
+      assert_raises(Parquet::ConversionError) do
+        Parquet.write_columns(batches.each, schema: schema, write_to: temp_path)
+      end
+
+    assert_equal 3, error.row_index
+    assert_equal "id", error.column_path
+    assert_equal "Int32", error.expected_type
+    assert_equal "Symbol", error.value_class
+  ensure
+    File.delete(temp_path) if File.exist?(temp_path)
+  end
+
   def test_reading_non_parquet_file
     non_parquet_path = "test/non_parquet.txt"
     File.write(non_parquet_path, "Just some text data.")
@@ -0,0 +1,128 @@
+require_relative 'test_helper'
+require 'json'
+
+class GeometryTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_geometry_#{Process.pid}.parquet")
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_geometry_roundtrip_as_wkt
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :geom, :geometry
+    end
+
+    data = [
+      [1, "POINT (1 2)"],
+      [2, "linestring (0 0, 1 1.5)"],
+      [3, "POLYGON ((0 0, 4 0, 4 4, 0 0))"],
+      [4, "GEOMETRYCOLLECTION (POINT (1 2), POINT Z (3 4 5))"],
+      [5, nil]
+    ]
+
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    geoms = Parquet.each_row(@test_file).map { |row| row["geom"] }
+    assert_equal [
+      "POINT (1 2)",
+      "LINESTRING (0 0, 1 1.5)",
+      "POLYGON ((0 0, 4 0, 4 4, 0 0))",
+      "GEOMETRYCOLLECTION (POINT (1 2), POINT Z (3 4 5))",
+      nil
+    ], geoms
+  end
+
+  def test_coordinate_arrays_and_hashes
+    schema = Parquet::Schema.define do
+      field :geom, :geometry
+    end
+
+    data = [
+      [[1, 2]],
+      [[[0, 0], [3, 4]]],
+      [[[[0, 0], [1, 0], [1, 1], [0, 0]]]],
+      [{ type: "MultiPoint", coordinates: [[1, 2], [3, 4]] }],
+      [{ "type" => "Point", "coordinates" => [5.5, 6.5, 7.5] }]
+    ]
+
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    geoms = Parquet.each_row(@test_file, geometry_format: :coordinates).map { |row| row["geom"] }
+    assert_equal({ "type" => "Point", "coordinates" => [1.0, 2.0] }, geoms[0])
+    assert_equal({ "type" => "LineString", "coordinates" => [[0.0, 0.0], [3.0, 4.0]] }, geoms[1])
+    assert_equal "Polygon", geoms[2]["type"]
+    assert_equal({ "type" => "MultiPoint", "coordinates" => [[1.0, 2.0], [3.0, 4.0]] }, geoms[3])
+    assert_equal [5.5, 6.5, 7.5], geoms[4]["coordinates"]
+
+    wkb = Parquet.each_row(@test_file, geometry_format: :wkb).first["geom"]
+    assert_equal Encoding::BINARY, wkb.encoding
+    assert_equal 21, wkb.bytesize
+  end
+
+  def test_wkb_input
+    schema = Parquet::Schema.define do
+      field :geom, :geometry
+    end
+
+    # Little endian WKB for POINT (1 2)
+    wkb = [1, 1, 1.0, 2.0].pack("CVEE")
+    Parquet.write_rows([[wkb]].each, schema: schema, write_to: @test_file)
+
+    assert_equal "POINT (1 2)", Parquet.each_row(@test_file).first["geom"]
+  end
+
+  def test_geo_metadata
+    schema = Parquet::Schema.define do
+      field :name, :string
+      field :location, :geometry, crs: "EPSG:4326"
+      field :area, :geography
+    end
+
+    data = [
+      ["a", "POINT (-3 1)", "POLYGON ((0 0, 1 0, 1 1, 0 0))"],
+      ["b", "POINT (4 -2)", nil]
+    ]
+
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    kv = Parquet.metadata(@test_file)["key_value_metadata"].find { |entry| entry["key"] == "geo" }
+    geo = JSON.parse(kv["value"])
+    assert_equal "location", geo["primary_column"]
+
+    location = geo["columns"]["location"]
+    assert_equal "WKB", location["encoding"]
+    assert_equal ["Point"], location["geometry_types"]
+    assert_equal [-3.0, -2.0, 4.0, 1.0], location["bbox"]
+    assert_equal({ "id" => { "authority" => "EPSG", "code" => 4326 } }, location["crs"])
+
+    area = geo["columns"]["area"]
+    assert_equal ["Polygon"], area["geometry_types"]
+    assert_equal "spherical", area["edges"]
+  end
+
+  def test_invalid_geometry
+    schema = Parquet::Schema.define do
+      field :geom, :geometry
+    end
+
+    assert_raises do
+      Parquet.write_rows([["POINT (1)"]].each, schema: schema, write_to: @test_file)
+    end
+
+    assert_raises do
+      Parquet.write_rows([[42]].each, schema: schema, write_to: @test_file)
+    end
+  end
+
+  def test_schema_dsl_keeps_crs
+    schema = Parquet::Schema.define do
+      field :geom, :geometry, crs: "EPSG:3857"
+    end
+
+    assert_equal "EPSG:3857", schema[:fields][0][:crs]
+  end
+end
@@ -0,0 +1,85 @@
+require_relative 'test_helper'
+
+class JsonTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_json_#{Process.pid}.parquet")
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_json_roundtrip
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :payload, :json
+    end
+
+    data = [
+      [1, { "user" => "alice", "tags" => ["a", "b"], "score" => 1.5 }],
+      [2, [1, 2, { "nested" => nil }]],
+      [3, '{"user":"bob"}'],
+      [4, nil]
+    ]
+
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file).to_a
+    assert_equal '{"user":"alice","tags":["a","b"],"score":1.5}', rows[0]["payload"]
+    assert_equal '[1,2,{"nested":null}]', rows[1]["payload"]
+    assert_equal '{"user":"bob"}', rows[2]["payload"]
+    assert_nil rows[3]["payload"]
+
+    parsed = Parquet.each_row(@test_file, parse_json: true).to_a
+    assert_equal({ "user" => "alice", "tags" => ["a", "b"], "score" => 1.5 }, parsed[0]["payload"])
+    assert_equal [1, 2, { "nested" => nil }], parsed[1]["payload"]
+    assert_equal({ "user" => "bob" }, parsed[2]["payload"])
+    assert_nil parsed[3]["payload"]
+  end
+
+  def test_json_logical_type_in_metadata
+    schema = Parquet::Schema.define do
+      field :payload, :json
+    end
+
+    Parquet.write_rows([[{ "a" => 1 }]].each, schema: schema, write_to: @test_file)
+
+    field = Parquet.metadata(@test_file)["schema"]["fields"].find { |f| f["name"] == "payload" }
+    assert_equal "Json", field["logical_type"]["type"]
+  end
+
+  def test_json_symbol_keys_are_serialized_as_strings
+    schema = Parquet::Schema.define do
+      field :payload, :json
+    end
+
+    Parquet.write_rows([[{ status: :ok }]].each, schema: schema, write_to: @test_file)
+
+    row = Parquet.each_row(@test_file, parse_json: true).first
+    assert_equal({ "status" => "ok" }, row["payload"])
+  end
+
+  def test_json_nested_in_struct
+    schema = Parquet::Schema.define do
+      field :event, :struct do
+        field :kind, :string
+        field :body, :json
+      end
+    end
+
+    Parquet.write_rows([[{ "kind" => "click", "body" => { "x" => 1 } }]].each, schema: schema, write_to: @test_file)
+
+    row = Parquet.each_row(@test_file, parse_json: true).first
+    assert_equal({ "kind" => "click", "body" => { "x" => 1 } }, row["event"])
+  end
+
+  def test_invalid_json_string_raises
+    schema = Parquet::Schema.define do
+      field :payload, :json
+    end
+
+    assert_raises do
+      Parquet.write_rows([["{not json"]].each, schema: schema, write_to: @test_file)
+    end
+  end
+end
@@ -0,0 +1,59 @@
+require_relative 'test_helper'
+
+class KeysTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_keys_#{Process.pid}.parquet")
+
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :address, :struct do
+        field :city, :string
+      end
+      field :attrs, :map, key: :string, value: :int32
+      field :counts, :map, key: :int32, value: :string
+    end
+
+    data = [
+      [1, { "city" => "Paris" }, { "a" => 1 }, { 7 => "seven" }],
+      [2, nil, {}, nil]
+    ]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_string_keys_by_default
+    row = Parquet.each_row(@test_file).first
+    assert_equal({ "id" => 1, "address" => { "city" => "Paris" }, "attrs" => { "a" => 1 }, "counts" => { 7 => "seven" } }, row)
+  end
+
+  def test_symbol_keys
+    rows = Parquet.each_row(@test_file, keys: :symbol).to_a
+    assert_equal({ id: 1, address: { city: "Paris" }, attrs: { a: 1 }, counts: { 7 => "seven" } }, rows[0])
+    assert_equal({ id: 2, address: nil, attrs: {}, counts: nil }, rows[1])
+  end
+
+  def test_proc_keys
+    calls = 0
+    transform = lambda do |name|
+      calls += 1
+      name.upcase
+    end
+
+    rows = Parquet.each_row(@test_file, keys: transform).to_a
+    assert_equal({ "ID" => 1, "ADDRESS" => { "CITY" => "Paris" }, "ATTRS" => { "A" => 1 }, "COUNTS" => { 7 => "seven" } }, rows[0])
+    # Column and field names are resolved once, only map keys are per entry
+    assert_equal 6, calls
+  end
+
+  def test_keys_with_enumerator
+    enum = Parquet.each_row(@test_file, keys: :symbol)
+    assert_equal 1, enum.next[:id]
+  end
+
+  def test_invalid_keys
+    assert_raises(ArgumentError) { Parquet.each_row(@test_file, keys: :bogus) { |_| } }
+  end
+end
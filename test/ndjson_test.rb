@@ -0,0 +1,78 @@
+require_relative 'test_helper'
+require 'json'
+require 'stringio'
+
+class NdjsonTest < Minitest::Test
+  def setup
+    @parquet_file = File.join(Dir.tmpdir, "test_ndjson_#{Process.pid}.parquet")
+    @json_file = File.join(Dir.tmpdir, "test_ndjson_#{Process.pid}.jsonl")
+  end
+
+  def teardown
+    [@parquet_file, @json_file].each { |f| File.delete(f) if File.exist?(f) }
+  end
+
+  def write_people
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :name, :string
+      field :tags, :list, item: :string
+    end
+    data = [[1, "Alice", ["a", "b"]], [2, nil, []]]
+    Parquet.write_rows(data.each, schema: schema, write_to: @parquet_file)
+  end
+
+  def test_to_ndjson
+    write_people
+
+    assert_equal 2, Parquet.to_ndjson(@parquet_file, @json_file)
+    lines = File.readlines(@json_file).map { |line| JSON.parse(line) }
+    assert_equal [{ "id" => 1, "name" => "Alice", "tags" => ["a", "b"] }, { "id" => 2, "tags" => [] }], lines
+  end
+
+  def test_to_ndjson_columns_and_io
+    write_people
+
+    io = StringIO.new
+    Parquet.to_ndjson(File.open(@parquet_file, "rb"), io, columns: ["id"])
+    assert_equal "{\"id\":1}\n{\"id\":2}\n", io.string
+  end
+
+  def test_from_ndjson_infers_schema
+    File.write(@json_file, <<~JSON)
+      {"id": 1, "name": "Alice", "score": 1.5, "active": true}
+      {"id": 2, "name": null, "score": 2, "active": false}
+    JSON
+
+    assert_equal 2, Parquet.from_ndjson(@json_file, @parquet_file)
+    rows = Parquet.each_row(@parquet_file).to_a
+    assert_equal({ "id" => 1, "name" => "Alice", "score" => 1.5, "active" => true }, rows[0])
+    assert_equal({ "id" => 2, "name" => nil, "score" => 2.0, "active" => false }, rows[1])
+  end
+
+  def test_from_ndjson_with_schema_to_io
+    schema = Parquet::Schema.define do
+      field :id, :int32, nullable: false
+      field :name, :string
+    end
+
+    input = StringIO.new("{\"id\":1,\"name\":\"Alice\"}\n{\"id\":2}\n")
+    output = StringIO.new
+    output.binmode
+    Parquet.from_ndjson(input, output, schema: schema, compression: "zstd")
+
+    rows = Parquet.each_row(StringIO.new(output.string)).to_a
+    assert_equal [{ "id" => 1, "name" => "Alice" }, { "id" => 2, "name" => nil }], rows
+  end
+
+  def test_roundtrip
+    write_people
+    copy = File.join(Dir.tmpdir, "test_ndjson_copy_#{Process.pid}.parquet")
+
+    Parquet.to_ndjson(@parquet_file, @json_file)
+    Parquet.from_ndjson(@json_file, copy)
+    assert_equal Parquet.each_row(@parquet_file).map { |r| r["name"] }, Parquet.each_row(copy).map { |r| r["name"] }
+  ensure
+    File.delete(copy) if copy && File.exist?(copy)
+  end
+end
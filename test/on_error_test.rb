@@ -0,0 +1,96 @@
+require_relative 'test_helper'
+require 'json'
+require 'stringio'
+
+class OnErrorTest < Minitest::Test
+  ROWS = [
+    [1, "a"],
+    ["x", "b"],  # can't be converted to int32
+    [nil, "c"],  # null in a non-nullable column
+    [4, "d"],
+    [5],         # too few values
+    "not a row",
+    [7, "g"]
+  ].freeze
+
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_on_error_#{Process.pid}.parquet")
+    @schema = Parquet::Schema.define do
+      field :id, :int32, nullable: false
+      field :name, :string
+    end
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def write(**options)
+    Parquet.write_rows(ROWS.each, schema: @schema, write_to: @test_file, **options)
+  end
+
+  def written_ids
+    Parquet.each_row(@test_file).map { |row| row["id"] }
+  end
+
+  def test_raise_by_default
+    error = assert_raises(Parquet::ConversionError) { write }
+    assert_equal 1, error.row_index
+    assert_equal "id", error.column_path
+  end
+
+  def test_raise_on_non_array_row
+    rows = [[1, "a"], "not a row"]
+    error = assert_raises(Parquet::ConversionError) do
+      Parquet.write_rows(rows.each, schema: @schema, write_to: @test_file)
+    end
+    assert_equal 1, error.row_index
+    assert_equal "String", error.value_class
+  end
+
+  def test_skip
+    assert_nil write(on_error: :skip)
+    assert_equal [1, 4, 7], written_ids
+  end
+
+  def test_collect
+    errors = write(on_error: :collect)
+
+    assert_equal [1, 4, 7], written_ids
+    assert_equal [1, 2, 4, 5], errors.map { |e| e["row_index"] }
+    assert_equal ["id", "id", nil, nil], errors.map { |e| e["column"] }
+    assert_match(/null value for non-nullable field/, errors[1]["message"])
+    assert_match(/Row has 1 values but schema has 2 fields/, errors[2]["message"])
+    assert_match(/must be an array/, errors[3]["message"])
+  end
+
+  def test_dead_letter
+    dead_letter = StringIO.new
+    write(on_error: :skip, dead_letter: dead_letter)
+
+    lines = dead_letter.string.lines.map { |line| JSON.parse(line) }
+    assert_equal [1, 2, 4, 5], lines.map { |l| l["row_index"] }
+    assert_equal ["x", "b"], lines[0]["row"]
+    assert_equal "id", lines[0]["column"]
+    assert_equal "not a row", lines[3]["row"]
+    assert_equal [1, 4, 7], written_ids
+  end
+
+  def test_batch_failures_still_raise
+    # Rows are checked one at a time; sort order only fails once a batch is built
+    rows = [[2, "b"], ["x", "bad"], [1, "a"]]
+    error = assert_raises(Parquet::Error) do
+      Parquet.write_rows(rows.each, schema: @schema, write_to: @test_file,
+                         sorted_by: [:id], verify_sorted: true, on_error: :skip)
+    end
+    assert_match(/out of order/, error.message)
+  end
+
+  def test_invalid_options
+    assert_raises(ArgumentError) { write(on_error: :ignore) }
+    assert_raises(ArgumentError) { write(dead_letter: StringIO.new) }
+    assert_raises(ArgumentError) do
+      Parquet.write_columns([[[1], ["a"]]].each, schema: @schema, write_to: @test_file, on_error: :skip)
+    end
+  end
+end
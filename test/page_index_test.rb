@@ -0,0 +1,60 @@
+require_relative 'test_helper'
+require 'stringio'
+
+class PageIndexTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_page_index_#{Process.pid}.parquet")
+
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :price, :decimal, precision: 10, scale: 2
+      field :day, :date32
+    end
+
+    data = [
+      [1, BigDecimal("19.99"), Date.new(2024, 1, 2)],
+      [2, nil, Date.new(2023, 6, 1)],
+      [3, BigDecimal("-2.50"), Date.new(2025, 12, 31)]
+    ]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_pages_are_not_loaded_by_default
+    columns = Parquet.metadata(@test_file)["row_groups"][0]["columns"]
+    columns.each { |column| refute column.key?("pages") }
+  end
+
+  def test_pages_with_decoded_values
+    columns = Parquet.metadata(@test_file, page_index: true)["row_groups"][0]["columns"]
+    by_path = columns.to_h { |column| [column["column_path"], column] }
+
+    id_pages = by_path["id"]["pages"]
+    assert_equal 1, id_pages.size
+    page = id_pages.first
+    assert_equal 0, page["first_row_index"]
+    assert_operator page["offset"], :>=, 4
+    assert_operator page["compressed_size"], :>, 0
+    assert_equal 1, page["min"]
+    assert_equal 3, page["max"]
+    assert_equal 0, page["null_count"]
+
+    price = by_path["price"]["pages"].first
+    assert_equal BigDecimal("-2.50"), price["min"]
+    assert_equal BigDecimal("19.99"), price["max"]
+    assert_equal 1, price["null_count"]
+
+    day = by_path["day"]["pages"].first
+    assert_equal Date.new(2023, 6, 1), day["min"]
+    assert_equal Date.new(2025, 12, 31), day["max"]
+  end
+
+  def test_page_index_from_io
+    io = StringIO.new(File.binread(@test_file))
+    columns = Parquet.metadata(io, page_index: true)["row_groups"][0]["columns"]
+    assert columns.all? { |column| column["pages"].is_a?(Array) }
+  end
+end
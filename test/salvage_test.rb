@@ -0,0 +1,76 @@
+require_relative 'test_helper'
+require 'logger'
+require 'stringio'
+
+class SalvageTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_salvage_#{Process.pid}.parquet")
+    @repaired_file = File.join(Dir.tmpdir, "test_salvage_repaired_#{Process.pid}.parquet")
+
+    @schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :name, :string
+    end
+    data = (1..100).map { |i| [i, "name #{i}"] }
+    Parquet.write_rows(data.each, schema: @schema, write_to: @test_file)
+  end
+
+  def teardown
+    [@test_file, @repaired_file].each { |f| File.delete(f) if File.exist?(f) }
+  end
+
+  # The file as a writer that never called close would have left it
+  def without_footer
+    data = File.binread(@test_file)
+    footer_length = data[-8, 4].unpack1("V")
+    data[0, data.size - 8 - footer_length]
+  end
+
+  def test_salvage_yields_rows
+    rows = []
+    report = Parquet.salvage(StringIO.new(without_footer), schema: @schema) { |row| rows << row }
+
+    assert_equal 100, report["num_rows"]
+    assert_equal 1, report["row_groups"]
+    assert_equal 100, rows.size
+    assert_equal({ "id" => 1, "name" => "name 1" }, rows.first)
+  end
+
+  def test_salvage_writes_repaired_file_with_schema_from_sibling
+    damaged = StringIO.new(without_footer)
+    report = Parquet.salvage(damaged, schema_from: @test_file, write_to: @repaired_file)
+
+    assert_equal 100, report["num_rows"]
+    assert_equal 100, Parquet.metadata(@repaired_file)["num_rows"]
+    assert_equal (1..100).to_a, Parquet.each_row(@repaired_file).map { |row| row["id"] }
+  end
+
+  def test_salvage_argument_errors
+    damaged = StringIO.new(without_footer)
+    assert_raises(ArgumentError) { Parquet.salvage(damaged) }
+    assert_raises(ArgumentError) { Parquet.salvage(damaged, schema: @schema, schema_from: @test_file) }
+    assert_raises(ArgumentError) do
+      Parquet.salvage(damaged, schema: @schema, write_to: @repaired_file) { |_| }
+    end
+  end
+
+  def test_skip_corrupt_row_groups
+    column = Parquet.metadata(@test_file)["row_groups"][0]["columns"].find { |c| c["column_path"] == "id" }
+    offset = column["dictionary_page_offset"] || column["data_page_offset"]
+    File.open(@test_file, "r+b") do |f|
+      f.seek(offset)
+      f.write("\xFF".b * 4)
+    end
+
+    assert_raises(RuntimeError) { Parquet.each_row(@test_file).to_a }
+
+    log_output = StringIO.new
+    logger = Logger.new(log_output)
+    rows = Parquet.each_row(@test_file, skip_corrupt_row_groups: true, logger: logger).to_a
+    assert_empty rows
+    assert_match(/Skipped corrupt row group 0 \(100 rows\)/, log_output.string)
+
+    batches = Parquet.each_column(@test_file, skip_corrupt_row_groups: true).to_a
+    assert_empty batches
+  end
+end
@@ -0,0 +1,43 @@
+require_relative 'test_helper'
+
+class SampleTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_sample_#{Process.pid}.parquet")
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :name, :string
+    end
+    data = (0...1000).map { |i| [i, "name #{i}"] }
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file, batch_size: 100)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_sample_n_rows
+    rows = Parquet.sample(@test_file, n: 50, seed: 42)
+    assert_equal 50, rows.size
+    ids = rows.map { |row| row["id"] }
+    assert_equal ids.sort, ids
+    assert_equal ids.uniq, ids
+    assert_equal({ "id" => ids.first, "name" => "name #{ids.first}" }, rows.first)
+
+    assert_equal rows, Parquet.sample(@test_file, n: 50, seed: 42)
+    assert_equal 1000, Parquet.sample(@test_file, n: 5000).size
+  end
+
+  def test_sample_rate_with_block
+    yielded = []
+    result = Parquet.sample(@test_file, sample_rate: 0.2, seed: 7, columns: ["name"]) { |row| yielded << row }
+    assert_nil result
+    assert_includes 120..280, yielded.size
+    assert_equal ["name"], yielded.first.keys
+  end
+
+  def test_sample_argument_errors
+    assert_raises(ArgumentError) { Parquet.sample(@test_file) }
+    assert_raises(ArgumentError) { Parquet.sample(@test_file, n: 10, sample_rate: 0.1) }
+    assert_raises(ArgumentError) { Parquet.sample(@test_file, sample_rate: 1.5) }
+  end
+end
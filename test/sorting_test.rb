@@ -0,0 +1,63 @@
+require_relative 'test_helper'
+
+class SortingTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_sorting_#{Process.pid}.parquet")
+    @schema = Parquet::Schema.define do
+      field :tenant_id, :int32, nullable: false
+      field :ts, :int64
+    end
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def write_tenants(tenants, **options)
+    rows = tenants.flat_map { |tenant_id| 9.downto(0).map { |ts| [tenant_id, ts] } }
+    Parquet.write_rows(rows.each, schema: @schema, write_to: @test_file, batch_size: 10, **options)
+  end
+
+  def test_sorting_columns_in_metadata
+    write_tenants(0...5, sorted_by: [[:tenant_id, :asc], [:ts, :desc, { nulls_first: true }]])
+
+    sorting = Parquet.metadata(@test_file)["row_groups"][0]["sorting_columns"]
+    assert_equal [
+      { "column_path" => "tenant_id", "column_idx" => 0, "descending" => false, "nulls_first" => false },
+      { "column_path" => "ts", "column_idx" => 1, "descending" => true, "nulls_first" => true }
+    ], sorting
+  end
+
+  def test_unsorted_file_has_no_sorting_columns
+    write_tenants(0...2)
+    assert_nil Parquet.metadata(@test_file)["row_groups"][0]["sorting_columns"]
+  end
+
+  def test_verify_sorted
+    write_tenants(0...5, sorted_by: [:tenant_id], verify_sorted: true)
+
+    error = assert_raises(RuntimeError) do
+      write_tenants([3, 1], sorted_by: [:tenant_id], verify_sorted: true)
+    end
+    assert_match(/out of order/, error.message)
+  end
+
+  def test_filter_rows
+    write_tenants(0...10, sorted_by: [:tenant_id, [:ts, :desc]])
+
+    rows = Parquet.each_row(@test_file, filter: { tenant_id: 3...5 }).to_a
+    assert_equal [3] * 10 + [4] * 10, rows.map { |row| row["tenant_id"] }
+
+    rows = Parquet.each_row(@test_file, filter: { "tenant_id" => 7, ts: 2..3 }).to_a
+    assert_equal [{ "tenant_id" => 7, "ts" => 3 }, { "tenant_id" => 7, "ts" => 2 }], rows
+
+    assert_empty Parquet.each_row(@test_file, filter: { tenant_id: 42.. }).to_a
+  end
+
+  def test_filter_columns
+    write_tenants(0...10, sorted_by: [:tenant_id])
+
+    batches = Parquet.each_column(@test_file, columns: ["ts"], filter: { tenant_id: 8.. }).to_a
+    assert_equal [9, 8, 7, 6, 5, 4, 3, 2, 1, 0] * 2, batches.flat_map { |batch| batch["ts"] }
+  end
+end
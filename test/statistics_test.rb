@@ -0,0 +1,85 @@
+require_relative 'test_helper'
+require 'stringio'
+
+class StatisticsTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_statistics_#{Process.pid}.parquet")
+
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :price, :decimal, precision: 10, scale: 2
+      field :created_at, :timestamp_millis
+      field :day, :date32
+      field :name, :string
+      field :address, :struct do
+        field :city, :string
+      end
+    end
+
+    data = [
+      [1, BigDecimal("19.99"), Time.utc(2024, 1, 2, 3, 4, 5), Date.new(2024, 1, 2), "bob", { "city" => "Paris" }],
+      [2, nil, Time.utc(2023, 6, 1), Date.new(2023, 6, 1), "alice", { "city" => nil }],
+      [3, BigDecimal("-2.50"), Time.utc(2025, 12, 31), Date.new(2025, 12, 31), nil, { "city" => "Oslo" }]
+    ]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_min_max_are_decoded
+    columns = Parquet.statistics(@test_file)["columns"]
+
+    assert_equal 1, columns["id"]["min"]
+    assert_equal 3, columns["id"]["max"]
+
+    assert_equal BigDecimal("-2.50"), columns["price"]["min"]
+    assert_equal BigDecimal("19.99"), columns["price"]["max"]
+    assert_equal 1, columns["price"]["null_count"]
+
+    assert_kind_of Time, columns["created_at"]["min"]
+    assert_equal Time.utc(2023, 6, 1), columns["created_at"]["min"]
+    assert_equal Time.utc(2025, 12, 31), columns["created_at"]["max"]
+
+    assert_equal Date.new(2023, 6, 1), columns["day"]["min"]
+    assert_equal Date.new(2025, 12, 31), columns["day"]["max"]
+
+    assert_equal "alice", columns["name"]["min"]
+    assert_equal "bob", columns["name"]["max"]
+    assert_equal 1, columns["name"]["null_count"]
+  end
+
+  def test_sizes_and_counts
+    stats = Parquet.statistics(@test_file)
+    assert_equal 3, stats["num_rows"]
+    refute stats.key?("row_groups")
+
+    id = stats["columns"]["id"]
+    assert_equal 3, id["num_values"]
+    assert_operator id["total_compressed_size"], :>, 0
+    assert_operator id["total_uncompressed_size"], :>, 0
+  end
+
+  def test_nested_columns_are_keyed_by_path
+    city = Parquet.statistics(@test_file)["columns"]["address.city"]
+    assert_equal 1, city["null_count"]
+    assert_nil city["min"]
+    assert_nil city["max"]
+  end
+
+  def test_columns_and_row_groups
+    stats = Parquet.statistics(@test_file, columns: ["price"], row_groups: true)
+    assert_equal ["price"], stats["columns"].keys
+
+    assert_equal 1, stats["row_groups"].size
+    row_group = stats["row_groups"].first
+    assert_equal 3, row_group["num_rows"]
+    assert_equal stats["columns"]["price"], row_group["columns"]["price"]
+  end
+
+  def test_io_input
+    io = StringIO.new(File.binread(@test_file))
+    assert_equal 3, Parquet.statistics(io)["columns"]["id"]["max"]
+  end
+end
@@ -148,4 +148,42 @@ class StringCacheTest < Minitest::Test
     log_content = log_output.string
     refute_match(/String cache stats:/, log_content)
   end
-end
\ No newline at end of file
+
+  def write_categories
+    categories = ["apple", "banana", "cherry"]
+    data = 300.times.map { |i| [i, categories[i % 3], "user_#{i % 10}"] }
+    schema = {
+      fields: [
+        {name: 'id', type: :int32},
+        {name: 'category', type: :string},
+        {name: 'user', type: :string}
+      ]
+    }
+    Parquet.write_rows(data, schema: schema, write_to: @test_file)
+  end
+
+  def test_intern_strings_on_read
+    write_categories
+
+    rows = Parquet.each_row(@test_file, intern_strings: true).to_a
+    assert_equal 300, rows.length
+    assert_equal "apple", rows[0]["category"]
+    assert rows[0]["category"].frozen?
+    assert_same rows[0]["category"], rows[3]["category"]
+    assert_same rows[0]["user"], rows[10]["user"]
+
+    rows = Parquet.each_row(@test_file, intern_strings: [:category], result_type: :array).to_a
+    assert_same rows[1][1], rows[4][1]
+    refute_same rows[0][2], rows[10][2]
+
+    rows = Parquet.each_row(@test_file).to_a
+    refute rows[0]["category"].frozen?
+  end
+
+  def test_intern_strings_invalid
+    write_categories
+
+    assert_raises(ArgumentError) { Parquet.each_row(@test_file, intern_strings: "category").to_a }
+    assert_raises(RuntimeError) { Parquet.each_row(@test_file, intern_strings: [:id]).to_a }
+  end
+end
@@ -0,0 +1,73 @@
+require_relative 'test_helper'
+
+class StructRowsTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_struct_rows_#{Process.pid}.parquet")
+
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :name, :string
+      field :address, :struct do
+        field :city, :string
+        field :zip, :string
+      end
+      field :tags, :list, item: :string
+    end
+
+    data = [
+      [1, "Alice", { "city" => "Paris", "zip" => "75001" }, ["a", "b"]],
+      [2, "Bob", nil, []]
+    ]
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_struct_rows
+    rows = Parquet.each_row(@test_file, result_type: :struct).to_a
+
+    assert_equal [:id, :name, :address, :tags], rows[0].class.members
+    assert_equal 1, rows[0].id
+    assert_equal "Alice", rows[0].name
+    assert_equal "Paris", rows[0].address.city
+    assert_equal "75001", rows[0].address.zip
+    assert_equal ["a", "b"], rows[0].tags
+    assert_nil rows[1].address
+  end
+
+  def test_one_class_per_file
+    rows = Parquet.each_row(@test_file, result_type: "struct").to_a
+    assert_same rows[0].class, rows[1].class
+  end
+
+  def test_struct_rows_with_projection
+    rows = Parquet.each_row(@test_file, result_type: :struct, columns: ["name"]).to_a
+    assert_equal [:name], rows[0].class.members
+    assert_equal ["Alice", "Bob"], rows.map(&:name)
+  end
+
+  def test_user_supplied_class
+    klass = defined?(Data) && Data.respond_to?(:define) ? Data.define(:name, :id) : Struct.new(:name, :id)
+
+    rows = Parquet.each_row(@test_file, result_type: klass).to_a
+    assert_instance_of klass, rows[0]
+    assert_equal "Alice", rows[0].name
+    assert_equal 2, rows[1].id
+  end
+
+  def test_user_supplied_class_with_unknown_member
+    klass = Struct.new(:id, :missing)
+
+    assert_raises(ArgumentError) do
+      Parquet.each_row(@test_file, result_type: klass) { |_| }
+    end
+  end
+
+  def test_each_column_rejects_struct
+    assert_raises(ArgumentError) do
+      Parquet.each_column(@test_file, result_type: :struct) { |_| }
+    end
+  end
+end
@@ -75,9 +75,8 @@ class TimezoneTest < Minitest::Test
     times.each_with_index do |original_time, i|
       read_time = read_rows[i]["timestamp"]
 
-      # According to Parquet spec, timestamps with ANY timezone are normalized to UTC
-      # The original timezone is lost - we only get back UTC timestamps
-      assert read_time.utc?, "Time should be in UTC for row #{i} (Parquet limitation)"
+      # Stored as UTC, then read back in the schema's zone
+      assert_equal 9 * 3600, read_time.utc_offset, "Time should be in +09:00 for row #{i}"
       assert_equal original_time.to_i, read_time.to_i, "Timestamp seconds should be preserved for row #{i}"
     end
   end
@@ -151,9 +150,8 @@ class TimezoneTest < Minitest::Test
           "Nanosecond precision should preserve all nanoseconds"
       end
 
-      # When schema has timezone, times are returned in UTC (Parquet limitation)
-      assert read_time.utc?, "Read time should be in UTC for #{field_name} (Parquet limitation)"
-      assert_equal 0, read_time.utc_offset, "UTC offset should be 0 for #{field_name}"
+      # Times are returned in the schema's zone, PDT in June
+      assert_equal(-7 * 3600, read_time.utc_offset, "Read time should be in America/Los_Angeles for #{field_name}")
     end
   end
 
@@ -176,10 +174,10 @@ class TimezoneTest < Minitest::Test
     read_rows = []
     Parquet.each_row(@test_file) { |row| read_rows << row }
 
-    # According to Parquet spec, ANY timestamp with timezone is normalized to UTC
-    # Original timezones (+09:00, -05:00) are lost - we only get UTC back
-    assert read_rows[0]["timestamp_tokyo"].utc?, "timestamp_tokyo should be in UTC (Parquet limitation)"
-    assert read_rows[0]["timestamp_est"].utc?, "timestamp_est should be in UTC (Parquet limitation)"
+    # Timestamps with a timezone are stored as UTC and read back in the schema's zone
+    assert_equal 9 * 3600, read_rows[0]["timestamp_tokyo"].utc_offset
+    assert_equal(-5 * 3600, read_rows[0]["timestamp_est"].utc_offset)
+    assert_equal Time.utc(2024, 1, 1, 3), read_rows[0]["timestamp_tokyo"]
 
     # UTC should be marked as UTC
     assert read_rows[0]["timestamp_utc"].utc?
@@ -225,13 +223,13 @@ class TimezoneTest < Minitest::Test
     assert_equal local_time.to_i, read_rows[0]["timestamp_default"].to_i
   end
 
-  def test_legacy_timezone_parameter_compatibility
-    # Ensure legacy timezone parameter still works (any value -> UTC storage)
+  def test_timezone_parameter
+    # Any zone means UTC storage, and the zone is kept for reading
     schema = Parquet::Schema.define do
       field :id, :int32
       field :ts_utc, :timestamp_millis, timezone: "UTC"
-      field :ts_tokyo, :timestamp_millis, timezone: "+09:00"      # Still stored as UTC
-      field :ts_ny, :timestamp_millis, timezone: "America/New_York" # Still stored as UTC
+      field :ts_tokyo, :timestamp_millis, timezone: "+09:00"
+      field :ts_ny, :timestamp_millis, timezone: "America/New_York"
     end
 
     test_time = Time.new(2024, 3, 15, 14, 30, 0, "+02:00")
@@ -242,14 +240,38 @@ class TimezoneTest < Minitest::Test
     read_rows = []
     Parquet.each_row(@test_file) { |row| read_rows << row }
 
-    # All should be in UTC regardless of timezone value
-    assert read_rows[0]["ts_utc"].utc?, "UTC timezone should store as UTC"
-    assert read_rows[0]["ts_tokyo"].utc?, "+09:00 timezone should store as UTC"
-    assert read_rows[0]["ts_ny"].utc?, "America/New_York timezone should store as UTC"
+    assert read_rows[0]["ts_utc"].utc?, "UTC timezone should read as UTC"
+    assert_equal 9 * 3600, read_rows[0]["ts_tokyo"].utc_offset
+    # Daylight saving time started on March 10
+    assert_equal(-4 * 3600, read_rows[0]["ts_ny"].utc_offset)
 
     # All should have same timestamp value
     assert_equal test_time.to_i, read_rows[0]["ts_utc"].to_i
     assert_equal test_time.to_i, read_rows[0]["ts_tokyo"].to_i
     assert_equal test_time.to_i, read_rows[0]["ts_ny"].to_i
   end
+
+  def test_named_timezone_follows_daylight_saving
+    schema = Parquet::Schema.define do
+      field :ts, :timestamp_micros, timezone: "Europe/Paris"
+    end
+
+    winter = Time.utc(2024, 1, 15, 12)
+    summer = Time.utc(2024, 7, 15, 12)
+    Parquet.write_rows([[winter], [summer]].each, schema: schema, write_to: @test_file)
+
+    read = Parquet.each_row(@test_file).map { |row| row["ts"] }
+    assert_equal [winter, summer], read
+    assert_equal [3600, 7200], read.map(&:utc_offset)
+    assert_equal [13, 14], read.map(&:hour)
+  end
+
+  def test_unknown_timezone
+    assert_raises(Parquet::SchemaError) do
+      schema = Parquet::Schema.define do
+        field :ts, :timestamp_millis, timezone: "Mars/Olympus_Mons"
+      end
+      Parquet.write_rows([[Time.now]].each, schema: schema, write_to: @test_file)
+    end
+  end
 end
@@ -0,0 +1,85 @@
+require_relative 'test_helper'
+require 'bigdecimal'
+require 'date'
+
+class VariantTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_variant_#{Process.pid}.parquet")
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_variant_roundtrip
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :payload, :variant
+    end
+
+    data = [
+      [1, { "user" => "alice", "tags" => ["a", "b"], "score" => 1.5, "active" => true }],
+      [2, [1, nil, "two", { "nested" => { "deep" => 300 } }]],
+      [3, "just a string"],
+      [4, 42],
+      [5, nil]
+    ]
+
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file).to_a
+    assert_equal({ "active" => true, "score" => 1.5, "tags" => ["a", "b"], "user" => "alice" }, rows[0]["payload"])
+    assert_equal [1, nil, "two", { "nested" => { "deep" => 300 } }], rows[1]["payload"]
+    assert_equal "just a string", rows[2]["payload"]
+    assert_equal 42, rows[3]["payload"]
+    assert_nil rows[4]["payload"]
+  end
+
+  def test_variant_symbols_and_scalars
+    schema = Parquet::Schema.define do
+      field :payload, :variant
+    end
+
+    time = Time.utc(2024, 1, 2, 3, 4, 5)
+    data = [
+      [{ status: :ok }],
+      [Date.new(2024, 5, 6)],
+      [time],
+      [BigDecimal("12.345")],
+      ["\x00\x01".b]
+    ]
+
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+
+    rows = Parquet.each_row(@test_file).map { |r| r["payload"] }
+    assert_equal({ "status" => "ok" }, rows[0])
+    assert_equal Date.new(2024, 5, 6), rows[1]
+    assert_equal time, rows[2]
+    assert_equal BigDecimal("12.345"), rows[3]
+    assert_equal "\x00\x01".b, rows[4]
+  end
+
+  def test_variant_time_out_of_range
+    schema = Parquet::Schema.define do
+      field :payload, :variant
+    end
+
+    assert_raises(Parquet::ConversionError) do
+      Parquet.write_rows([[Time.at(2**62)]].each, schema: schema, write_to: @test_file)
+    end
+  end
+
+  def test_variant_in_struct
+    schema = Parquet::Schema.define do
+      field :event, :struct do
+        field :kind, :string
+        field :attributes, :variant
+      end
+    end
+
+    Parquet.write_rows([[{ "kind" => "click", "attributes" => { "x" => 1 } }]].each, schema: schema, write_to: @test_file)
+
+    row = Parquet.each_row(@test_file).first
+    assert_equal({ "kind" => "click", "attributes" => { "x" => 1 } }, row["event"])
+  end
+end
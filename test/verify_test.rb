@@ -0,0 +1,62 @@
+require_relative 'test_helper'
+require 'stringio'
+
+class VerifyTest < Minitest::Test
+  def setup
+    @test_file = File.join(Dir.tmpdir, "test_verify_#{Process.pid}.parquet")
+
+    schema = Parquet::Schema.define do
+      field :id, :int64, nullable: false
+      field :name, :string
+    end
+    data = (1..100).map { |i| [i, "name #{i}"] }
+    Parquet.write_rows(data.each, schema: schema, write_to: @test_file)
+  end
+
+  def teardown
+    File.delete(@test_file) if File.exist?(@test_file)
+  end
+
+  def test_healthy_file
+    report = Parquet.verify(@test_file)
+
+    assert report["ok"]
+    assert_equal 100, report["num_rows"]
+    assert_equal 1, report["row_groups"]
+    assert_operator report["pages"], :>=, 2
+    assert_empty report["problems"]
+  end
+
+  def test_io_and_checksums_option
+    report = File.open(@test_file, "rb") { |f| Parquet.verify(f, checksums: false) }
+    assert report["ok"]
+    assert_equal 0, report["checksums_verified"]
+  end
+
+  def test_corrupt_page_is_reported
+    column = Parquet.metadata(@test_file)["row_groups"][0]["columns"].find { |c| c["column_path"] == "name" }
+    offset = column["dictionary_page_offset"] || column["data_page_offset"]
+    File.open(@test_file, "r+b") do |f|
+      f.seek(offset)
+      f.write("\xFF".b * 4)
+    end
+
+    report = Parquet.verify(@test_file)
+    refute report["ok"]
+    assert_equal 1, report["problems"].size
+
+    problem = report["problems"][0]
+    assert_equal 0, problem["row_group"]
+    assert_equal "name", problem["column"]
+    assert_equal offset, problem["page_offset"]
+    assert_kind_of String, problem["error"]
+  end
+
+  def test_truncated_file
+    data = File.binread(@test_file)
+    report = Parquet.verify(StringIO.new(data[0, data.size / 2]))
+
+    refute report["ok"]
+    assert_nil report["problems"][0]["row_group"]
+  end
+end